@@ -46,8 +46,19 @@ pub fn train_sync(lang: Lang) {
     rmp_serde::encode::write(&mut file, &model).unwrap();
 }
 
+/// Languages whose model is embedded, selected with the `embed-*` cargo features.
+pub fn embedded_langs() -> Vec<Lang> {
+    Lang::all()
+        .into_iter()
+        .filter(|lang| {
+            let feature = format!("CARGO_FEATURE_EMBED_{}", lang.to_string().to_uppercase());
+            env::var_os(feature).is_some()
+        })
+        .collect()
+}
+
 pub fn train_all_async() {
-    let join_handlers: Vec<_> = Lang::all()
+    let join_handlers: Vec<_> = embedded_langs()
         .into_iter()
         .map(|lang| train_async(lang))
         .collect();
@@ -58,7 +69,7 @@ pub fn train_all_async() {
 }
 
 pub fn train_all_sync() {
-    for lang in Lang::all() {
+    for lang in embedded_langs() {
         train_sync(lang);
     }
 }
@@ -16,6 +16,7 @@ use json_utils::{PartialUtterance, Utterance, TestOutput, TestAssertion, SlotVal
 fn main() {
     let matches = clap_app!(rustling_cli =>
         (@arg lang: -l --lang default_value[en] "2-letter language code (default to \"en\")")
+        (@arg timezone: -t --timezone default_value[local] "Timezone used to resolve moments, IANA name or offset (default to \"local\")")
         (@subcommand parse =>
              (@arg kinds: -k --kinds +takes_value +use_delimiter "kinds, last one wins, coma separated")
              (@arg sentence: +required "Sentence to test")
@@ -36,6 +37,7 @@ fn main() {
     ).get_matches();
 
     let lang = value_t!(matches.value_of("lang"), Lang).unwrap_or_else(|e| e.exit());
+    let timezone = value_t!(matches.value_of("timezone"), Tz).unwrap_or_else(|e| e.exit());
     match matches.subcommand() {
         ("parse", Some(matches)) => {
             let kinds = matches
@@ -48,7 +50,7 @@ fn main() {
             let sentence = matches.value_of("sentence").unwrap().to_lowercase();
             let parser = build_parser(lang).unwrap();
             
-            let context = ResolverContext::default();
+            let context = ResolverContext::now_in(timezone);
             let entities = if let Some(kinds) = kinds {
                 parser.parse_with_kind_order(&*sentence, &context, &kinds).unwrap()
             } else {
@@ -85,7 +87,7 @@ fn main() {
             let sentence = matches.value_of("sentence").unwrap().to_lowercase();
             let parser = build_raw_parser(lang).unwrap();
 
-            let context = ResolverContext::default();
+            let context = ResolverContext::now_in(timezone);
             
             let tagger = CandidateTagger {
                 output_kind_filter: &kinds,
@@ -136,7 +138,7 @@ fn main() {
               serde_json::from_reader(&file).unwrap()
             };
             let parser = build_parser(lang).unwrap();
-            let default_context = Moment(Local.ymd(2017, 6, 1).and_hms(5, 00, 0));
+            let default_context = Moment(Tz::local().ymd(2017, 6, 1).and_hms(5, 00, 0));
             let utterances: Vec<Utterance> = partial_utterances.into_iter()
                 .map(|it| {
                   if it.keep() && (it.value.is_none() || force_resolution) {
@@ -184,7 +186,7 @@ fn main() {
               serde_json::from_reader(&file).unwrap()
             };
             let parser = build_parser(lang).unwrap();
-            let default_context = Moment(Local.ymd(2017, 6, 1).and_hms(5, 00, 0));
+            let default_context = Moment(Tz::local().ymd(2017, 6, 1).and_hms(5, 00, 0));
             
             let output: Vec<TestOutput> = utterances.into_iter()
                 .map(|utterance| {
@@ -9,21 +9,34 @@ mod training;
 use rustling_ontology_values::DimensionKind::*;
 
 pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    Ok(rule_set_builder()?.build())
+}
+
+pub fn rule_set_builder() -> ::rustling::RustlingResult<::rustling::RuleSetBuilder<rustling_ontology_values::Dimension>> {
     let mut b = ::rustling::RuleSetBuilder::new(
                     ::rustling::BoundariesChecker::composed_word_or_detailed(),
                     ::rustling::BoundariesChecker::separated_alphanumeric_word());
     rules::rules_numbers(&mut b)?;
+    rules::rules_fraction(&mut b)?;
     rules::rules_datetime(&mut b)?;
     rules::rules_cycle(&mut b)?;
     rules::rules_duration(&mut b)?;
     rules::rules_temperature(&mut b)?;
     rules::rules_finance(&mut b)?;
     rules::rules_percentage(&mut b)?;
-    Ok(b.build())
+    rules::rules_quantities(&mut b)?;
+    rules::rules_distance(&mut b)?;
+    rules::rules_volume(&mut b)?;
+    rules::rules_mass(&mut b)?;
+    rules::rules_quantity_range(&mut b)?;
+    rules::rules_contact(&mut b)?;
+    rules::rules_recurrence(&mut b)?;
+    rules::rules_age(&mut b)?;
+    Ok(b)
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Datetime, Duration, Temperature, AmountOfMoney, Percentage];
+    return vec![Number, Ordinal, Datetime, Duration, Temperature, AmountOfMoney, Percentage, Distance, Volume, Mass, Rate, QuantityRange, PhoneNumber, Email, Url, Recurrence, Age];
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
@@ -32,7 +45,28 @@ pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Di
     training::examples_datetime(&mut v);
     training::examples_temperature(&mut v);
     training::examples_finance(&mut v);
+    training::examples_distance(&mut v);
+    training::examples_volume(&mut v);
+    training::examples_mass(&mut v);
+    training::examples_rate(&mut v);
     training::examples_percentage(&mut v);
+    training::examples_quantity_range(&mut v);
+    training::examples_contact(&mut v);
+    training::examples_recurrence(&mut v);
+    training::examples_age(&mut v);
+    training::examples_fraction(&mut v);
+    v
+}
+
+/// Opt-in rules for Roman numerals, e.g. "chapter iv" or "louis xiv". A bare Roman numeral is a
+/// latent number and ordinal.
+pub fn rules_roman_numerals(b: &mut ::rustling::RuleSetBuilder<rustling_ontology_values::Dimension>) -> ::rustling::RustlingResult<()> {
+    rules::rules_roman_numerals(b)
+}
+
+pub fn examples_roman_numerals() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
+    let mut v = vec![];
+    training::examples_roman_numerals(&mut v);
     v
 }
 
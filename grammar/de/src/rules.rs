@@ -374,6 +374,11 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              duration_check!(|duration: &DurationValue| !duration.prefixed),
              |a, b| Ok(a.value() + b.value())
     );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             unit_of_duration_check!(),
+             |number, uod| helpers::fractional_duration(number.value(), uod.value())
+    );
     Ok(())
 }
 
@@ -1369,6 +1374,21 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           text_match.group(2).parse()?,
                           text_match.group(1).parse()?)
     );
+    b.rule_1_terminal("mm/dd/yy or mm/dd/yyyy - Ambiguous cases - month first",
+                      b.reg(r#"(0?[1-9]|1[0-2])[-\./](0?[1-9]|1[0-2])[-\./](\d{2,4})"#)?,
+                      |text_match| Ok(helpers::year_month_day(
+                          text_match.group(3).parse()?,
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)?
+                          .date_order(Some(DateOrder::MonthDayYear)))
+    );
+    b.rule_1_terminal("mm/dd - Ambiguous cases - month first",
+                      b.reg(r#"(0?[1-9]|1[0-2])[\./](0?[1-9]|1[0-2])"#)?,
+                      |text_match| Ok(helpers::month_day(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)?
+                          .date_order(Some(DateOrder::MonthDayYear)))
+    );
     // End of Written dates in numeric formats
     b.rule_1_terminal("breakfast (latent)",
                       b.reg(r#"fr[üu]hst[üu]ck(?:szeit|spause|s)?"#)?,
@@ -1709,13 +1729,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("week-end",
         b.reg(r#"wochen ?enden?"#)?,
-        |_| {
-            let friday = helpers::day_of_week(Weekday::Fri)?
-                .intersect(&helpers::hour(18, false)?)?;
-            let monday = helpers::day_of_week(Weekday::Mon)?
-                .intersect(&helpers::hour(0, false)?)?;
-            friday.span_to(&monday, false)
-        }
+        |_| helpers::weekend()
     );
     b.rule_1_terminal("season - summer",
                       b.reg(r#"sommer(?:zeit|s)?"#)?,
@@ -1980,10 +1994,53 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("end of week",
         b.reg(r#"ende der woche"#)?,
-        |_| Ok(helpers::day_of_week(Weekday::Thu)
-                    ?.span_to(&helpers::day_of_week(Weekday::Sun)?, false)?
+        |_| Ok(helpers::nth_day_of_week(3)
+                    ?.span_to(&helpers::nth_day_of_week(-1)?, false)?
                     .form(PartOfForm::end_of(Form::PartOfWeek)))
     );
+    b.rule_1_terminal("week <number> (ISO 8601)",
+        b.reg(r#"(?:die )?(?:kw|kalenderwoche|woche) ?(?:nr\.? ?)?(\d{1,2})"#)?,
+        |text_match| helpers::week_of_year(text_match.group(1).parse()?)
+    );
+    b.rule_1_terminal("next business day",
+        b.reg(r#"(?:der |am )?n[äa]chste[nr]? (?:werktag|arbeitstag|gesch[äa]ftstag)"#)?,
+        |_| helpers::business_day_nth(0)
+    );
+    b.rule_1_terminal("previous business day",
+        b.reg(r#"(?:der |am )?(?:vorherige[nr]?|letzte[nr]?) (?:werktag|arbeitstag|gesch[äa]ftstag)"#)?,
+        |_| helpers::business_day_nth(-1)
+    );
+    b.rule_3("in <integer> business days",
+        b.reg(r#"in"#)?,
+        integer_check_by_range!(1),
+        b.reg(r#"(?:werktag|arbeitstag|gesch[äa]ftstag)(?:en|e)?"#)?,
+        |_, integer, _| helpers::business_day_nth(integer.value().value - 1)
+    );
+    b.rule_1_terminal("end of business",
+        b.reg(r#"(?:bei |zum )?(?:gesch[äa]ftsschluss|b[üu]roschluss|dienstschluss)"#)?,
+        |_| helpers::end_of_business()
+    );
+    b.rule_1_terminal("business hours",
+        b.reg(r#"(?:w[äa]hrend der )?(?:gesch[äa]ftszeiten?|b[üu]rozeiten?|arbeitszeit(?:en)?)"#)?,
+        |_| helpers::business_hours()
+    );
+    b.rule_1_terminal("fiscal year <year>",
+        b.reg(r#"(?:das )?(?:gj|gesch[äa]ftsjahr|fiskaljahr) ?(\d{4}|\d{2})"#)?,
+        |text_match| helpers::fiscal_year(text_match.group(1).parse()?)
+    );
+    b.rule_1_terminal("Q1-4 fiscal year <year>",
+        b.reg(r#"q([1-4]) ?(?:des )?(?:gj|gesch[äa]ftsjahr(?:e?s)?|fiskaljahr(?:e?s)?) ?(\d{4}|\d{2})"#)?,
+        |text_match| helpers::fiscal_quarter(text_match.group(1).parse()?)?
+            .intersect(&helpers::fiscal_year(text_match.group(2).parse()?)?)
+    );
+    b.rule_1_terminal("this|next|last fiscal year",
+        b.reg(r#"(?:im )?(dies|laufend|n[äa]chst|letzt|vorig)(?:e[nms]?)? (?:gesch[äa]ftsjahr|fiskaljahr)(?:e?s)?"#)?,
+        |text_match| match text_match.group(1).as_ref() {
+            "dies" | "laufend" => helpers::fiscal_cycle_nth(Grain::Year, 0),
+            "nächst" | "nachst" => helpers::fiscal_cycle_nth(Grain::Year, 1),
+            _ => helpers::fiscal_cycle_nth(Grain::Year, -1),
+        }
+    );
 
     b.rule_1_terminal("end of month",
              b.reg(r#"(?:am )?ende (?:des|vom) monate?s?|monatsende"#)?,
@@ -2573,3 +2630,383 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_quantities(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_quantities(b, &rustling_ontology_values::rules::QuantityWords {
+        one: r#"eine?n?"#,
+        approximately: r#"zirka|circa|nahezu|beinahe|ungef[äa]hr|fast|ca\.?"#,
+        exactly: r#"(?:haar|ganz |sehr )?genau|exakt|rund|gerade|pr[äa]zise"#,
+        per: r#"pro|je|/"#,
+    })
+}
+
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("km",
+                      b.reg(r#"kilometern?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("km"), latent: false })
+    );
+    b.rule_1_terminal("m",
+                      b.reg(r#"metern?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("m"), latent: false })
+    );
+    b.rule_1_terminal("cm",
+                      b.reg(r#"zentimetern?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("cm"), latent: false })
+    );
+    b.rule_1_terminal("mm",
+                      b.reg(r#"millimetern?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("mm"), latent: false })
+    );
+    b.rule_1_terminal("mi",
+                      b.reg(r#"meilen?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("mi"), latent: false })
+    );
+    b.rule_1_terminal("yd",
+                      b.reg(r#"yards?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("yd"), latent: false })
+    );
+    b.rule_1_terminal("ft",
+                      b.reg(r#"fu(?:ß|ss)"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("ft"), latent: false })
+    );
+    b.rule_1_terminal("in",
+                      b.reg(r#"zoll"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("in"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_volume(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("l",
+                      b.reg(r#"litern?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("l"), latent: false })
+    );
+    b.rule_1_terminal("dl",
+                      b.reg(r#"decilitern?|dezilitern?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("dl"), latent: false })
+    );
+    b.rule_1_terminal("cl",
+                      b.reg(r#"zentilitern?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("cl"), latent: false })
+    );
+    b.rule_1_terminal("ml",
+                      b.reg(r#"millilitern?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("ml"), latent: false })
+    );
+    b.rule_1_terminal("gal",
+                      b.reg(r#"gallonen?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("gal"), latent: false })
+    );
+    b.rule_1_terminal("pt",
+                      b.reg(r#"pints?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("pt"), latent: false })
+    );
+    b.rule_1_terminal("cup",
+                      b.reg(r#"tassen?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("cup"), latent: false })
+    );
+    b.rule_1_terminal("tbsp",
+                      b.reg(r#"essl[öo]ffeln?|el"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("tbsp"), latent: false })
+    );
+    b.rule_1_terminal("tsp",
+                      b.reg(r#"teel[öo]ffeln?|tl"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("tsp"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_mass(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("t",
+                      b.reg(r#"tonnen?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("t"), latent: false })
+    );
+    b.rule_1_terminal("kg",
+                      b.reg(r#"kilos?|kilogramm(?:e|en)?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("kg"), latent: false })
+    );
+    b.rule_1_terminal("g",
+                      b.reg(r#"gramm(?:e|en)?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("g"), latent: false })
+    );
+    b.rule_1_terminal("mg",
+                      b.reg(r#"milligramm(?:e|en)?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("mg"), latent: false })
+    );
+    b.rule_1_terminal("lb",
+                      b.reg(r#"pfund(?:e)?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("lb"), latent: false })
+    );
+    b.rule_1_terminal("oz",
+                      b.reg(r#"unzen?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("oz"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_quantity_range(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("between <number> and <number>",
+             b.reg(r#"zwischen"#)?,
+             number_check!(),
+             b.reg(r#"und"#)?,
+             number_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <number>",
+             number_check_by_range!(0.0, 999.0),
+             b.reg(r#"\-"#)?,
+             number_check_by_range!(0.0, 999.0),
+             |start, _, end| Ok(helpers::quantity_between(start.value(), end.value())?.latent(true)));
+    b.rule_4("between <number> and <amount-of-money>",
+             b.reg(r#"zwischen|von"#)?,
+             number_check!(),
+             b.reg(r#"und|bis|\-"#)?,
+             amount_of_money_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <amount-of-money> and <amount-of-money>",
+             b.reg(r#"zwischen|von"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"und|bis|\-"#)?,
+             amount_of_money_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <number> and <temperature>",
+             b.reg(r#"zwischen|von"#)?,
+             number_check!(),
+             b.reg(r#"und|bis|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <temperature> and <temperature>",
+             b.reg(r#"zwischen|von"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"und|bis|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <ordinal> and <ordinal>",
+             b.reg(r#"zwischen|von"#)?,
+             ordinal_check!(),
+             b.reg(r#"und|bis|\-"#)?,
+             ordinal_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"\-|bis"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"\-|bis"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <temperature>",
+             number_check!(),
+             b.reg(r#"\-|bis"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<temperature> - <temperature>",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"\-|bis"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<ordinal> - <ordinal>",
+             ordinal_check!(),
+             b.reg(r#"\-|bis"#)?,
+             ordinal_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_2("more than <number>",
+             b.reg(r#"mehr als|[üu]ber|ueber|oberhalb von|gr[öo](?:ß|ss)er als"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <number>",
+             b.reg(r#"mindestens"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("more than <amount-of-money>",
+             b.reg(r#"mehr als|[üu]ber|ueber|oberhalb von|gr[öo](?:ß|ss)er als"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <amount-of-money>",
+             b.reg(r#"mindestens"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("more than <temperature>",
+             b.reg(r#"mehr als|[üu]ber|ueber|oberhalb von|gr[öo](?:ß|ss)er als"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <temperature>",
+             b.reg(r#"mindestens"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("less than <number>",
+             b.reg(r#"weniger als|unter|unterhalb von|kleiner als"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <number>",
+             b.reg(r#"h[öo]chstens|bis zu"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("less than <amount-of-money>",
+             b.reg(r#"weniger als|unter|unterhalb von|kleiner als"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <amount-of-money>",
+             b.reg(r#"h[öo]chstens|bis zu"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("less than <temperature>",
+             b.reg(r#"weniger als|unter|unterhalb von|kleiner als"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <temperature>",
+             b.reg(r#"h[öo]chstens|bis zu"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("<number> or more",
+             number_check!(),
+             b.reg(r#"oder mehr|und mehr"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<amount-of-money> or more",
+             amount_of_money_check!(),
+             b.reg(r#"oder mehr|und mehr"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<temperature> or more",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"oder mehr|und mehr"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<number> or less",
+             number_check!(),
+             b.reg(r#"oder weniger|und weniger"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<amount-of-money> or less",
+             amount_of_money_check!(),
+             b.reg(r#"oder weniger|und weniger"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<temperature> or less",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"oder weniger|und weniger"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    Ok(())
+}
+
+pub fn rules_fraction(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("<numerator>/<denominator> (latent)",
+             b.reg(r#"(\d+)/(\d+)"#)?,
+             |text_match| Ok(helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)?.latent(true)));
+    b.rule_2("<integer> <denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(halbe?|drittel|viertel|f[üu]nftel|sechstel|siebtel|achtel|neuntel|zehntel)"#)?,
+             |integer, text_match| {
+                 let denominator = match text_match.group(1).as_ref() {
+                     "halb" | "halbe" => 2,
+                     "drittel" => 3,
+                     "viertel" => 4,
+                     "fünftel" | "funftel" => 5,
+                     "sechstel" => 6,
+                     "siebtel" => 7,
+                     "achtel" => 8,
+                     "neuntel" => 9,
+                     "zehntel" => 10,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::fraction(integer.value().value, denominator)
+             });
+    b.rule_1_terminal("1..12 and a half",
+                      b.reg(r#"(ein|zwei|drei|vier|f[üu]nf|sechs|sieben|acht|neun|zehn|elf|zw[öo]lf)einhalb"#)?,
+                      |text_match| {
+                          let value = match text_match.group(1).as_ref() {
+                              "ein" => 1,
+                              "zwei" => 2,
+                              "drei" => 3,
+                              "vier" => 4,
+                              "funf" => 5,
+                              "fünf" => 5,
+                              "sechs" => 6,
+                              "sieben" => 7,
+                              "acht" => 8,
+                              "neun" => 9,
+                              "zehn" => 10,
+                              "elf" => 11,
+                              "zwolf" => 12,
+                              "zwölf" => 12,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          helpers::fraction(value * 2 + 1, 2)
+                      });
+    b.rule_1_terminal("one and a half",
+                      b.reg(r#"anderthalb"#)?,
+                      |_| helpers::fraction(3, 2));
+    b.rule_2("<integer> <fraction>",
+             integer_check_by_range!(0),
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() < 1.0),
+             |integer, fraction| helpers::mixed_number(integer.value(), fraction.value()));
+    Ok(())
+}
+
+pub fn rules_roman_numerals(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_roman_numerals(b, &rustling_ontology_values::rules::RomanNumeralWords {
+        nouns: r#"kapitel|teil|band|buch|akt|szene|artikel|abschnitt|titel|anhang|phase|stufe|klasse|typ"#,
+        names: r#"heinrich|karl|friedrich|wilhelm|otto|elisabeth|papst (?:pius|leo|benedikt|gregor|johannes paul|johannes)"#,
+    })
+}
+
+pub fn rules_contact(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_contact(b)?;
+    b.rule_1_terminal("spoken email address",
+                      b.reg(r#"((?:[a-z0-9]+ (?:unterstrich|bindestrich|punkt) )*[a-z0-9]+) (?:at|ät) ((?:[a-z0-9]+ (?:unterstrich|bindestrich|punkt) )+[a-z]{2,})"#)?,
+                      |text_match| helpers::spoken_email(text_match.group(1), text_match.group(2), &[("unterstrich", "_"), ("bindestrich", "-"), ("punkt", ".")])
+    );
+    Ok(())
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_recurrence(b, &rustling_ontology_values::rules::RecurrenceWords {
+        every: r#"jeden|jede|jedes"#,
+        every_other: Some(r#"jeden zweiten|jede zweite|jedes zweite"#),
+        every_integer: Some(r#"alle"#),
+        hourly: r#"stündlich"#,
+        daily: r#"täglich"#,
+        weekly: r#"wöchentlich"#,
+        monthly: r#"monatlich"#,
+        quarterly: Some(r#"vierteljährlich|quartalsweise"#),
+        yearly: r#"jährlich"#,
+        and: Some(r#"und"#),
+        until: Some(r#"bis(?: zum| zur)?"#),
+        times: Some(r#"mal"#),
+    })?;
+    b.rule_1_terminal("every <day-of-week> (plural)",
+                      b.reg(r#"immer (mon|diens|mittwoch|donners|frei|sams|sonn)(?:tag)?s"#)?,
+                      |text_match| {
+                          let weekday = match text_match.group(1) {
+                              "mon" => Weekday::Mon,
+                              "diens" => Weekday::Tue,
+                              "mittwoch" => Weekday::Wed,
+                              "donners" => Weekday::Thu,
+                              "frei" => Weekday::Fri,
+                              "sams" => Weekday::Sat,
+                              "sonn" => Weekday::Sun,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          helpers::recurrence(&helpers::day_of_week(weekday)?, 1)
+                      }
+    );
+    Ok(())
+}
+
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_3("<number> <unit-of-duration> alt",
+             number_check!(),
+             unit_of_duration_check!(),
+             b.reg(r#"alt"#)?,
+             |number, uod, _| helpers::age(number.value(), uod.value().grain)
+    );
+    b.rule_3("im Alter von <number> <unit-of-duration>",
+             b.reg(r#"im alter von"#)?,
+             number_check!(),
+             unit_of_duration_check!(|uod: &UnitOfDurationValue| uod.grain == Grain::Year || uod.grain == Grain::Month),
+             |_, number, uod| helpers::age(number.value(), uod.value().grain)
+    );
+    b.rule_2("<number>-jährig",
+             number_check!(),
+             b.reg(r#"-?j[äa]hrige?[rnms]?"#)?,
+             |number, _| helpers::age(number.value(), Grain::Year)
+    );
+    Ok(())
+}
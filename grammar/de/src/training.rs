@@ -76,7 +76,7 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 0]), "jetzt", "genau jetzt", "gerade eben");
     example!(v, check_moment!(c, [2013, 2, 12]), "heute", "zu dieser zeit");
     example!(v, check_moment!(c, [2013, 2, 11]), "gestern");
@@ -103,6 +103,15 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 13]), "Mittwoch Feb 13");
     example!(v, check_moment!(c, [2013, 2, 18]), "Montag Feb 18");
     example!(v, check_moment!(c, [2013, 2, 11], Grain::Week), "diese woche");
+    example!(v, check_moment!(c, [2013, 10, 14], Grain::Week), "KW 42", "kw42", "Kalenderwoche 42", "woche 42");
+    example!(v, check_moment!(c, [2013, 2, 13]), "nächster Werktag", "am nächsten Arbeitstag");
+    example!(v, check_moment!(c, [2013, 2, 11]), "letzter Werktag", "der vorherige Geschäftstag");
+    example!(v, check_moment!(c, [2013, 2, 15]), "in 3 Werktagen", "in drei Arbeitstagen");
+    example!(v, check_moment!(c, [2013, 2, 12, 17, 0]), "Geschäftsschluss", "zum Büroschluss");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 9], [2013, 2, 12, 17]), "während der Geschäftszeiten", "Bürozeiten");
+    example!(v, check_moment!(c, [2014]), "Geschäftsjahr 2014", "GJ 2014", "nächstes Geschäftsjahr");
+    example!(v, check_moment!(c, [2014, 7, 1], Grain::Quarter), "Q3 GJ 2014", "Q3 des Geschäftsjahres 2014");
+    example!(v, check_moment!(c, [2013]), "dieses Geschäftsjahr", "im laufenden Geschäftsjahr");
     example!(v, check_moment!(c, [2013, 2, 18], Grain::Week), "kommende woche");
     example!(v, check_moment!(c, [2013, 2, 4], Grain::Week), "letzte woche");
     example!(v, check_moment!(c, [2013, 2, 18], Grain::Week), "nächste woche");
@@ -330,4 +339,97 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_float(1000000.0), "1.000.000,00");
     example!(v, check_ordinal(44), "der vierundvierzigste");
     example!(v, check_integer(455628), "vierhundertfünfundfünfzigtausendsechshundertachtundzwanzig");
-}
\ No newline at end of file
+}
+
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(5.0, Some("km"), Precision::Exact), "5 km", "5km", "fünf kilometer");
+    example!(v, check_distance(200.0, Some("m"), Precision::Exact), "200 meter", "zweihundert meter");
+    example!(v, check_distance(3.0, Some("mi"), Precision::Exact), "3 meilen", "drei meilen");
+    example!(v, check_distance(1.0, Some("km"), Precision::Exact), "ein kilometer");
+    example!(v, check_distance(12.0, Some("cm"), Precision::Exact), "12 cm", "zwölf zentimeter");
+    example!(v, check_distance(4.0, Some("mm"), Precision::Exact), "4 mm", "vier millimeter");
+    example!(v, check_distance(6.0, Some("ft"), Precision::Exact), "6 fuß");
+    example!(v, check_distance(2.0, Some("in"), Precision::Exact), "2 zoll", "zwei zoll");
+    example!(v, check_distance(10.0, Some("km"), Precision::Approximate), "ungefähr 10 km", "fast zehn kilometer");
+    example!(v, check_distance(400.0, Some("m"), Precision::Exact), "genau 400 meter");
+}
+
+pub fn examples_volume(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_volume(2.0, Some("l"), Precision::Exact), "2 liter", "zwei liter", "2l");
+    example!(v, check_volume(500.0, Some("ml"), Precision::Exact), "500 ml", "fünfhundert milliliter");
+    example!(v, check_volume(25.0, Some("cl"), Precision::Exact), "25 cl");
+    example!(v, check_volume(3.0, Some("cup"), Precision::Exact), "drei tassen");
+    example!(v, check_volume(2.0, Some("tbsp"), Precision::Exact), "zwei esslöffel", "2 el");
+    example!(v, check_volume(1.0, Some("tsp"), Precision::Exact), "ein teelöffel");
+    example!(v, check_volume(1.0, Some("l"), Precision::Approximate), "ungefähr ein liter");
+}
+
+pub fn examples_mass(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_mass(500.0, Some("g"), Precision::Exact), "500 gramm", "500g", "fünfhundert gramm");
+    example!(v, check_mass(1.0, Some("lb"), Precision::Exact), "ein pfund");
+    example!(v, check_mass(2.0, Some("kg"), Precision::Exact), "2 kg", "zwei kilo", "zwei kilogramm");
+    example!(v, check_mass(250.0, Some("mg"), Precision::Exact), "250 mg");
+    example!(v, check_mass(3.0, Some("oz"), Precision::Exact), "drei unzen");
+    example!(v, check_mass(1.0, Some("t"), Precision::Exact), "eine tonne");
+    example!(v, check_mass(5.0, Some("kg"), Precision::Approximate), "ungefähr 5 kg");
+    example!(v, check_mass(100.0, Some("g"), Precision::Exact), "genau 100 gramm");
+}
+
+pub fn examples_rate(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_rate(50.0, Some("km"), "hour", Precision::Exact), "50 km/h", "fünfzig kilometer pro stunde");
+    example!(v, check_rate(5.0, Some("minute"), "km", Precision::Exact), "5 minuten pro kilometer", "fünf minuten pro km");
+    example!(v, check_rate(3.0, None, "day", Precision::Exact), "3 pro tag");
+    example!(v, check_rate(90.0, Some("km"), "hour", Precision::Approximate), "ungefähr 90 km/h");
+}
+
+pub fn examples_quantity_range(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity_range(QuantityKind::Number, Some(25.0), None), "mehr als 25", "25 oder mehr");
+    example!(v, check_quantity_range(QuantityKind::Number, None, Some(3.0)), "weniger als 3");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(5.0), Some(10.0)), "zwischen 5 und 10", "zwischen fünf und zehn");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(3.0), Some(4.0)), "3-4", "3 - 4");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("EUR") }, Some(20.0), Some(30.0)), "zwischen 20 und 30 Euro");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("EUR") }, None, Some(50.0)), "unter 50 Euro");
+    example!(v, check_quantity_range(QuantityKind::Temperature { unit: Some("degree") }, Some(25.0), None), "über 25 Grad");
+}
+
+pub fn examples_fraction(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_fraction(2, 3), "zwei Drittel");
+    example!(v, check_fraction(1, 4), "ein Viertel");
+    example!(v, check_fraction(3, 4), "drei viertel");
+    example!(v, check_fraction(5, 2), "zweieinhalb");
+    example!(v, check_fraction(3, 2), "anderthalb");
+    example!(v, check_duration!([0, 0, 0, 2, 12]), "zweieinhalb Tage");
+}
+
+pub fn examples_roman_numerals(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_integer(4), "kapitel iv");
+    example!(v, check_integer(2), "band ii");
+    example!(v, check_ordinal(14), "ludwig xiv", "Ludwig XIV");
+    example!(v, check_ordinal(2), "wilhelm ii");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("+33612345678"), "+33 6 12 34 56 78", "0033 6 12 34 56 78", "+33612345678");
+    example!(v, check_phone_number("0301234567"), "030 1234567", "030-1234567");
+    example!(v, check_email("john.doe@gmail.com"), "john.doe@gmail.com");
+    example!(v, check_email("hans.mueller@web.de"), "hans punkt mueller at web punkt de");
+    example!(v, check_url("https://www.example.com/path"), "https://www.example.com/path");
+    example!(v, check_url("www.example.com"), "www.example.com");
+    example!(v, check_url("example.com"), "example.com");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Mon]), "jeden montag", "immer montags", "jeden montag um 17 uhr");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Mon, Weekday::Wed]), "jeden montag und mittwoch");
+    example!(v, check_recurrence(c.clone(), Grain::Day, 1, vec![]), "jeden tag", "täglich", "jeden tag um 8 uhr");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 2, vec![]), "alle 2 wochen", "jede zweite woche");
+    example!(v, check_recurrence(c.clone(), Grain::Month, 1, vec![]), "jeden monat", "monatlich");
+    example!(v, check_recurrence(c.clone(), Grain::Year, 1, vec![]), "jedes jahr", "jährlich");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Tue]), "jeden dienstag bis märz");
+}
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age(7.0, Grain::Year), "7 jahre alt", "sieben jahre alt", "7-jähriger", "im alter von 7 jahren");
+    example!(v, check_age(6.0, Grain::Month), "6 monate alt");
+}
@@ -8,29 +8,44 @@ mod rules_celebrations;
 mod rules_duration;
 mod rules_number;
 mod rules_amount;
+mod rules_contact;
 mod training;
 
 use rustling_ontology_values::DimensionKind::*;
 
 pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    Ok(rule_set_builder()?.build())
+}
+
+pub fn rule_set_builder() -> ::rustling::RustlingResult<::rustling::RuleSetBuilder<rustling_ontology_values::Dimension>> {
     let mut b = ::rustling::RuleSetBuilder::new(
                     ::rustling::BoundariesChecker::detailed(),
                     ::rustling::BoundariesChecker::separated_alphanumeric_word());
     rules_number::rules_numbers(&mut b)?;
+    rules_number::rules_fraction(&mut b)?;
     rules_amount::rules_temperature(&mut b)?;
     rules_amount::rules_finance(&mut b)?;
     rules_amount::rules_percentage(&mut b)?;
+    rules_amount::rules_quantities(&mut b)?;
+    rules_amount::rules_distance(&mut b)?;
+    rules_amount::rules_volume(&mut b)?;
+    rules_amount::rules_mass(&mut b)?;
+    rules_amount::rules_rate(&mut b)?;
     rules_datetime::rules_cycle(&mut b)?;
     rules_datetime::rules_datetime(&mut b)?;
     rules_datetime::rules_datetime_with_duration(&mut b)?;
     rules_datetime::rules_datetime_with_cycle(&mut b)?;
     rules_celebrations::rules_celebration(&mut b)?;
     rules_duration::rules_duration(&mut b)?;
-    Ok(b.build())
+    rules_amount::rules_quantity_range(&mut b)?;
+    rules_contact::rules_contact(&mut b)?;
+    rules_datetime::rules_recurrence(&mut b)?;
+    rules_duration::rules_age(&mut b)?;
+    Ok(b)
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Datetime, Duration, Temperature, AmountOfMoney, Percentage];
+    return vec![Number, Ordinal, Datetime, Duration, Temperature, AmountOfMoney, Percentage, Distance, Volume, Mass, Rate, QuantityRange, PhoneNumber, Email, Url, Recurrence, Age];
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
@@ -40,7 +55,28 @@ pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Di
     training::examples_durations(&mut v);
     training::examples_temperature(&mut v);
     training::examples_finance(&mut v);
+    training::examples_distance(&mut v);
+    training::examples_volume(&mut v);
+    training::examples_mass(&mut v);
+    training::examples_rate(&mut v);
     training::examples_percentage(&mut v);
+    training::examples_quantity_range(&mut v);
+    training::examples_contact(&mut v);
+    training::examples_recurrence(&mut v);
+    training::examples_age(&mut v);
+    training::examples_fraction(&mut v);
+    v
+}
+
+/// Opt-in rules for Roman numerals, e.g. "chapter iv" or "louis xiv". A bare Roman numeral is a
+/// latent number and ordinal.
+pub fn rules_roman_numerals(b: &mut ::rustling::RuleSetBuilder<rustling_ontology_values::Dimension>) -> ::rustling::RustlingResult<()> {
+    rules_number::rules_roman_numerals(b)
+}
+
+pub fn examples_roman_numerals() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
+    let mut v = vec![];
+    training::examples_roman_numerals(&mut v);
     v
 }
 
@@ -2,6 +2,7 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
+use rustling_ontology_moment::Grain;
 
 pub fn rules_percentage(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     b.rule_2("<number> per cent",
@@ -209,3 +210,274 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
     Ok(())
 }
 
+
+pub fn rules_quantities(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_quantities(b, &rustling_ontology_values::rules::QuantityWords {
+        one: r#"an?"#,
+        approximately: r#"(?:about|approx(?:\.|imately)?|close to|near(?: to)?|around|almost)"#,
+        exactly: r#"exactly|precisely"#,
+        per: r#"per|an?|/"#,
+    })
+}
+
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("km",
+                      b.reg(r#"kilomet(?:er|re)s?|klicks?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("km"), latent: false })
+    );
+    b.rule_1_terminal("m",
+                      b.reg(r#"met(?:er|re)s?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("m"), latent: false })
+    );
+    b.rule_1_terminal("cm",
+                      b.reg(r#"centimet(?:er|re)s?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("cm"), latent: false })
+    );
+    b.rule_1_terminal("mm",
+                      b.reg(r#"millimet(?:er|re)s?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("mm"), latent: false })
+    );
+    b.rule_1_terminal("mi",
+                      b.reg(r#"miles?|mi"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("mi"), latent: false })
+    );
+    b.rule_1_terminal("yd",
+                      b.reg(r#"yards?|yds?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("yd"), latent: false })
+    );
+    b.rule_1_terminal("ft",
+                      b.reg(r#"foot|feet|ft"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("ft"), latent: false })
+    );
+    b.rule_1_terminal("in",
+                      b.reg(r#"inch(?:es)?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("in"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_volume(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("l",
+                      b.reg(r#"lit(?:er|re)s?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("l"), latent: false })
+    );
+    b.rule_1_terminal("dl",
+                      b.reg(r#"decilit(?:er|re)s?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("dl"), latent: false })
+    );
+    b.rule_1_terminal("cl",
+                      b.reg(r#"centilit(?:er|re)s?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("cl"), latent: false })
+    );
+    b.rule_1_terminal("ml",
+                      b.reg(r#"millilit(?:er|re)s?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("ml"), latent: false })
+    );
+    b.rule_1_terminal("gal",
+                      b.reg(r#"gal(?:lon)?s?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("gal"), latent: false })
+    );
+    b.rule_1_terminal("pt",
+                      b.reg(r#"pints?|pt"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("pt"), latent: false })
+    );
+    b.rule_1_terminal("cup",
+                      b.reg(r#"cups?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("cup"), latent: false })
+    );
+    b.rule_1_terminal("fl oz",
+                      b.reg(r#"fl\.? ?oz|fluid ounces?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("fl oz"), latent: false })
+    );
+    b.rule_1_terminal("tbsp",
+                      b.reg(r#"tbsp|tablespoons?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("tbsp"), latent: false })
+    );
+    b.rule_1_terminal("tsp",
+                      b.reg(r#"tsp|teaspoons?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("tsp"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_mass(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("t",
+                      b.reg(r#"tonnes?|metric tons?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("t"), latent: false })
+    );
+    b.rule_1_terminal("kg",
+                      b.reg(r#"kilos?|kilogram(?:me)?s?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("kg"), latent: false })
+    );
+    b.rule_1_terminal("g",
+                      b.reg(r#"gram(?:me)?s?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("g"), latent: false })
+    );
+    b.rule_1_terminal("mg",
+                      b.reg(r#"milligram(?:me)?s?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("mg"), latent: false })
+    );
+    b.rule_1_terminal("lb",
+                      b.reg(r#"lbs?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("lb"), latent: false })
+    );
+    b.rule_1_terminal("lb (latent)",
+                      b.reg(r#"pounds?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("lb"), latent: true })
+    );
+    b.rule_1_terminal("oz",
+                      b.reg(r#"oz|ounces?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("oz"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_rate(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number> mph",
+             number_check!(),
+             b.reg(r#"mph"#)?,
+             |a, _| helpers::rate_per_duration(a.value().value(), Some("mi"), a.value().precision(), &UnitOfDurationValue::new(Grain::Hour)));
+    Ok(())
+}
+
+pub fn rules_quantity_range(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("between <number> and <number>",
+             b.reg(r#"between"#)?,
+             number_check!(),
+             b.reg(r#"and"#)?,
+             number_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <number>",
+             number_check_by_range!(0.0, 999.0),
+             b.reg(r#"\-"#)?,
+             number_check_by_range!(0.0, 999.0),
+             |start, _, end| Ok(helpers::quantity_between(start.value(), end.value())?.latent(true)));
+    b.rule_4("between <number> and <amount-of-money>",
+             b.reg(r#"between|from"#)?,
+             number_check!(),
+             b.reg(r#"and|to|\-"#)?,
+             amount_of_money_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <amount-of-money> and <amount-of-money>",
+             b.reg(r#"between|from"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"and|to|\-"#)?,
+             amount_of_money_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <number> and <temperature>",
+             b.reg(r#"between|from"#)?,
+             number_check!(),
+             b.reg(r#"and|to|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <temperature> and <temperature>",
+             b.reg(r#"between|from"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"and|to|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <ordinal> and <ordinal>",
+             b.reg(r#"between|from"#)?,
+             ordinal_check!(),
+             b.reg(r#"and|to|\-"#)?,
+             ordinal_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"\-|to|through"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"\-|to|through"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <temperature>",
+             number_check!(),
+             b.reg(r#"\-|to|through"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<temperature> - <temperature>",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"\-|to|through"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<ordinal> - <ordinal>",
+             ordinal_check!(),
+             b.reg(r#"\-|to|through"#)?,
+             ordinal_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_2("more than <number>",
+             b.reg(r#"(?:more|greater|higher) than|above|over"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <number>",
+             b.reg(r#"at least"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("more than <amount-of-money>",
+             b.reg(r#"(?:more|greater|higher) than|above|over"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <amount-of-money>",
+             b.reg(r#"at least"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("more than <temperature>",
+             b.reg(r#"(?:more|greater|higher) than|above|over"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <temperature>",
+             b.reg(r#"at least"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("less than <number>",
+             b.reg(r#"(?:less|fewer|lower) than|below|under"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <number>",
+             b.reg(r#"at most|up to"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("less than <amount-of-money>",
+             b.reg(r#"(?:less|fewer|lower) than|below|under"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <amount-of-money>",
+             b.reg(r#"at most|up to"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("less than <temperature>",
+             b.reg(r#"(?:less|fewer|lower) than|below|under"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <temperature>",
+             b.reg(r#"at most|up to"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("<number> or more",
+             number_check!(),
+             b.reg(r#"or (?:more|above)|and (?:more|above|up)"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<amount-of-money> or more",
+             amount_of_money_check!(),
+             b.reg(r#"or (?:more|above)|and (?:more|above|up)"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<temperature> or more",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"or (?:more|above)|and (?:more|above|up)"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<number> or less",
+             number_check!(),
+             b.reg(r#"or (?:less|fewer|below)|and (?:less|below|under)"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<amount-of-money> or less",
+             amount_of_money_check!(),
+             b.reg(r#"or (?:less|fewer|below)|and (?:less|below|under)"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<temperature> or less",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"or (?:less|fewer|below)|and (?:less|below|under)"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain};
+use rustling_ontology_moment::{Weekday, Grain, Calendar};
 
 
 // TODO: Add locales
@@ -195,6 +195,50 @@ pub fn rules_celebration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                               .form(Form::Celebration))
                       }
     );
+    b.rule_1_terminal("chinese new year",
+                      b.reg(r#"chinese new year(?:'s day)?|lunar new year|spring festival"#)?,
+                      |_| Ok(helpers::calendar_date(Calendar::Chinese, 1, 1)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("ramadan",
+                      b.reg(r#"(?:the month of )?rama[dz]an"#)?,
+                      |_| {
+                          let start = helpers::calendar_date(Calendar::Islamic, 9, 1)?;
+                          let end = helpers::calendar_date(Calendar::Islamic, 10, 1)?;
+                          Ok(start.span_to(&end, false)?
+                              .form(Form::Celebration))
+                      }
+    );
+    b.rule_1_terminal("eid al-fitr",
+                      b.reg(r#"eid(?: al| ul| el)?[- ]?fitr"#)?,
+                      |_| Ok(helpers::calendar_date(Calendar::Islamic, 10, 1)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("eid al-adha",
+                      b.reg(r#"eid(?: al| ul| el)?[- ]?adha"#)?,
+                      |_| Ok(helpers::calendar_date(Calendar::Islamic, 12, 10)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("rosh hashanah",
+                      b.reg(r#"rosh hash?anah?"#)?,
+                      |_| Ok(helpers::calendar_date(Calendar::Hebrew, 7, 1)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("yom kippur",
+                      b.reg(r#"yom kippur"#)?,
+                      |_| Ok(helpers::calendar_date(Calendar::Hebrew, 7, 10)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("hanukkah",
+                      b.reg(r#"c?hanukk?ah"#)?,
+                      |_| Ok(helpers::calendar_date(Calendar::Hebrew, 9, 25)?
+                          .form(Form::Celebration))
+    );
+    b.rule_1_terminal("passover",
+                      b.reg(r#"passover|pesach"#)?,
+                      |_| Ok(helpers::calendar_date(Calendar::Hebrew, 1, 15)?
+                          .form(Form::Celebration))
+    );
 
     Ok(())
 }
\ No newline at end of file
@@ -188,6 +188,36 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                  helpers::cycle_nth_after(Grain::Quarter, n, year.value())
              }
     );
+    b.rule_1_terminal("fiscal year <year>",
+                      b.reg(r#"(?:the )?(?:fy|fiscal year) ?'?(\d{4}|\d{2})"#)?,
+                      |text_match| helpers::fiscal_year(text_match.group(1).parse()?)
+    );
+    b.rule_1_terminal("Q1-4 fiscal year <year>",
+                      b.reg(r#"q([1-4]) ?(?:of )?(?:the )?(?:fy|fiscal year) ?'?(\d{4}|\d{2})"#)?,
+                      |text_match| helpers::fiscal_quarter(text_match.group(1).parse()?)?
+                          .intersect(&helpers::fiscal_year(text_match.group(2).parse()?)?)
+    );
+    b.rule_1_terminal("this|next|last fiscal year|quarter",
+                      b.reg(r#"(?:the )?(this|current|next|last|previous) fiscal (year|quarter|qtr)"#)?,
+                      |text_match| {
+                          let n = match text_match.group(1).as_ref() {
+                              "this" | "current" => 0,
+                              "next" => 1,
+                              _ => -1,
+                          };
+                          let grain = match text_match.group(2).as_ref() {
+                              "year" => Grain::Year,
+                              _ => Grain::Quarter,
+                          };
+                          helpers::fiscal_cycle_nth(grain, n)
+                      }
+    );
+    b.rule_2("<ordinal> fiscal quarter",
+             ordinal_check_by_range!(1, 4),
+             b.reg(r#"fiscal (?:quarter|qtr)"#)?,
+             |ordinal, _| helpers::fiscal_quarter(ordinal.value().value as u32)?
+                 .intersect(&helpers::fiscal_cycle_nth(Grain::Year, 0)?)
+    );
     /* END OF DATETIME - DATE - STANDALONE SINGLE GRAIN */
 
 
@@ -215,13 +245,48 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("end of week",
                       b.reg(r#"(?:the )?end of (?:the )?week"#)?,
-                      |_| helpers::day_of_week(Weekday::Thu)
-                          ?.span_to(&helpers::day_of_week(Weekday::Sun)?, false)
+                      |_| helpers::nth_day_of_week(3)
+                          ?.span_to(&helpers::nth_day_of_week(-1)?, false)
     );
     b.rule_1_terminal("by the end of the week",
                       b.reg(r#"by (?:the )?end of (?:the )?week"#)?,
                       |_| helpers::cycle_nth(Grain::Day, 0)?
-                          .span_to(&helpers::day_of_week(Weekday::Sun)?, true)
+                          .span_to(&helpers::nth_day_of_week(-1)?, true)
+    );
+    b.rule_1_terminal("week <number> (ISO 8601)",
+                      b.reg(r#"(?:the )?(?:calendar )?week (?:number |no\.? ?|#)?(\d{1,2})"#)?,
+                      |text_match| helpers::week_of_year(text_match.group(1).parse()?)
+    );
+    b.rule_1_terminal("next business day",
+                      b.reg(r#"(?:the )?next (?:business|working|work) ?day"#)?,
+                      |_| helpers::business_day_nth(0)
+    );
+    b.rule_1_terminal("previous business day",
+                      b.reg(r#"(?:the )?(?:previous|last) (?:business|working|work) ?day"#)?,
+                      |_| helpers::business_day_nth(-1)
+    );
+    b.rule_3("in <integer> business days",
+             b.reg(r#"in"#)?,
+             integer_check_by_range!(1),
+             b.reg(r#"(?:business|working|work) ?days?"#)?,
+             |_, integer, _| helpers::business_day_nth(integer.value().value - 1)
+    );
+    b.rule_2("<integer> business days from now",
+             integer_check_by_range!(1),
+             b.reg(r#"(?:business|working|work) ?days? (?:from (?:now|today)|later)"#)?,
+             |integer, _| helpers::business_day_nth(integer.value().value - 1)
+    );
+    b.rule_1_terminal("end of business",
+                      b.reg(r#"(?:the )?(?:end|close) of (?:the )?business(?: day)?|eob|cob"#)?,
+                      |_| helpers::end_of_business()
+    );
+    b.rule_1_terminal("end of business today",
+                      b.reg(r#"(?:(?:the )?(?:end|close) of (?:the )?business(?: day)?|eob|cob) today"#)?,
+                      |_| helpers::cycle_nth(Grain::Day, 0)?.intersect(&helpers::end_of_business()?)
+    );
+    b.rule_1_terminal("business hours",
+                      b.reg(r#"(?:during )?(?:the )?(?:business|working|office) hours"#)?,
+                      |_| helpers::business_hours()
     );
     b.rule_1_terminal("EOD|End of day",
                       b.reg(r#"(?:eod|end of (?:the )?day)"#)?,
@@ -703,7 +768,8 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     /* Notice for accepted forms and resolution:
     Non ambiguous month-day combinations: 13<=d<=31 && 01<=m<=12
-    Ambiguous month-day combinations: 01<=d<=12 && 01<=m<=12
+    Ambiguous month-day combinations: 01<=d<=12 && 01<=m<=12, read in the date order of the
+    locale of the resolver context
     regexes:
     month and ambiguous day: (0?[1-9]|1[0-2])
     non ambiguous day: (1[3-9]|2\d|3[01])
@@ -725,13 +791,31 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           text_match.group(2).parse()?)
     );
 // Date - written form only
-    b.rule_1_terminal("mm/dd/yy or mm/dd/yyyy - Ambiguous cases - interpret as US standard",
+    b.rule_1_terminal("mm/dd/yy or mm/dd/yyyy - Ambiguous cases - month first",
                       b.reg(r#"(0?[1-9]|1[0-2])[-/\.](0?[1-9]|1[0-2])[-/\.](\d{2,4})"#)?,
                       |text_match| helpers::year_month_day(
                           text_match.group(3).parse()?,
                           text_match.group(1).parse()?,
                           text_match.group(2).parse()?)
     );
+// Date - written form only
+    b.rule_1_terminal("dd/mm/yy or dd/mm/yyyy - Ambiguous cases - day first",
+                      b.reg(r#"(0?[1-9]|1[0-2])[-/\.](0?[1-9]|1[0-2])[-/\.](\d{2,4})"#)?,
+                      |text_match| Ok(helpers::year_month_day(
+                          text_match.group(3).parse()?,
+                          text_match.group(2).parse()?,
+                          text_match.group(1).parse()?)?
+                          .date_order(Some(DateOrder::DayMonthYear)))
+    );
+// Date - written form only
+    b.rule_1_terminal("yy/mm/dd - Ambiguous cases - year first",
+                      b.reg(r#"(\d{2})[-/\.](0?[1-9]|1[0-2])[-/\.](0?[1-9]|[12]\d|3[01])"#)?,
+                      |text_match| Ok(helpers::year_month_day(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?,
+                          text_match.group(3).parse()?)?
+                          .date_order(Some(DateOrder::YearMonthDay)))
+    );
 // Date - written form only
     b.rule_1_terminal("dd/mm - Non ambiguous cases - Non US standard",
                       b.reg(r#"(1[3-9]|2\d|3[01])[/\.](0?[1-9]|1[0-2])"#)?,
@@ -747,12 +831,20 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           text_match.group(2).parse()?)
     );
 // Date - written form only
-    b.rule_1_terminal("mm/dd - Ambiguous cases - interpret as US standard",
+    b.rule_1_terminal("mm/dd - Ambiguous cases - month first",
                       b.reg(r#"(0?[1-9]|1[0-2])[/\.](0?[1-9]|1[0-2])"#)?,
                       |text_match| helpers::month_day(
                           text_match.group(1).parse()?,
                           text_match.group(2).parse()?)
     );
+// Date - written form only
+    b.rule_1_terminal("dd/mm - Ambiguous cases - day first",
+                      b.reg(r#"(0?[1-9]|1[0-2])[/\.](0?[1-9]|1[0-2])"#)?,
+                      |text_match| Ok(helpers::month_day(
+                          text_match.group(2).parse()?,
+                          text_match.group(1).parse()?)?
+                          .date_order(Some(DateOrder::DayMonthYear)))
+    );
     /* END OF DATETIME - DATE - DATES - WRITTEN FORMS */
 
     /* DATETIME - TIME - PARTS OF DAY */
@@ -969,13 +1061,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     b.rule_1_terminal("week-end - Hour grain, from Friday evening to Sunday midnight",
                       b.reg(r#"(?:the )?(?:week(?:\s|-)?end|wkend)"#)?,
-                      |_| {
-                          let friday = helpers::day_of_week(Weekday::Fri)?
-                              .intersect(&helpers::hour(18, false)?)?;
-                          let monday = helpers::day_of_week(Weekday::Mon)?
-                              .intersect(&helpers::hour(0, false)?)?;
-                          Ok(friday.span_to(&monday, false)?.datetime_kind(DatetimeKind::DatePeriod))
-                      }
+                      |_| helpers::weekend()
     );
     b.rule_1_terminal("season - summer",
                       b.reg(r#"(?:the )?summer"#)?,
@@ -1509,3 +1595,37 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_recurrence(b, &rustling_ontology_values::rules::RecurrenceWords {
+        every: r#"every|each"#,
+        every_other: Some(r#"every (?:other|second)"#),
+        every_integer: Some(r#"every"#),
+        hourly: r#"hourly"#,
+        daily: r#"daily"#,
+        weekly: r#"weekly"#,
+        monthly: r#"monthly"#,
+        quarterly: Some(r#"quarterly"#),
+        yearly: r#"yearly|annually"#,
+        and: Some(r#"and"#),
+        until: Some(r#"until|till|through"#),
+        times: Some(r#"times"#),
+    })?;
+    b.rule_1_terminal("every <day-of-week> (plural)",
+                      b.reg(r#"(?:on )?(mon|tues|wednes|thurs|fri|satur|sun)days"#)?,
+                      |text_match| {
+                          let weekday = match text_match.group(1) {
+                              "mon" => Weekday::Mon,
+                              "tues" => Weekday::Tue,
+                              "wednes" => Weekday::Wed,
+                              "thurs" => Weekday::Thu,
+                              "fri" => Weekday::Fri,
+                              "satur" => Weekday::Sat,
+                              "sun" => Weekday::Sun,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          helpers::recurrence(&helpers::day_of_week(weekday)?, 1)
+                      }
+    );
+    Ok(())
+}
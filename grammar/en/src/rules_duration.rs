@@ -185,5 +185,51 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"exactly|precisely"#)?,
              |duration, _| Ok(duration.value().clone().precision(Precision::Exact))
     );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             unit_of_duration_check!(),
+             |number, uod| helpers::fractional_duration(number.value(), uod.value())
+    );
+    b.rule_3("<fraction> of <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             b.reg(r#"of an?"#)?,
+             unit_of_duration_check!(),
+             |number, _, uod| helpers::fractional_duration(number.value(), uod.value())
+    );
+    Ok(())
+}
+
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_3("<number> <unit-of-duration> old",
+             number_check!(),
+             unit_of_duration_check!(),
+             b.reg(r#"old"#)?,
+             |number, uod, _| helpers::age(number.value(), uod.value().grain)
+    );
+    b.rule_3("<number> <unit-of-duration> of age",
+             number_check!(),
+             unit_of_duration_check!(),
+             b.reg(r#"of age"#)?,
+             |number, uod, _| helpers::age(number.value(), uod.value().grain)
+    );
+    b.rule_2("<number>-<unit>-old",
+             number_check!(),
+             b.reg(r#"-?(year|month|week|day)s?-old"#)?,
+             |number, text_match| {
+                 let unit = match text_match.group(1) {
+                     "year" => Grain::Year,
+                     "month" => Grain::Month,
+                     "week" => Grain::Week,
+                     "day" => Grain::Day,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::age(number.value(), unit)
+             }
+    );
+    b.rule_2("aged <number>",
+             b.reg(r#"aged|age of"#)?,
+             number_check!(),
+             |_, number| helpers::age(number.value(), Grain::Year)
+    );
     Ok(())
-}
\ No newline at end of file
+}
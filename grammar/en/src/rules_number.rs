@@ -182,16 +182,6 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                      ..FloatValue::default()
                  })
              });
-    b.rule_2("<integer> and a half",
-             integer_check!(),
-             b.reg(r#"and a half"#)?,
-             |integer, _| FloatValue::new(integer.value().value as f64 + 0.5)
-    );
-    b.rule_2("<integer> and a quarter",
-             integer_check!(),
-             b.reg(r#"and a quarter"#)?,
-             |integer, _| FloatValue::new(integer.value().value as f64 + 0.25)
-    );
     b.rule_3("number dot number",
              integer_check!(|integer: &IntegerValue| !integer.prefixed),
              b.reg(r#"dot|point"#)?,
@@ -443,3 +433,55 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |_, ordinal| Ok((*ordinal.value()).prefixed()));
     Ok(())
 }
+
+pub fn rules_fraction(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("<numerator>/<denominator> (latent)",
+             b.reg(r#"(\d+)/(\d+)"#)?,
+             |text_match| Ok(helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)?.latent(true)));
+    b.rule_2("<integer> <denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(half|halve|third|quarter|fourth|fifth|sixth|seventh|eighth|ninth|tenth)s?"#)?,
+             |integer, text_match| {
+                 let denominator = match text_match.group(1).as_ref() {
+                     "half" | "halve" => 2,
+                     "third" => 3,
+                     "quarter" | "fourth" => 4,
+                     "fifth" => 5,
+                     "sixth" => 6,
+                     "seventh" => 7,
+                     "eighth" => 8,
+                     "ninth" => 9,
+                     "tenth" => 10,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::fraction(integer.value().value, denominator)
+             });
+    b.rule_1_terminal("a <denominator>",
+                      b.reg(r#"an? (half|third|quarter)"#)?,
+                      |text_match| {
+                          let denominator = match text_match.group(1).as_ref() {
+                              "half" => 2,
+                              "third" => 3,
+                              "quarter" => 4,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          helpers::fraction(1, denominator)
+                      });
+    b.rule_3("<integer> and <fraction>",
+             integer_check_by_range!(0),
+             b.reg(r#"and"#)?,
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() < 1.0),
+             |integer, _, fraction| helpers::mixed_number(integer.value(), fraction.value()));
+    b.rule_2("<integer> <fraction>",
+             integer_check_by_range!(0),
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() < 1.0),
+             |integer, fraction| helpers::mixed_number(integer.value(), fraction.value()));
+    Ok(())
+}
+
+pub fn rules_roman_numerals(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_roman_numerals(b, &rustling_ontology_values::rules::RomanNumeralWords {
+        nouns: r#"chapter|part|volume|vol\.?|book|act|scene|article|section|title|appendix|annex|phase|stage|grade|level|class|type"#,
+        names: r#"henry|george|edward|elizabeth|james|william|richard|philip|frederick|pope (?:pius|leo|benedict|gregory|john paul|john)|world war"#,
+    })
+}
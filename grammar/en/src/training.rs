@@ -47,7 +47,7 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 
 // TODO: Sort out and split by datetime subtype
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 0]), "now", "right now", "just now", "at this time");
     example!(v, check_moment!(c, [2013, 2, 12]), "today");
     example!(v, check_moment!(c, [2013, 2, 11]), "yesterday");
@@ -82,6 +82,17 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 13]), "Wed, Feb13");
     example!(v, check_moment!(c, [2013, 2, 18]), "Monday, Feb 18", "Mon, February 18");
     example!(v, check_moment!(c, [2013, 2, 11], Grain::Week), "this week", "current week", "coming week");
+    example!(v, check_moment!(c, [2013, 10, 14], Grain::Week), "week 42", "week number 42", "calendar week 42");
+    example!(v, check_moment!(c, [2013, 2, 13]), "next business day", "the next working day", "next workday");
+    example!(v, check_moment!(c, [2013, 2, 11]), "previous business day", "last working day");
+    example!(v, check_moment!(c, [2013, 2, 15]), "in 3 business days", "3 working days from now");
+    example!(v, check_moment!(c, [2013, 2, 19]), "in five business days");
+    example!(v, check_moment!(c, [2013, 2, 12, 17, 0]), "end of business today", "close of business", "EOB");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 9], [2013, 2, 12, 17]), "during business hours", "office hours");
+    example!(v, check_moment!(c, [2014]), "FY2014", "fiscal year 2014", "FY14", "FY '14");
+    example!(v, check_moment!(c, [2014, 7, 1], Grain::Quarter), "Q3 FY14", "q3 of fiscal year 2014");
+    example!(v, check_moment!(c, [2013, 4, 1], Grain::Quarter), "next fiscal quarter", "2nd fiscal quarter");
+    example!(v, check_moment!(c, [2013]), "this fiscal year", "the current fiscal year");
     example!(v, check_moment!(c, [2013, 2, 4], Grain::Week), "last week", "past week", "previous week");
     example!(v, check_moment!(c, [2013, 2, 18], Grain::Week), "next week", "the following week");
     example!(v, check_moment!(c, [2013, 1]), "last month");
@@ -174,6 +185,14 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 8, 30, 18], [2013, 9, 3, 0]), "labor day weekend");
     example!(v, check_moment!(c, [2013, 10, 31]), "halloween");
     example!(v, check_moment!(c, [2013, 11, 28]), "thanksgiving day", "thanksgiving");
+    example!(v, check_moment!(c, [2014, 1, 31]), "chinese new year", "lunar new year");
+    example!(v, check_moment_span!(c, [2013, 7, 9], [2013, 8, 8]), "ramadan", "the month of ramadan");
+    example!(v, check_moment!(c, [2013, 8, 8]), "eid al-fitr", "eid ul fitr");
+    example!(v, check_moment!(c, [2013, 10, 15]), "eid al-adha");
+    example!(v, check_moment!(c, [2013, 9, 5]), "rosh hashanah", "rosh hashana");
+    example!(v, check_moment!(c, [2013, 9, 14]), "yom kippur");
+    example!(v, check_moment!(c, [2013, 11, 28]), "hanukkah", "chanukah");
+    example!(v, check_moment!(c, [2013, 3, 26]), "passover", "pesach");
     example!(v, check_moment_span!(c, [2013, 2, 12, 18], [2013, 2, 13, 0]), "this evening", "tonight");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 29, 58], [2013, 2, 12, 4, 30, 00]), "last 2 seconds", "last two seconds");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4, 30, 01], [2013, 2, 12, 4, 30, 04]), "next 3 seconds", "next three seconds");
@@ -308,3 +327,112 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(2), "the 2nd", "2nd", "second");
     example!(v, check_ordinal(21), "the twenty first");
 }
+
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(5.0, Some("km"), Precision::Exact), "5 km", "5km", "five kilometers", "5 kilometres");
+    example!(v, check_distance(200.0, Some("m"), Precision::Exact), "200 meters", "two hundred metres");
+    example!(v, check_distance(3.0, Some("mi"), Precision::Exact), "three miles", "3 mi");
+    example!(v, check_distance(1.0, Some("mi"), Precision::Exact), "a mile", "one mile");
+    example!(v, check_distance(12.0, Some("cm"), Precision::Exact), "12 cm", "twelve centimeters");
+    example!(v, check_distance(4.0, Some("mm"), Precision::Exact), "4mm", "four millimetres");
+    example!(v, check_distance(100.0, Some("yd"), Precision::Exact), "100 yards", "100 yds");
+    example!(v, check_distance(6.0, Some("ft"), Precision::Exact), "six feet", "6 ft", "6 foot");
+    example!(v, check_distance(2.0, Some("in"), Precision::Exact), "two inches", "2 inches");
+    example!(v, check_distance(10.0, Some("km"), Precision::Approximate), "about ten kilometers", "around 10 km");
+    example!(v, check_distance(400.0, Some("m"), Precision::Exact), "exactly 400 meters");
+}
+
+pub fn examples_volume(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_volume(2.0, Some("l"), Precision::Exact), "2 litres", "two liters", "2l");
+    example!(v, check_volume(500.0, Some("ml"), Precision::Exact), "500 ml", "five hundred milliliters");
+    example!(v, check_volume(25.0, Some("cl"), Precision::Exact), "25 cl");
+    example!(v, check_volume(1.0, Some("gal"), Precision::Exact), "a gallon", "one gallon");
+    example!(v, check_volume(3.0, Some("cup"), Precision::Exact), "three cups", "3 cups");
+    example!(v, check_volume(2.0, Some("tbsp"), Precision::Exact), "two tablespoons", "2 tbsp");
+    example!(v, check_volume(1.0, Some("tsp"), Precision::Exact), "a teaspoon", "1 tsp");
+    example!(v, check_volume(2.0, Some("pt"), Precision::Exact), "two pints");
+    example!(v, check_volume(8.0, Some("fl oz"), Precision::Exact), "8 fl oz", "eight fluid ounces");
+    example!(v, check_volume(1.0, Some("l"), Precision::Approximate), "about one liter", "around 1 l");
+}
+
+pub fn examples_mass(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_mass(500.0, Some("g"), Precision::Exact), "500 grams", "500 grammes", "500g", "five hundred grams");
+    example!(v, check_mass(1.0, Some("lb"), Precision::Exact), "one pound", "1 lb", "1 lbs");
+    example!(v, check_mass(2.0, Some("kg"), Precision::Exact), "2 kg", "two kilos", "two kilograms");
+    example!(v, check_mass(250.0, Some("mg"), Precision::Exact), "250 mg");
+    example!(v, check_mass(3.0, Some("oz"), Precision::Exact), "three ounces", "3 oz");
+    example!(v, check_mass(1.0, Some("t"), Precision::Exact), "one tonne");
+    example!(v, check_mass(5.0, Some("kg"), Precision::Approximate), "about 5 kg", "almost five kilos");
+    example!(v, check_mass(100.0, Some("g"), Precision::Exact), "exactly 100 grams");
+}
+
+pub fn examples_rate(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_rate(60.0, Some("mi"), "hour", Precision::Exact), "60 miles per hour", "sixty miles an hour", "60 mph");
+    example!(v, check_rate(10.0, Some("km"), "hour", Precision::Exact), "10 km/h", "ten kilometers per hour", "10 kph");
+    example!(v, check_rate(5.0, Some("minute"), "km", Precision::Exact), "5 minutes per kilometre", "five minutes per km");
+    example!(v, check_rate(3.0, None, "day", Precision::Exact), "3 per day", "three a day");
+    example!(v, check_rate(50.0, Some("km"), "hour", Precision::Approximate), "about 50 km/h");
+}
+
+pub fn examples_quantity_range(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity_range(QuantityKind::Number, Some(25.0), None), "more than 25", "over twenty five", "25 or more");
+    example!(v, check_quantity_range(QuantityKind::Number, None, Some(3.0)), "less than 3", "fewer than three");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(5.0), Some(10.0)), "between 5 and 10", "between five and ten");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(3.0), Some(4.0)), "3-4", "3 - 4");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(5.0), None), "at least 5", "five or more");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("$") }, None, Some(50.0)), "up to 50 dollars", "at most $50");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("EUR") }, Some(20.0), Some(30.0)), "between 20 and 30 euros", "from 20 to 30 euros");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("$") }, None, Some(50.0)), "under 50 dollars", "less than fifty dollars");
+    example!(v, check_quantity_range(QuantityKind::Temperature { unit: Some("degree") }, Some(25.0), None), "above 25 degrees", "more than twenty five degrees");
+    example!(v, check_quantity_range(QuantityKind::Ordinal, Some(3.0), Some(5.0)), "3rd to 5th", "between third and fifth");
+}
+
+pub fn examples_fraction(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_fraction(3, 4), "three quarters", "three fourths");
+    example!(v, check_fraction(1, 2), "a half", "one half");
+    example!(v, check_fraction(2, 3), "two thirds");
+    example!(v, check_fraction(5, 2), "two and a half", "2 1/2");
+    example!(v, check_fraction(3, 2), "one and a half");
+    example!(v, check_fraction(11, 4), "two and three quarters");
+    example!(v, check_duration!([0, 0, 0, 0, 1, 30]), "one and a half hours", "1 1/2 hours");
+    example!(v, check_duration!([0, 0, 0, 2, 12]), "two and a half days");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 45]), "three quarters of an hour");
+    example!(v, check_finance(2.5, Some("EUR"), Precision::Exact), "two and a half euros");
+}
+
+pub fn examples_roman_numerals(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_integer(4), "chapter iv", "Chapter IV");
+    example!(v, check_integer(12), "volume xii");
+    example!(v, check_ordinal(14), "louis xiv", "Louis XIV");
+    example!(v, check_ordinal(4), "henri iv");
+    example!(v, check_ordinal(2), "world war ii", "World War II");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("+33612345678"), "+33 6 12 34 56 78", "0033 6 12 34 56 78", "+33612345678");
+    example!(v, check_phone_number("4155550123"), "(415) 555-0123", "415-555-0123", "415.555.0123");
+    example!(v, check_email("john.doe@gmail.com"), "john.doe@gmail.com");
+    example!(v, check_email("john.doe@gmail.com"), "john dot doe at gmail dot com");
+    example!(v, check_url("https://www.example.com/path"), "https://www.example.com/path");
+    example!(v, check_url("www.example.com"), "www.example.com");
+    example!(v, check_url("example.com"), "example.com");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Mon]), "every monday", "each monday", "mondays", "on mondays", "every monday at 5pm");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Mon, Weekday::Wed]), "every monday and wednesday", "every monday and wednesday at 5pm");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 2, vec![Weekday::Fri]), "every other friday");
+    example!(v, check_recurrence(c.clone(), Grain::Day, 1, vec![]), "every day", "daily", "each day at 8am", "every morning");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 2, vec![]), "every 2 weeks", "every other week");
+    example!(v, check_recurrence(c.clone(), Grain::Month, 1, vec![]), "every month", "monthly");
+    example!(v, check_recurrence(c.clone(), Grain::Month, 3, vec![]), "quarterly");
+    example!(v, check_recurrence(c.clone(), Grain::Year, 1, vec![]), "every year", "yearly", "annually");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Tue]), "every tuesday until march", "every tuesday 5 times");
+}
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age(7.0, Grain::Year), "7 years old", "seven years old", "7-year-old", "aged 7", "7 years of age");
+    example!(v, check_age(18.0, Grain::Month), "18 months old", "18-month-old");
+    example!(v, check_age(3.0, Grain::Year), "3-year-old", "three-year-old");
+}
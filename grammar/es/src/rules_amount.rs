@@ -242,3 +242,258 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              });
     Ok(())
 }
+
+pub fn rules_quantities(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_quantities(b, &rustling_ontology_values::rules::QuantityWords {
+        one: r#"un[oa]?"#,
+        approximately: r#"aproximadamente|sobre|cerca de|casi|un[oa]s"#,
+        exactly: r#"exactamente"#,
+        per: r#"por|/|a la"#,
+    })
+}
+
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("km",
+                      b.reg(r#"kil[oó]metros?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("km"), latent: false })
+    );
+    b.rule_1_terminal("m",
+                      b.reg(r#"metros?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("m"), latent: false })
+    );
+    b.rule_1_terminal("cm",
+                      b.reg(r#"cent[ií]metros?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("cm"), latent: false })
+    );
+    b.rule_1_terminal("mm",
+                      b.reg(r#"mil[ií]metros?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("mm"), latent: false })
+    );
+    b.rule_1_terminal("mi",
+                      b.reg(r#"millas?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("mi"), latent: false })
+    );
+    b.rule_1_terminal("yd",
+                      b.reg(r#"yardas?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("yd"), latent: false })
+    );
+    b.rule_1_terminal("ft",
+                      b.reg(r#"pies?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("ft"), latent: false })
+    );
+    b.rule_1_terminal("in",
+                      b.reg(r#"pulgadas?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("in"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_volume(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("l",
+                      b.reg(r#"litros?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("l"), latent: false })
+    );
+    b.rule_1_terminal("dl",
+                      b.reg(r#"decilitros?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("dl"), latent: false })
+    );
+    b.rule_1_terminal("cl",
+                      b.reg(r#"centilitros?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("cl"), latent: false })
+    );
+    b.rule_1_terminal("ml",
+                      b.reg(r#"mililitros?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("ml"), latent: false })
+    );
+    b.rule_1_terminal("gal",
+                      b.reg(r#"galones|gal[oó]n"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("gal"), latent: false })
+    );
+    b.rule_1_terminal("pt",
+                      b.reg(r#"pintas?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("pt"), latent: false })
+    );
+    b.rule_1_terminal("cup",
+                      b.reg(r#"tazas?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("cup"), latent: false })
+    );
+    b.rule_1_terminal("tbsp",
+                      b.reg(r#"cucharadas?(?: soperas?)?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("tbsp"), latent: false })
+    );
+    b.rule_1_terminal("tsp",
+                      b.reg(r#"cucharaditas?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("tsp"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_mass(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("t",
+                      b.reg(r#"toneladas?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("t"), latent: false })
+    );
+    b.rule_1_terminal("kg",
+                      b.reg(r#"kilos?|kilogramos?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("kg"), latent: false })
+    );
+    b.rule_1_terminal("g",
+                      b.reg(r#"gramos?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("g"), latent: false })
+    );
+    b.rule_1_terminal("mg",
+                      b.reg(r#"miligramos?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("mg"), latent: false })
+    );
+    b.rule_1_terminal("lb",
+                      b.reg(r#"libras?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("lb"), latent: false })
+    );
+    b.rule_1_terminal("oz",
+                      b.reg(r#"onzas?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("oz"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_quantity_range(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("between <number> and <number>",
+             b.reg(r#"entre"#)?,
+             number_check!(),
+             b.reg(r#"y"#)?,
+             number_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <number>",
+             number_check_by_range!(0.0, 999.0),
+             b.reg(r#"\-"#)?,
+             number_check_by_range!(0.0, 999.0),
+             |start, _, end| Ok(helpers::quantity_between(start.value(), end.value())?.latent(true)));
+    b.rule_4("between <number> and <amount-of-money>",
+             b.reg(r#"entre|de|desde"#)?,
+             number_check!(),
+             b.reg(r#"y|a|hasta|\-"#)?,
+             amount_of_money_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <amount-of-money> and <amount-of-money>",
+             b.reg(r#"entre|de|desde"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"y|a|hasta|\-"#)?,
+             amount_of_money_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <number> and <temperature>",
+             b.reg(r#"entre|de|desde"#)?,
+             number_check!(),
+             b.reg(r#"y|a|hasta|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <temperature> and <temperature>",
+             b.reg(r#"entre|de|desde"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"y|a|hasta|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <ordinal> and <ordinal>",
+             b.reg(r#"entre|de|desde"#)?,
+             ordinal_check!(),
+             b.reg(r#"y|a|hasta|\-"#)?,
+             ordinal_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"\-|a|hasta"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"\-|a|hasta"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <temperature>",
+             number_check!(),
+             b.reg(r#"\-|a|hasta"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<temperature> - <temperature>",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"\-|a|hasta"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<ordinal> - <ordinal>",
+             ordinal_check!(),
+             b.reg(r#"\-|a|hasta"#)?,
+             ordinal_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_2("more than <number>",
+             b.reg(r#"m[aá]s (?:de|que)|por encima de|superior a"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <number>",
+             b.reg(r#"al menos|como m[ií]nimo"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("more than <amount-of-money>",
+             b.reg(r#"m[aá]s (?:de|que)|por encima de|superior a"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <amount-of-money>",
+             b.reg(r#"al menos|como m[ií]nimo"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("more than <temperature>",
+             b.reg(r#"m[aá]s (?:de|que)|por encima de|superior a"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <temperature>",
+             b.reg(r#"al menos|como m[ií]nimo"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("less than <number>",
+             b.reg(r#"menos (?:de|que)|por debajo de|inferior a"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <number>",
+             b.reg(r#"como m[aá]ximo|hasta"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("less than <amount-of-money>",
+             b.reg(r#"menos (?:de|que)|por debajo de|inferior a"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <amount-of-money>",
+             b.reg(r#"como m[aá]ximo|hasta"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("less than <temperature>",
+             b.reg(r#"menos (?:de|que)|por debajo de|inferior a"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <temperature>",
+             b.reg(r#"como m[aá]ximo|hasta"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("<number> or more",
+             number_check!(),
+             b.reg(r#"o m[aá]s|y m[aá]s"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<amount-of-money> or more",
+             amount_of_money_check!(),
+             b.reg(r#"o m[aá]s|y m[aá]s"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<temperature> or more",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"o m[aá]s|y m[aá]s"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<number> or less",
+             number_check!(),
+             b.reg(r#"o menos|y menos"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<amount-of-money> or less",
+             amount_of_money_check!(),
+             b.reg(r#"o menos|y menos"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<temperature> or less",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"o menos|y menos"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    Ok(())
+}
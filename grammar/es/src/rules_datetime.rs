@@ -569,6 +569,21 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           text_match.group(2).parse()?,
                           text_match.group(1).parse()?)
     );
+    b.rule_1_terminal("mm/dd/yy or mm/dd/yyyy - Ambiguous cases - month first",
+                      b.reg(r#"(0?[1-9]|1[0-2])[-\./](0?[1-9]|1[0-2])[-\./](\d{2,4})"#)?,
+                      |text_match| Ok(helpers::year_month_day(
+                          text_match.group(3).parse()?,
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)?
+                          .date_order(Some(DateOrder::MonthDayYear)))
+    );
+    b.rule_1_terminal("mm/dd - Ambiguous cases - month first",
+                      b.reg(r#"(0?[1-9]|1[0-2])[\./](0?[1-9]|1[0-2])"#)?,
+                      |text_match| Ok(helpers::month_day(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)?
+                          .date_order(Some(DateOrder::MonthDayYear)))
+    );
     // End of Written dates in numeric formats
     b.rule_1_terminal("beginning of day",
                       b.reg(r#"al (?:inicio|empezar) d?el d[iíì]a|a primera hora"#)?,
@@ -780,8 +795,12 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("end of week (not weekend)",
                       b.reg(r#"a finales de la semana"#)?,
-                      |_| helpers::day_of_week(Weekday::Thu)
-                          ?.span_to(&helpers::day_of_week(Weekday::Sun)?, false)
+                      |_| helpers::nth_day_of_week(3)
+                          ?.span_to(&helpers::nth_day_of_week(-1)?, false)
+    );
+    b.rule_1_terminal("week <number> (ISO 8601)",
+                      b.reg(r#"(?:la )?semana (?:n[°º] ?|n[úu]mero )?(\d{1,2})"#)?,
+                      |text_match| helpers::week_of_year(text_match.group(1).parse()?)
     );
     b.rule_1_terminal("during the week",
                       b.reg(r#"durante la semana"#)?,
@@ -790,13 +809,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("week-end",
                       b.reg(r#"week[ -]?end|fin(?:de)?(?: de semana)?"#)?,
-                      |_| {
-                          let friday = helpers::day_of_week(Weekday::Fri)?
-                              .intersect(&helpers::hour(18, false)?)?;
-                          let monday = helpers::day_of_week(Weekday::Mon)?
-                              .intersect(&helpers::hour(0, false)?)?;
-                          friday.span_to(&monday, false)
-                      }
+                      |_| helpers::weekend()
     );
     b.rule_1_terminal("season - summer",
                       b.reg(r#"verano"#)?,
@@ -1152,3 +1165,37 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_recurrence(b, &rustling_ontology_values::rules::RecurrenceWords {
+        every: r#"cada|todos los|todas las"#,
+        every_other: Some(r#"cada dos|una de cada dos"#),
+        every_integer: Some(r#"cada"#),
+        hourly: r#"cada hora"#,
+        daily: r#"diariamente|a diario"#,
+        weekly: r#"semanalmente"#,
+        monthly: r#"mensualmente"#,
+        quarterly: Some(r#"trimestralmente"#),
+        yearly: r#"anualmente"#,
+        and: Some(r#"y|e"#),
+        until: Some(r#"hasta(?: el)?"#),
+        times: Some(r#"veces"#),
+    })?;
+    b.rule_1_terminal("every <day-of-week> (plural)",
+                      b.reg(r#"(?:todos )?los (lunes|martes|mi[eé]rcoles|jueves|viernes|s[aá]bados|domingos)"#)?,
+                      |text_match| {
+                          let weekday = match text_match.group(1) {
+                              "lunes" => Weekday::Mon,
+                              "martes" => Weekday::Tue,
+                              "jueves" => Weekday::Thu,
+                              "viernes" => Weekday::Fri,
+                              "domingos" => Weekday::Sun,
+                              day if day.starts_with("mi") => Weekday::Wed,
+                              day if day.starts_with("s") => Weekday::Sat,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          helpers::recurrence(&helpers::day_of_week(weekday)?, 1)
+                      }
+    );
+    Ok(())
+}
@@ -114,5 +114,32 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check_by_range!(0),
              |duration, integer| helpers::compose_duration_with_integer(duration.value(), integer.value())
     );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             unit_of_duration_check!(),
+             |number, uod| helpers::fractional_duration(number.value(), uod.value())
+    );
+    b.rule_3("<fraction> of <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             b.reg(r#"de"#)?,
+             unit_of_duration_check!(),
+             |number, _, uod| helpers::fractional_duration(number.value(), uod.value())
+    );
+    Ok(())
+}
+
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_3("tengo <number> <unit-of-duration>",
+             b.reg(r#"tengo|tienes|tiene|tenemos|ten[eé]is|tienen"#)?,
+             number_check!(),
+             unit_of_duration_check!(|uod: &UnitOfDurationValue| uod.grain == Grain::Year || uod.grain == Grain::Month),
+             |_, number, uod| helpers::age(number.value(), uod.value().grain)
+    );
+    b.rule_3("<number> <unit-of-duration> de edad",
+             number_check!(),
+             unit_of_duration_check!(),
+             b.reg(r#"de edad"#)?,
+             |number, uod, _| helpers::age(number.value(), uod.value().grain)
+    );
     Ok(())
 }
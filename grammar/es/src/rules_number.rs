@@ -421,3 +421,43 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_fraction(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("<numerator>/<denominator> (latent)",
+             b.reg(r#"(\d+)/(\d+)"#)?,
+             |text_match| Ok(helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)?.latent(true)));
+    b.rule_2("<integer> <denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(medio|tercio|cuarto|quinto|sexto|s[eé]ptimo|octavo|noveno|d[eé]cimo)s?"#)?,
+             |integer, text_match| {
+                 let denominator = match text_match.group(1).as_ref() {
+                     "medio" => 2,
+                     "tercio" => 3,
+                     "cuarto" => 4,
+                     "quinto" => 5,
+                     "sexto" => 6,
+                     "septimo" | "séptimo" => 7,
+                     "octavo" => 8,
+                     "noveno" => 9,
+                     "decimo" | "décimo" => 10,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::fraction(integer.value().value, denominator)
+             });
+    b.rule_2("<integer> and a half",
+             integer_check_by_range!(0),
+             b.reg(r#"y medi[oa]"#)?,
+             |integer, _| helpers::fraction(integer.value().value * 2 + 1, 2));
+    b.rule_2("<integer> <fraction>",
+             integer_check_by_range!(0),
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() < 1.0),
+             |integer, fraction| helpers::mixed_number(integer.value(), fraction.value()));
+    Ok(())
+}
+
+pub fn rules_roman_numerals(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_roman_numerals(b, &rustling_ontology_values::rules::RomanNumeralWords {
+        nouns: r#"cap[íi]tulo|parte|tomo|volumen|libro|acto|escena|art[íi]culo|secci[óo]n|t[íi]tulo|anexo|fase|etapa|nivel|clase|tipo"#,
+        names: r#"luis|enrique|carlos|felipe|fernando|alfonso|isabel|pedro|juan carlos|papa (?:p[íi]o|le[óo]n|benedicto|gregorio|juan pablo|juan)"#,
+    })
+}
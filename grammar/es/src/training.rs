@@ -4,7 +4,7 @@ use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     // Days
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 00]), "ahora", "ahora mismo", "en este preciso momento", "en este preciso istante", "inmediatamente");
     example!(v, check_moment!(c, [2013, 2, 12]), "hoy", "en este momento");
@@ -53,6 +53,7 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 13]), "miércoles de esta semana", "el miércoles de esta semana");
     // TODO: support "durante la semana"
     example!(v, check_moment!(c, [2013, 2, 11], Grain::Week), "esta semana");
+    example!(v, check_moment!(c, [2013, 10, 14], Grain::Week), "semana 42", "la semana número 42");
     example!(v, check_moment!(c, [2013, 2, 4], Grain::Week), "la semana pasada", "la pasada semana");
     example!(v, check_moment!(c, [2013, 2, 18], Grain::Week), "la próxima semana", "la semana próxima", "la semana que viene");
     example!(v, check_moment!(c, [2013, 1]), "el mes pasado", "el pasado mes");
@@ -346,3 +347,93 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_finance(15.0, Some("$"), Precision::Approximate), "unos quince dólares", "unos quince dolares");
     example!(v, check_finance(3000000.0, Some("EUR"), Precision::Exact), "tres millones de euros");
 }
+
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(5.0, Some("km"), Precision::Exact), "5 km", "5km", "cinco kilómetros");
+    example!(v, check_distance(200.0, Some("m"), Precision::Exact), "200 metros", "doscientos metros");
+    example!(v, check_distance(3.0, Some("mi"), Precision::Exact), "3 millas", "tres millas");
+    example!(v, check_distance(1.0, Some("km"), Precision::Exact), "un kilómetro");
+    example!(v, check_distance(12.0, Some("cm"), Precision::Exact), "12 cm", "doce centímetros");
+    example!(v, check_distance(4.0, Some("mm"), Precision::Exact), "4 mm", "cuatro milímetros");
+    example!(v, check_distance(6.0, Some("ft"), Precision::Exact), "6 pies");
+    example!(v, check_distance(2.0, Some("in"), Precision::Exact), "2 pulgadas", "dos pulgadas");
+    example!(v, check_distance(10.0, Some("km"), Precision::Approximate), "aproximadamente 10 km", "cerca de diez kilómetros");
+    example!(v, check_distance(400.0, Some("m"), Precision::Exact), "exactamente 400 metros");
+}
+
+pub fn examples_volume(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_volume(2.0, Some("l"), Precision::Exact), "2 litros", "dos litros", "2l");
+    example!(v, check_volume(500.0, Some("ml"), Precision::Exact), "500 ml", "quinientos mililitros");
+    example!(v, check_volume(25.0, Some("cl"), Precision::Exact), "25 cl");
+    example!(v, check_volume(3.0, Some("cup"), Precision::Exact), "tres tazas");
+    example!(v, check_volume(2.0, Some("tbsp"), Precision::Exact), "dos cucharadas");
+    example!(v, check_volume(1.0, Some("tsp"), Precision::Exact), "una cucharadita");
+    example!(v, check_volume(1.0, Some("l"), Precision::Approximate), "aproximadamente un litro");
+}
+
+pub fn examples_mass(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_mass(500.0, Some("g"), Precision::Exact), "500 gramos", "500g", "quinientos gramos");
+    example!(v, check_mass(1.0, Some("lb"), Precision::Exact), "una libra");
+    example!(v, check_mass(2.0, Some("kg"), Precision::Exact), "2 kg", "dos kilos", "dos kilogramos");
+    example!(v, check_mass(250.0, Some("mg"), Precision::Exact), "250 mg");
+    example!(v, check_mass(3.0, Some("oz"), Precision::Exact), "tres onzas");
+    example!(v, check_mass(1.0, Some("t"), Precision::Exact), "una tonelada");
+    example!(v, check_mass(5.0, Some("kg"), Precision::Approximate), "aproximadamente 5 kg");
+    example!(v, check_mass(100.0, Some("g"), Precision::Exact), "exactamente 100 gramos");
+}
+
+pub fn examples_rate(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_rate(50.0, Some("km"), "hour", Precision::Exact), "50 km/h", "cincuenta kilómetros por hora");
+    example!(v, check_rate(5.0, Some("minute"), "km", Precision::Exact), "5 minutos por kilómetro", "cinco minutos por km");
+    example!(v, check_rate(3.0, None, "day", Precision::Exact), "3 por día");
+    example!(v, check_rate(90.0, Some("km"), "hour", Precision::Approximate), "aproximadamente 90 km/h");
+}
+
+pub fn examples_quantity_range(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity_range(QuantityKind::Number, Some(25.0), None), "más de 25", "25 o más");
+    example!(v, check_quantity_range(QuantityKind::Number, None, Some(3.0)), "menos de 3");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(5.0), Some(10.0)), "entre 5 y 10", "entre cinco y diez");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(3.0), Some(4.0)), "3-4", "3 - 4");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("EUR") }, Some(20.0), Some(30.0)), "entre 20 y 30 euros");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("EUR") }, None, Some(50.0)), "menos de 50 euros");
+    example!(v, check_quantity_range(QuantityKind::Temperature { unit: Some("degree") }, Some(25.0), None), "más de 25 grados");
+}
+
+pub fn examples_fraction(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_fraction(3, 4), "tres cuartos");
+    example!(v, check_fraction(1, 3), "un tercio");
+    example!(v, check_fraction(5, 2), "dos y medio");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 45]), "tres cuartos de hora");
+}
+
+pub fn examples_roman_numerals(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_integer(4), "capítulo iv");
+    example!(v, check_integer(2), "tomo ii");
+    example!(v, check_ordinal(14), "luis xiv");
+    example!(v, check_ordinal(2), "felipe ii", "Felipe II");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("+33612345678"), "+33 6 12 34 56 78", "0033 6 12 34 56 78", "+33612345678");
+    example!(v, check_phone_number("912345678"), "912 345 678", "912-345-678");
+    example!(v, check_email("john.doe@gmail.com"), "john.doe@gmail.com");
+    example!(v, check_email("juan.garcia@gmail.com"), "juan punto garcia arroba gmail punto com");
+    example!(v, check_url("https://www.example.com/path"), "https://www.example.com/path");
+    example!(v, check_url("www.example.com"), "www.example.com");
+    example!(v, check_url("example.com"), "example.com");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Mon]), "cada lunes", "todos los lunes", "los lunes");
+    example!(v, check_recurrence(c.clone(), Grain::Day, 1, vec![]), "cada día", "todos los días", "diariamente");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 2, vec![]), "cada 2 semanas", "cada dos semanas");
+    example!(v, check_recurrence(c.clone(), Grain::Month, 1, vec![]), "cada mes", "todos los meses", "mensualmente");
+    example!(v, check_recurrence(c.clone(), Grain::Year, 1, vec![]), "cada año", "anualmente");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Tue]), "cada martes hasta marzo");
+}
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age(35.0, Grain::Year), "tengo 35 años", "tiene treinta y cinco años", "35 años de edad");
+    example!(v, check_age(6.0, Grain::Month), "tiene 6 meses");
+}
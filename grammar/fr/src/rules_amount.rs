@@ -241,3 +241,258 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
              });
     Ok(())
 }
+
+pub fn rules_quantities(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_quantities(b, &rustling_ontology_values::rules::QuantityWords {
+        one: r#"une?"#,
+        approximately: r#"(?:autour|pas loin|pr[eè]s|aux alentours) d[e']|environ|presque|(?:approximative|quasi)ment"#,
+        exactly: r#"(?:tr[eè]s )?exactement|pr[eé]cis[eé]ment|pile(?: poil)?"#,
+        per: r#"par|/|[aà] l'"#,
+    })
+}
+
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("km",
+                      b.reg(r#"kilom[eè]tres?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("km"), latent: false })
+    );
+    b.rule_1_terminal("m",
+                      b.reg(r#"m[eè]tres?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("m"), latent: false })
+    );
+    b.rule_1_terminal("cm",
+                      b.reg(r#"centim[eè]tres?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("cm"), latent: false })
+    );
+    b.rule_1_terminal("mm",
+                      b.reg(r#"millim[eè]tres?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("mm"), latent: false })
+    );
+    b.rule_1_terminal("mi",
+                      b.reg(r#"miles?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("mi"), latent: false })
+    );
+    b.rule_1_terminal("yd",
+                      b.reg(r#"yards?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("yd"), latent: false })
+    );
+    b.rule_1_terminal("ft",
+                      b.reg(r#"pieds?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("ft"), latent: false })
+    );
+    b.rule_1_terminal("in",
+                      b.reg(r#"pouces?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("in"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_volume(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("l",
+                      b.reg(r#"litres?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("l"), latent: false })
+    );
+    b.rule_1_terminal("dl",
+                      b.reg(r#"d[eé]cilitres?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("dl"), latent: false })
+    );
+    b.rule_1_terminal("cl",
+                      b.reg(r#"centilitres?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("cl"), latent: false })
+    );
+    b.rule_1_terminal("ml",
+                      b.reg(r#"millilitres?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("ml"), latent: false })
+    );
+    b.rule_1_terminal("gal",
+                      b.reg(r#"gallons?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("gal"), latent: false })
+    );
+    b.rule_1_terminal("pt",
+                      b.reg(r#"pintes?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("pt"), latent: false })
+    );
+    b.rule_1_terminal("cup",
+                      b.reg(r#"tasses?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("cup"), latent: false })
+    );
+    b.rule_1_terminal("tbsp",
+                      b.reg(r#"cuill[eè]res? [aà] soupe|c\.? ?[aà] s\.?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("tbsp"), latent: false })
+    );
+    b.rule_1_terminal("tsp",
+                      b.reg(r#"cuill[eè]res? [aà] caf[eé]|c\.? ?[aà] c\.?"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("tsp"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_mass(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("t",
+                      b.reg(r#"tonnes?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("t"), latent: false })
+    );
+    b.rule_1_terminal("kg",
+                      b.reg(r#"kilos?|kilogrammes?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("kg"), latent: false })
+    );
+    b.rule_1_terminal("g",
+                      b.reg(r#"grammes?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("g"), latent: false })
+    );
+    b.rule_1_terminal("mg",
+                      b.reg(r#"milligrammes?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("mg"), latent: false })
+    );
+    b.rule_1_terminal("lb",
+                      b.reg(r#"livres?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("lb"), latent: false })
+    );
+    b.rule_1_terminal("oz",
+                      b.reg(r#"onces?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("oz"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_quantity_range(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("between <number> and <number>",
+             b.reg(r#"entre"#)?,
+             number_check!(),
+             b.reg(r#"et"#)?,
+             number_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <number>",
+             number_check_by_range!(0.0, 999.0),
+             b.reg(r#"\-"#)?,
+             number_check_by_range!(0.0, 999.0),
+             |start, _, end| Ok(helpers::quantity_between(start.value(), end.value())?.latent(true)));
+    b.rule_4("between <number> and <amount-of-money>",
+             b.reg(r#"entre|de"#)?,
+             number_check!(),
+             b.reg(r#"et|[aà]|\-"#)?,
+             amount_of_money_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <amount-of-money> and <amount-of-money>",
+             b.reg(r#"entre|de"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"et|[aà]|\-"#)?,
+             amount_of_money_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <number> and <temperature>",
+             b.reg(r#"entre|de"#)?,
+             number_check!(),
+             b.reg(r#"et|[aà]|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <temperature> and <temperature>",
+             b.reg(r#"entre|de"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"et|[aà]|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <ordinal> and <ordinal>",
+             b.reg(r#"entre|de"#)?,
+             ordinal_check!(),
+             b.reg(r#"et|[aà]|\-"#)?,
+             ordinal_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"\-|[aà]"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"\-|[aà]"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <temperature>",
+             number_check!(),
+             b.reg(r#"\-|[aà]"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<temperature> - <temperature>",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"\-|[aà]"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<ordinal> - <ordinal>",
+             ordinal_check!(),
+             b.reg(r#"\-|[aà]"#)?,
+             ordinal_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_2("more than <number>",
+             b.reg(r#"plus de|au(?:\-| )dessus de|sup[ée]rieure? [aà]"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <number>",
+             b.reg(r#"au moins"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("more than <amount-of-money>",
+             b.reg(r#"plus de|au(?:\-| )dessus de|sup[ée]rieure? [aà]"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <amount-of-money>",
+             b.reg(r#"au moins"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("more than <temperature>",
+             b.reg(r#"plus de|au(?:\-| )dessus de|sup[ée]rieure? [aà]"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <temperature>",
+             b.reg(r#"au moins"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("less than <number>",
+             b.reg(r#"moins de|en(?:\-| )dessous de|inf[ée]rieure? [aà]"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <number>",
+             b.reg(r#"au plus|jusqu'[aà]"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("less than <amount-of-money>",
+             b.reg(r#"moins de|en(?:\-| )dessous de|inf[ée]rieure? [aà]"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <amount-of-money>",
+             b.reg(r#"au plus|jusqu'[aà]"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("less than <temperature>",
+             b.reg(r#"moins de|en(?:\-| )dessous de|inf[ée]rieure? [aà]"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <temperature>",
+             b.reg(r#"au plus|jusqu'[aà]"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("<number> or more",
+             number_check!(),
+             b.reg(r#"ou plus|et plus"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<amount-of-money> or more",
+             amount_of_money_check!(),
+             b.reg(r#"ou plus|et plus"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<temperature> or more",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"ou plus|et plus"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<number> or less",
+             number_check!(),
+             b.reg(r#"ou moins|et moins"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<amount-of-money> or less",
+             amount_of_money_check!(),
+             b.reg(r#"ou moins|et moins"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<temperature> or less",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"ou moins|et moins"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use rustling::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
+
+pub fn rules_contact(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_contact(b)?;
+    b.rule_1_terminal("spoken email address",
+                      b.reg(r#"((?:[a-z0-9]+ (?:tiret bas|tiret|point) )*[a-z0-9]+) (?:arobase|at) ((?:[a-z0-9]+ (?:tiret bas|tiret|point) )+[a-z]{2,})"#)?,
+                      |text_match| helpers::spoken_email(text_match.group(1), text_match.group(2), &[("tiret bas", "_"), ("tiret", "-"), ("point", ".")])
+    );
+    Ok(())
+}
@@ -598,6 +598,21 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           text_match.group(2).parse()?,
                           text_match.group(1).parse()?)
     );
+    b.rule_1_terminal("mm/dd/yy or mm/dd/yyyy - Ambiguous cases - month first",
+                      b.reg(r#"(0?[1-9]|1[0-2])[-\./](0?[1-9]|1[0-2])[-\./](\d{2,4})"#)?,
+                      |text_match| Ok(helpers::year_month_day(
+                          text_match.group(3).parse()?,
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)?
+                          .date_order(Some(DateOrder::MonthDayYear)))
+    );
+    b.rule_1_terminal("mm/dd - Ambiguous cases - month first",
+                      b.reg(r#"(0?[1-9]|1[0-2])[\./](0?[1-9]|1[0-2])"#)?,
+                      |text_match| Ok(helpers::month_day(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)?
+                          .date_order(Some(DateOrder::MonthDayYear)))
+    );
     // End of Written time/date in numeric formats
     b.rule_1_terminal("matin",
                       b.reg(r#"mat(?:in[ée]?e?)?"#)?,
@@ -928,8 +943,54 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("fin de semaine (Warning: this is the weekend in Quebec)",
         b.reg(r#"(?:en |à la )?fin de (?:cette |la )?semaine"#)?,
-        |_| helpers::day_of_week(Weekday::Thu)
-                    ?.span_to(&helpers::day_of_week(Weekday::Sun)?, false)
+        |_| helpers::nth_day_of_week(3)
+                    ?.span_to(&helpers::nth_day_of_week(-1)?, false)
+    );
+    b.rule_1_terminal("week <number> (ISO 8601)",
+        b.reg(r#"(?:la )?semaine (?:n°|num[ée]ro |no\.? ?)?(\d{1,2})"#)?,
+        |text_match| helpers::week_of_year(text_match.group(1).parse()?)
+    );
+    b.rule_1_terminal("prochain jour ouvré",
+        b.reg(r#"(?:le )?(?:prochain jour (?:ouvr[ée]|ouvrable|de travail)|jour (?:ouvr[ée]|ouvrable) suivant)"#)?,
+        |_| helpers::business_day_nth(0)
+    );
+    b.rule_1_terminal("jour ouvré précédent",
+        b.reg(r#"(?:le )?(?:dernier jour (?:ouvr[ée]|ouvrable)|jour (?:ouvr[ée]|ouvrable) pr[ée]c[ée]dent)"#)?,
+        |_| helpers::business_day_nth(-1)
+    );
+    b.rule_3("dans <integer> jours ouvrés",
+        b.reg(r#"dans"#)?,
+        integer_check_by_range!(1),
+        b.reg(r#"jours? (?:ouvr[ée]s?|ouvrables?)"#)?,
+        |_, integer, _| helpers::business_day_nth(integer.value().value - 1)
+    );
+    b.rule_1_terminal("fermeture des bureaux",
+        b.reg(r#"(?:[àa] la )?(?:fermeture des bureaux|fin de (?:la )?journ[ée]e de travail)"#)?,
+        |_| helpers::end_of_business()
+    );
+    b.rule_1_terminal("heures de bureau",
+        b.reg(r#"(?:pendant |durant )?(?:les )?heures (?:de bureau|ouvr[ée]es|ouvrables)"#)?,
+        |_| helpers::business_hours()
+    );
+    b.rule_1_terminal("exercice <year>",
+        b.reg(r#"(?:l'|l’)?exercice (?:fiscal )?(\d{4}|\d{2})"#)?,
+        |text_match| helpers::fiscal_year(text_match.group(1).parse()?)
+    );
+    b.rule_1_terminal("T1-4 de l'exercice <year>",
+        b.reg(r#"t([1-4]) (?:de l'|de l’)?exercice (?:fiscal )?(\d{4}|\d{2})"#)?,
+        |text_match| helpers::fiscal_quarter(text_match.group(1).parse()?)?
+            .intersect(&helpers::fiscal_year(text_match.group(2).parse()?)?)
+    );
+    b.rule_1_terminal("cet exercice",
+        b.reg(r#"(?:cet |l'|l’)exercice (?:fiscal )?(?:en cours|actuel)|cet exercice(?: fiscal)?"#)?,
+        |_| helpers::fiscal_cycle_nth(Grain::Year, 0)
+    );
+    b.rule_1_terminal("l'exercice prochain|précédent",
+        b.reg(r#"(?:l'|l’)exercice (?:fiscal )?(prochain|suivant|pr[ée]c[ée]dent|dernier)"#)?,
+        |text_match| match text_match.group(1).as_ref() {
+            "prochain" | "suivant" => helpers::fiscal_cycle_nth(Grain::Year, 1),
+            _ => helpers::fiscal_cycle_nth(Grain::Year, -1),
+        }
     );
     b.rule_1_terminal("en semaine",
         b.reg(r#"(?:pendant la |en )semaine"#)?,
@@ -1784,3 +1845,37 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_recurrence(b, &rustling_ontology_values::rules::RecurrenceWords {
+        every: r#"chaque|tous les|toutes les"#,
+        every_other: Some(r#"(?:tous|toutes) les deux"#),
+        every_integer: Some(r#"tous les|toutes les"#),
+        hourly: r#"toutes les heures"#,
+        daily: r#"quotidien(?:ne)?(?:ment)?"#,
+        weekly: r#"hebdomadaire(?:ment)?"#,
+        monthly: r#"mensuel(?:le)?(?:ment)?"#,
+        quarterly: Some(r#"trimestriel(?:le)?(?:ment)?"#),
+        yearly: r#"annuel(?:le)?(?:ment)?"#,
+        and: Some(r#"et"#),
+        until: Some(r#"jusqu'(?:à|au|en)"#),
+        times: Some(r#"fois"#),
+    })?;
+    b.rule_1_terminal("every <day-of-week> (plural)",
+                      b.reg(r#"(?:tous )?les (lundi|mardi|mercredi|jeudi|vendredi|samedi|dimanche)s"#)?,
+                      |text_match| {
+                          let weekday = match text_match.group(1) {
+                              "lundi" => Weekday::Mon,
+                              "mardi" => Weekday::Tue,
+                              "mercredi" => Weekday::Wed,
+                              "jeudi" => Weekday::Thu,
+                              "vendredi" => Weekday::Fri,
+                              "samedi" => Weekday::Sat,
+                              "dimanche" => Weekday::Sun,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          helpers::recurrence(&helpers::day_of_week(weekday)?, 1)
+                      }
+    );
+    Ok(())
+}
@@ -137,6 +137,39 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
             duration_check!(),
             |_, duration| Ok(duration.value().clone().prefixed())
     );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             unit_of_duration_check!(),
+             |number, uod| helpers::fractional_duration(number.value(), uod.value())
+    );
+    b.rule_3("<fraction> of <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             b.reg(r#"d'|de"#)?,
+             unit_of_duration_check!(),
+             |number, _, uod| helpers::fractional_duration(number.value(), uod.value())
+    );
     Ok(())
 }
 
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_3("âgé de <number> <unit-of-duration>",
+             b.reg(r#"[aâ]g[ée]e?s? de"#)?,
+             number_check!(),
+             unit_of_duration_check!(|uod: &UnitOfDurationValue| uod.grain == Grain::Year || uod.grain == Grain::Month),
+             |_, number, uod| helpers::age(number.value(), uod.value().grain)
+    );
+    // Latent as the number of years may be a delay or an experience, e.g. "j'ai 3 ans de retard"
+    b.rule_3("j'ai <number> <unit-of-duration> (latent)",
+             b.reg(r#"j'ai|tu as|il a|elle a|on a|nous avons|vous avez|ils ont|elles ont"#)?,
+             number_check!(),
+             unit_of_duration_check!(|uod: &UnitOfDurationValue| uod.grain == Grain::Year || uod.grain == Grain::Month),
+             |_, number, uod| Ok(helpers::age(number.value(), uod.value().grain)?.latent(true))
+    );
+    b.rule_3("<number> <unit-of-duration> d'âge",
+             number_check!(),
+             unit_of_duration_check!(),
+             b.reg(r#"d'[aâ]ge|r[ée]volus"#)?,
+             |number, uod, _| helpers::age(number.value(), uod.value().grain)
+    );
+    Ok(())
+}
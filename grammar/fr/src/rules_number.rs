@@ -469,7 +469,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         integer_check_by_range!(0, 99),
         b.reg(r#"et demie?"#)?,
         |integer, _| {
-            FloatValue::new(integer.value().value as f64 + 0.5)
+            helpers::fraction(integer.value().value * 2 + 1, 2)
         }
     );
     b.rule_1_terminal("70, 80, 90 (Belgium and Switzerland)",
@@ -696,7 +696,7 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              integer_check_by_range!(0, 99),
              b.reg(r#"et demie?"#)?,
              |integer, _| {
-                 FloatValue::new(integer.value().value as f64 + 0.5)
+                 helpers::fraction(integer.value().value * 2 + 1, 2)
              }
     );
     b.rule_1_terminal("70, 80, 90 (Belgium and Switzerland)",
@@ -807,3 +807,39 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_fraction(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("<numerator>/<denominator> (latent)",
+             b.reg(r#"(\d+)/(\d+)"#)?,
+             |text_match| Ok(helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)?.latent(true)));
+    b.rule_2("<integer> <denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(demi|tier|quart|cinqui[eè]me|sixi[eè]me|septi[eè]me|huiti[eè]me|neuvi[eè]me|dixi[eè]me)s?"#)?,
+             |integer, text_match| {
+                 let denominator = match text_match.group(1).as_ref() {
+                     "demi" => 2,
+                     "tier" => 3,
+                     "quart" => 4,
+                     "cinquieme" | "cinquième" => 5,
+                     "sixieme" | "sixième" => 6,
+                     "septieme" | "septième" => 7,
+                     "huitieme" | "huitième" => 8,
+                     "neuvieme" | "neuvième" => 9,
+                     "dixieme" | "dixième" => 10,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::fraction(integer.value().value, denominator)
+             });
+    b.rule_2("<integer> <fraction>",
+             integer_check_by_range!(0),
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() < 1.0),
+             |integer, fraction| helpers::mixed_number(integer.value(), fraction.value()));
+    Ok(())
+}
+
+pub fn rules_roman_numerals(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_roman_numerals(b, &rustling_ontology_values::rules::RomanNumeralWords {
+        nouns: r#"chapitre|partie|tome|volume|livre|acte|sc[èe]ne|article|section|titre|annexe|phase|[ée]tape|niveau|classe|type"#,
+        names: r#"fran[çc]ois|philippe|jean|pape (?:pie|l[ée]on|beno[iî]t|gr[ée]goire|jean-paul|jean)"#,
+    })
+}
@@ -47,7 +47,7 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 00]), "maintenant", "tout de suite", "en ce moment");
     example!(v, check_moment!(c, [2013, 2, 12]), "aujourd'hui", "ce jour", "dans la journée");
     example!(v, check_moment!(c, [2013, 2, 11]), "hier", "le jour d'avant", "le jour précédent", "la veille");
@@ -104,6 +104,15 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 12]), "mardi cette semaine");
     example!(v, check_moment!(c, [2013, 2, 13]), "mercredi cette semaine");
     example!(v, check_moment!(c, [2013, 2, 11], Grain::Week), "cette semaine", "dans la semaine");
+    example!(v, check_moment!(c, [2013, 10, 14], Grain::Week), "semaine 42", "la semaine numéro 42");
+    example!(v, check_moment!(c, [2013, 2, 13]), "le prochain jour ouvré", "jour ouvrable suivant");
+    example!(v, check_moment!(c, [2013, 2, 11]), "le jour ouvré précédent", "dernier jour ouvrable");
+    example!(v, check_moment!(c, [2013, 2, 15]), "dans 3 jours ouvrés", "dans trois jours ouvrables");
+    example!(v, check_moment!(c, [2013, 2, 12, 17, 0]), "à la fermeture des bureaux", "fin de journée de travail");
+    example!(v, check_moment_span!(c, [2013, 2, 12, 9], [2013, 2, 12, 17]), "pendant les heures de bureau", "heures ouvrées");
+    example!(v, check_moment!(c, [2014]), "exercice 2014", "l'exercice fiscal 2014", "l'exercice prochain");
+    example!(v, check_moment!(c, [2014, 7, 1], Grain::Quarter), "T3 de l'exercice 2014", "t3 exercice 2014");
+    example!(v, check_moment!(c, [2013]), "cet exercice", "l'exercice en cours");
     example!(v, check_moment!(c, [2013, 2, 4], Grain::Week), "la semaine dernière");
     example!(v, check_moment!(c, [2013, 2, 18], Grain::Week), "la semaine prochaine", "la semaine suivante", "la semaine qui suit");
     example!(v, check_moment!(c, [2013, 1]), "le mois dernier");
@@ -354,3 +363,96 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
              "troisième",
              "troisieme");
 }
+
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(5.0, Some("km"), Precision::Exact), "5 km", "5km", "cinq kilomètres");
+    example!(v, check_distance(200.0, Some("m"), Precision::Exact), "200 mètres", "deux cents mètres");
+    example!(v, check_distance(3.0, Some("mi"), Precision::Exact), "3 miles", "trois miles");
+    example!(v, check_distance(1.0, Some("km"), Precision::Exact), "un kilomètre");
+    example!(v, check_distance(12.0, Some("cm"), Precision::Exact), "12 cm", "douze centimètres");
+    example!(v, check_distance(4.0, Some("mm"), Precision::Exact), "4 mm", "quatre millimètres");
+    example!(v, check_distance(6.0, Some("ft"), Precision::Exact), "6 pieds");
+    example!(v, check_distance(2.0, Some("in"), Precision::Exact), "2 pouces", "deux pouces");
+    example!(v, check_distance(10.0, Some("km"), Precision::Approximate), "environ 10 km", "presque dix kilomètres");
+    example!(v, check_distance(400.0, Some("m"), Precision::Exact), "exactement 400 mètres");
+}
+
+pub fn examples_volume(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_volume(2.0, Some("l"), Precision::Exact), "2 litres", "deux litres", "2l");
+    example!(v, check_volume(500.0, Some("ml"), Precision::Exact), "500 ml", "cinq cents millilitres");
+    example!(v, check_volume(25.0, Some("cl"), Precision::Exact), "25 cl", "vingt-cinq centilitres");
+    example!(v, check_volume(3.0, Some("cup"), Precision::Exact), "trois tasses");
+    example!(v, check_volume(2.0, Some("tbsp"), Precision::Exact), "deux cuillères à soupe", "2 c. à s.");
+    example!(v, check_volume(1.0, Some("tsp"), Precision::Exact), "une cuillère à café");
+    example!(v, check_volume(2.0, Some("pt"), Precision::Exact), "deux pintes");
+    example!(v, check_volume(1.0, Some("l"), Precision::Approximate), "environ un litre");
+}
+
+pub fn examples_mass(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_mass(500.0, Some("g"), Precision::Exact), "500 grammes", "500g", "cinq cents grammes");
+    example!(v, check_mass(1.0, Some("lb"), Precision::Exact), "une livre");
+    example!(v, check_mass(2.0, Some("kg"), Precision::Exact), "2 kg", "deux kilos", "deux kilogrammes");
+    example!(v, check_mass(250.0, Some("mg"), Precision::Exact), "250 mg");
+    example!(v, check_mass(3.0, Some("oz"), Precision::Exact), "trois onces");
+    example!(v, check_mass(1.0, Some("t"), Precision::Exact), "une tonne");
+    example!(v, check_mass(5.0, Some("kg"), Precision::Approximate), "environ 5 kg");
+    example!(v, check_mass(100.0, Some("g"), Precision::Exact), "exactement 100 grammes");
+}
+
+pub fn examples_rate(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_rate(50.0, Some("km"), "hour", Precision::Exact), "50 km/h", "cinquante kilomètres par heure", "50 kilomètres à l'heure");
+    example!(v, check_rate(5.0, Some("minute"), "km", Precision::Exact), "5 minutes par kilomètre", "cinq minutes par km");
+    example!(v, check_rate(3.0, None, "day", Precision::Exact), "3 par jour");
+    example!(v, check_rate(90.0, Some("km"), "hour", Precision::Approximate), "environ 90 km/h");
+}
+
+pub fn examples_quantity_range(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity_range(QuantityKind::Number, Some(25.0), None), "plus de 25", "25 ou plus");
+    example!(v, check_quantity_range(QuantityKind::Number, None, Some(3.0)), "moins de 3");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(5.0), Some(10.0)), "entre 5 et 10", "entre cinq et dix");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(3.0), Some(4.0)), "3-4", "3 - 4");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("EUR") }, Some(20.0), Some(30.0)), "entre 20 et 30 euros");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("EUR") }, None, Some(50.0)), "moins de 50 euros");
+    example!(v, check_quantity_range(QuantityKind::Temperature { unit: Some("degree") }, Some(25.0), None), "plus de 25 degrés", "au-dessus de 25 degrés");
+}
+
+pub fn examples_fraction(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_fraction(1, 3), "un tiers");
+    example!(v, check_fraction(3, 4), "trois quarts");
+    example!(v, check_fraction(2, 3), "deux tiers");
+    example!(v, check_fraction(5, 2), "deux et demi");
+    example!(v, check_fraction(1, 2), "un demi");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 45]), "trois quarts d'heure");
+}
+
+pub fn examples_roman_numerals(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_integer(4), "chapitre iv");
+    example!(v, check_integer(2), "tome ii");
+    example!(v, check_ordinal(14), "louis xiv", "Louis XIV");
+    example!(v, check_ordinal(4), "henri iv");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("+33612345678"), "+33 6 12 34 56 78", "0033 6 12 34 56 78", "+33612345678");
+    example!(v, check_phone_number("0612345678"), "06 12 34 56 78", "06.12.34.56.78", "0612345678");
+    example!(v, check_email("john.doe@gmail.com"), "john.doe@gmail.com");
+    example!(v, check_email("jean.dupont@free.fr"), "jean point dupont arobase free point fr");
+    example!(v, check_url("https://www.example.com/path"), "https://www.example.com/path");
+    example!(v, check_url("www.example.com"), "www.example.com");
+    example!(v, check_url("example.com"), "example.com");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Mon]), "chaque lundi", "tous les lundis", "les lundis", "tous les lundis à 17h");
+    example!(v, check_recurrence(c.clone(), Grain::Day, 1, vec![]), "tous les jours", "chaque jour", "quotidiennement", "tous les jours à 8h");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 2, vec![]), "toutes les 2 semaines", "toutes les deux semaines");
+    example!(v, check_recurrence(c.clone(), Grain::Month, 1, vec![]), "chaque mois", "tous les mois", "mensuellement");
+    example!(v, check_recurrence(c.clone(), Grain::Year, 1, vec![]), "chaque année", "annuellement");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Tue]), "tous les mardis jusqu'en mars");
+}
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age(35.0, Grain::Year), "âgé de 35 ans", "35 ans révolus");
+    example!(v, check_age(6.0, Grain::Month), "elle a 6 mois");
+}
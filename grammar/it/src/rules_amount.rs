@@ -255,4 +255,259 @@ pub fn rules_temperature(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()
                  })
              });
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn rules_quantities(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_quantities(b, &rustling_ontology_values::rules::QuantityWords {
+        one: r#"un[oa]?|un'"#,
+        approximately: r#"verso|interno a|(?:approssim|indic|orient)ativamente|(?:all'in)?circa|quasi|più o meno|pressappoco|suppergiù|grosso modo"#,
+        exactly: r#"(?:esatt|precis)amente"#,
+        per: r#"per|/|all'|al"#,
+    })
+}
+
+pub fn rules_distance(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("km",
+                      b.reg(r#"chilometr[oi]"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("km"), latent: false })
+    );
+    b.rule_1_terminal("m",
+                      b.reg(r#"metr[oi]"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("m"), latent: false })
+    );
+    b.rule_1_terminal("cm",
+                      b.reg(r#"centimetr[oi]"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("cm"), latent: false })
+    );
+    b.rule_1_terminal("mm",
+                      b.reg(r#"millimetr[oi]"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("mm"), latent: false })
+    );
+    b.rule_1_terminal("mi",
+                      b.reg(r#"migli[oa]"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("mi"), latent: false })
+    );
+    b.rule_1_terminal("yd",
+                      b.reg(r#"iard[ae]|yards?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("yd"), latent: false })
+    );
+    b.rule_1_terminal("ft",
+                      b.reg(r#"pied[ei]"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("ft"), latent: false })
+    );
+    b.rule_1_terminal("in",
+                      b.reg(r#"pollic[ei]"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("in"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_volume(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("l",
+                      b.reg(r#"litr[oi]"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("l"), latent: false })
+    );
+    b.rule_1_terminal("dl",
+                      b.reg(r#"decilitr[oi]"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("dl"), latent: false })
+    );
+    b.rule_1_terminal("cl",
+                      b.reg(r#"centilitr[oi]"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("cl"), latent: false })
+    );
+    b.rule_1_terminal("ml",
+                      b.reg(r#"millilitr[oi]"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("ml"), latent: false })
+    );
+    b.rule_1_terminal("gal",
+                      b.reg(r#"gallon[ei]"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("gal"), latent: false })
+    );
+    b.rule_1_terminal("pt",
+                      b.reg(r#"pint[ae]"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("pt"), latent: false })
+    );
+    b.rule_1_terminal("cup",
+                      b.reg(r#"tazz[ae]"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("cup"), latent: false })
+    );
+    b.rule_1_terminal("tbsp",
+                      b.reg(r#"cucchia(?:io|i)"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("tbsp"), latent: false })
+    );
+    b.rule_1_terminal("tsp",
+                      b.reg(r#"cucchiain[oi]"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("tsp"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_mass(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("t",
+                      b.reg(r#"tonnellat[ae]"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("t"), latent: false })
+    );
+    b.rule_1_terminal("kg",
+                      b.reg(r#"chil[oi]|chilogramm[oi]"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("kg"), latent: false })
+    );
+    b.rule_1_terminal("g",
+                      b.reg(r#"gramm[oi]"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("g"), latent: false })
+    );
+    b.rule_1_terminal("mg",
+                      b.reg(r#"milligramm[oi]"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("mg"), latent: false })
+    );
+    b.rule_1_terminal("lb",
+                      b.reg(r#"libbr[ae]"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("lb"), latent: false })
+    );
+    b.rule_1_terminal("oz",
+                      b.reg(r#"once|oncia"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("oz"), latent: false })
+    );
+    Ok(())
+}
+
+pub fn rules_quantity_range(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("between <number> and <number>",
+             b.reg(r#"tra|fra"#)?,
+             number_check!(),
+             b.reg(r#"e"#)?,
+             number_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <number>",
+             number_check_by_range!(0.0, 999.0),
+             b.reg(r#"\-"#)?,
+             number_check_by_range!(0.0, 999.0),
+             |start, _, end| Ok(helpers::quantity_between(start.value(), end.value())?.latent(true)));
+    b.rule_4("between <number> and <amount-of-money>",
+             b.reg(r#"tra|fra|da"#)?,
+             number_check!(),
+             b.reg(r#"e|a|\-"#)?,
+             amount_of_money_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <amount-of-money> and <amount-of-money>",
+             b.reg(r#"tra|fra|da"#)?,
+             amount_of_money_check!(),
+             b.reg(r#"e|a|\-"#)?,
+             amount_of_money_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <number> and <temperature>",
+             b.reg(r#"tra|fra|da"#)?,
+             number_check!(),
+             b.reg(r#"e|a|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <temperature> and <temperature>",
+             b.reg(r#"tra|fra|da"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"e|a|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_4("between <ordinal> and <ordinal>",
+             b.reg(r#"tra|fra|da"#)?,
+             ordinal_check!(),
+             b.reg(r#"e|a|\-"#)?,
+             ordinal_check!(),
+             |_, start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"\-|a"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"\-|a"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <temperature>",
+             number_check!(),
+             b.reg(r#"\-|a"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<temperature> - <temperature>",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"\-|a"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<ordinal> - <ordinal>",
+             ordinal_check!(),
+             b.reg(r#"\-|a"#)?,
+             ordinal_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_2("more than <number>",
+             b.reg(r#"pi[uù] di|sopra|oltre|superiore a"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <number>",
+             b.reg(r#"almeno"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("more than <amount-of-money>",
+             b.reg(r#"pi[uù] di|sopra|oltre|superiore a"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <amount-of-money>",
+             b.reg(r#"almeno"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("more than <temperature>",
+             b.reg(r#"pi[uù] di|sopra|oltre|superiore a"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <temperature>",
+             b.reg(r#"almeno"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("less than <number>",
+             b.reg(r#"meno di|sotto|inferiore a"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <number>",
+             b.reg(r#"al massimo|fino a"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("less than <amount-of-money>",
+             b.reg(r#"meno di|sotto|inferiore a"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <amount-of-money>",
+             b.reg(r#"al massimo|fino a"#)?,
+             amount_of_money_check!(),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("less than <temperature>",
+             b.reg(r#"meno di|sotto|inferiore a"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <temperature>",
+             b.reg(r#"al massimo|fino a"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("<number> or more",
+             number_check!(),
+             b.reg(r#"o pi[uù]|e pi[uù]"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<amount-of-money> or more",
+             amount_of_money_check!(),
+             b.reg(r#"o pi[uù]|e pi[uù]"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<temperature> or more",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"o pi[uù]|e pi[uù]"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<number> or less",
+             number_check!(),
+             b.reg(r#"o meno|e meno"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<amount-of-money> or less",
+             amount_of_money_check!(),
+             b.reg(r#"o meno|e meno"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<temperature> or less",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"o meno|e meno"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    Ok(())
+}
@@ -634,6 +634,21 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           text_match.group(2).parse()?,
                           text_match.group(1).parse()?)
     );
+    b.rule_1_terminal("mm/dd/yy or mm/dd/yyyy - Ambiguous cases - month first",
+                      b.reg(r#"(0?[1-9]|1[0-2])[-\./](0?[1-9]|1[0-2])[-\./](\d{2,4})"#)?,
+                      |text_match| Ok(helpers::year_month_day(
+                          text_match.group(3).parse()?,
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)?
+                          .date_order(Some(DateOrder::MonthDayYear)))
+    );
+    b.rule_1_terminal("mm/dd - Ambiguous cases - month first",
+                      b.reg(r#"(0?[1-9]|1[0-2])[-\./](0?[1-9]|1[0-2])"#)?,
+                      |text_match| Ok(helpers::month_day(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)?
+                          .date_order(Some(DateOrder::MonthDayYear)))
+    );
     // End of Written dates in numeric formats
     // Parts of the day
     b.rule_1_terminal("morning",
@@ -948,13 +963,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     // Parts of the week
     b.rule_1_terminal("week-end",
                       b.reg(r#"week(?:\s|-)?end|fine(?:\s|-)?settimana"#)?,
-                      |_| {
-                          let friday = helpers::day_of_week(Weekday::Fri)?
-                              .intersect(&helpers::hour(18, false)?)?;
-                          let monday = helpers::day_of_week(Weekday::Mon)?
-                              .intersect(&helpers::hour(0, false)?)?;
-                          friday.span_to(&monday, false)
-                      }
+                      |_| helpers::weekend()
     );
     b.rule_1_terminal("beginning of the week",
                       b.reg(r#"(?:a |all' ?)?inizio (?:della |di questa )?settimana"#)?,
@@ -968,8 +977,12 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("end of the week",
                       b.reg(r#"(?:alla )?fine (?:della|di questa) settimana"#)?,
-                      |_| helpers::day_of_week(Weekday::Thu)
-                          ?.span_to(&helpers::day_of_week(Weekday::Sun)?, false)
+                      |_| helpers::nth_day_of_week(3)
+                          ?.span_to(&helpers::nth_day_of_week(-1)?, false)
+    );
+    b.rule_1_terminal("week <number> (ISO 8601)",
+                      b.reg(r#"(?:la )?settimana (?:n[°\.] ?|numero )?(\d{1,2})"#)?,
+                      |text_match| helpers::week_of_year(text_match.group(1).parse()?)
     );
     b.rule_1_terminal("during the week / week days",
                       b.reg(r#"(?:durante la|nel corso della|in) settimana"#)?,
@@ -1515,4 +1528,38 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       |_| CycleValue::new(Grain::Year)
     );
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_recurrence(b, &rustling_ontology_values::rules::RecurrenceWords {
+        every: r#"ogni|tutti i|tutte le"#,
+        every_other: Some(r#"ogni due|uno sì e uno no"#),
+        every_integer: Some(r#"ogni"#),
+        hourly: r#"ogni ora"#,
+        daily: r#"quotidianamente|giornalmente"#,
+        weekly: r#"settimanalmente"#,
+        monthly: r#"mensilmente"#,
+        quarterly: Some(r#"trimestralmente"#),
+        yearly: r#"annualmente"#,
+        and: Some(r#"e|ed"#),
+        until: Some(r#"fino a(?:l(?:l[ao'])?)?"#),
+        times: Some(r#"volte"#),
+    })?;
+    b.rule_1_terminal("every <day-of-week> (plural)",
+                      b.reg(r#"(?:tutti i|tutte le|i|le) (luned[iì]|marted[iì]|mercoled[iì]|gioved[iì]|venerd[iì]|sabati|domeniche)"#)?,
+                      |text_match| {
+                          let weekday = match text_match.group(1) {
+                              "sabati" => Weekday::Sat,
+                              "domeniche" => Weekday::Sun,
+                              day if day.starts_with("luned") => Weekday::Mon,
+                              day if day.starts_with("marted") => Weekday::Tue,
+                              day if day.starts_with("mercoled") => Weekday::Wed,
+                              day if day.starts_with("gioved") => Weekday::Thu,
+                              day if day.starts_with("venerd") => Weekday::Fri,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          helpers::recurrence(&helpers::day_of_week(weekday)?, 1)
+                      }
+    );
+    Ok(())
+}
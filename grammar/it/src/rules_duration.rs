@@ -134,5 +134,32 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              duration_check!(),
              |_, duration| Ok(duration.value().clone().prefixed())
     );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             unit_of_duration_check!(),
+             |number, uod| helpers::fractional_duration(number.value(), uod.value())
+    );
+    b.rule_3("<fraction> of <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             b.reg(r#"d'|di"#)?,
+             unit_of_duration_check!(),
+             |number, _, uod| helpers::fractional_duration(number.value(), uod.value())
+    );
+    Ok(())
+}
+
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_3("ho <number> <unit-of-duration>",
+             b.reg(r#"ho|hai|ha|abbiamo|avete|hanno"#)?,
+             number_check!(),
+             unit_of_duration_check!(|uod: &UnitOfDurationValue| uod.grain == Grain::Year || uod.grain == Grain::Month),
+             |_, number, uod| helpers::age(number.value(), uod.value().grain)
+    );
+    b.rule_3("<number> <unit-of-duration> di età",
+             number_check!(),
+             unit_of_duration_check!(),
+             b.reg(r#"di et[àa]"#)?,
+             |number, uod, _| helpers::age(number.value(), uod.value().grain)
+    );
     Ok(())
-}
\ No newline at end of file
+}
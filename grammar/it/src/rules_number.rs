@@ -348,3 +348,43 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       });
     Ok(())
 }
+
+pub fn rules_fraction(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("<numerator>/<denominator> (latent)",
+             b.reg(r#"(\d+)/(\d+)"#)?,
+             |text_match| Ok(helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)?.latent(true)));
+    b.rule_2("<integer> <denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(mezz|terz|quart|quint|sest|settim|ottav|non|decim)[oi]"#)?,
+             |integer, text_match| {
+                 let denominator = match text_match.group(1).as_ref() {
+                     "mezz" => 2,
+                     "terz" => 3,
+                     "quart" => 4,
+                     "quint" => 5,
+                     "sest" => 6,
+                     "settim" => 7,
+                     "ottav" => 8,
+                     "non" => 9,
+                     "decim" => 10,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::fraction(integer.value().value, denominator)
+             });
+    b.rule_2("<integer> and a half",
+             integer_check_by_range!(0),
+             b.reg(r#"e mezz[oa]"#)?,
+             |integer, _| helpers::fraction(integer.value().value * 2 + 1, 2));
+    b.rule_2("<integer> <fraction>",
+             integer_check_by_range!(0),
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() < 1.0),
+             |integer, fraction| helpers::mixed_number(integer.value(), fraction.value()));
+    Ok(())
+}
+
+pub fn rules_roman_numerals(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_roman_numerals(b, &rustling_ontology_values::rules::RomanNumeralWords {
+        nouns: r#"capitolo|parte|tomo|volume|libro|atto|scena|articolo|sezione|titolo|allegato|fase|livello|classe|tipo"#,
+        names: r#"luigi|enrico|carlo|filippo|vittorio emanuele|umberto|elisabetta|papa (?:pio|leone|benedetto|gregorio|giovanni paolo|giovanni)"#,
+    })
+}
@@ -4,7 +4,7 @@ use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::ResolverContext;
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     // Days
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 00]), "ora", "adesso", "in questo momento esatto", "in questo preciso istante");
     example!(v, check_moment!(c, [2013, 2, 12]), "oggi", "in questo momento", "in questa giornata");
@@ -50,6 +50,7 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     // fix_example!(v, check_moment!(c, [2013, 2, 12]), "martedì di questa settimana", "il martedì di questa settimana");
     // fix_example!(v, check_moment!(c, [2013, 2, 13]), "mercoledì di questa settimana", "il mercoledì di questa settimana");
     example!(v, check_moment!(c, [2013, 2, 11], Grain::Week), "questa settimana", "in settimana");
+    example!(v, check_moment!(c, [2013, 10, 14], Grain::Week), "settimana 42", "la settimana numero 42");
     example!(v, check_moment!(c, [2013, 2, 4], Grain::Week), "la settimana scorsa", "la settimana passata", "la scorsa settimana");
     example!(v, check_moment!(c, [2013, 2, 18], Grain::Week), "la settimana prossima", "la prossima settimana", "la settimana seguente", "la settimana che viene");
     example!(v, check_moment!(c, [2013, 1]), "il mese scorso", "lo scorso mese", "il mese passato");
@@ -346,4 +347,94 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     // TODO: FIXME - add approx. numbers in IT rules
     // todo_example!(v, check_finance(15.0, Some("$"), Precision::Approximate), "una quindicina di dollari", "una 15ina di dollari");
     example!(v, check_finance(3000000.0, Some("EUR"), Precision::Exact), "tre milioni di euro");
-}
\ No newline at end of file
+}
+
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(5.0, Some("km"), Precision::Exact), "5 km", "5km", "cinque chilometri");
+    example!(v, check_distance(200.0, Some("m"), Precision::Exact), "200 metri", "duecento metri");
+    example!(v, check_distance(3.0, Some("mi"), Precision::Exact), "3 miglia", "tre miglia");
+    example!(v, check_distance(1.0, Some("km"), Precision::Exact), "un chilometro");
+    example!(v, check_distance(12.0, Some("cm"), Precision::Exact), "12 cm", "dodici centimetri");
+    example!(v, check_distance(4.0, Some("mm"), Precision::Exact), "4 mm", "quattro millimetri");
+    example!(v, check_distance(6.0, Some("ft"), Precision::Exact), "6 piedi");
+    example!(v, check_distance(2.0, Some("in"), Precision::Exact), "2 pollici", "due pollici");
+    example!(v, check_distance(10.0, Some("km"), Precision::Approximate), "circa 10 km", "quasi dieci chilometri");
+    example!(v, check_distance(400.0, Some("m"), Precision::Exact), "esattamente 400 metri");
+}
+
+pub fn examples_volume(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_volume(2.0, Some("l"), Precision::Exact), "2 litri", "due litri", "2l");
+    example!(v, check_volume(500.0, Some("ml"), Precision::Exact), "500 ml", "cinquecento millilitri");
+    example!(v, check_volume(25.0, Some("cl"), Precision::Exact), "25 cl");
+    example!(v, check_volume(3.0, Some("cup"), Precision::Exact), "tre tazze");
+    example!(v, check_volume(2.0, Some("tbsp"), Precision::Exact), "due cucchiai");
+    example!(v, check_volume(1.0, Some("tsp"), Precision::Exact), "un cucchiaino");
+    example!(v, check_volume(1.0, Some("l"), Precision::Approximate), "circa un litro");
+}
+
+pub fn examples_mass(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_mass(500.0, Some("g"), Precision::Exact), "500 grammi", "500g", "cinquecento grammi");
+    example!(v, check_mass(1.0, Some("lb"), Precision::Exact), "una libbra");
+    example!(v, check_mass(2.0, Some("kg"), Precision::Exact), "2 kg", "due chili", "due chilogrammi");
+    example!(v, check_mass(250.0, Some("mg"), Precision::Exact), "250 mg");
+    example!(v, check_mass(3.0, Some("oz"), Precision::Exact), "tre once");
+    example!(v, check_mass(1.0, Some("t"), Precision::Exact), "una tonnellata");
+    example!(v, check_mass(5.0, Some("kg"), Precision::Approximate), "circa 5 kg");
+    example!(v, check_mass(100.0, Some("g"), Precision::Exact), "esattamente 100 grammi");
+}
+
+pub fn examples_rate(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_rate(50.0, Some("km"), "hour", Precision::Exact), "50 km/h", "cinquanta chilometri all'ora", "50 chilometri per ora");
+    example!(v, check_rate(5.0, Some("minute"), "km", Precision::Exact), "5 minuti per chilometro", "cinque minuti per km");
+    example!(v, check_rate(3.0, None, "day", Precision::Exact), "3 al giorno");
+    example!(v, check_rate(90.0, Some("km"), "hour", Precision::Approximate), "circa 90 km/h");
+}
+
+pub fn examples_quantity_range(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity_range(QuantityKind::Number, Some(25.0), None), "più di 25", "25 o più");
+    example!(v, check_quantity_range(QuantityKind::Number, None, Some(3.0)), "meno di 3");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(5.0), Some(10.0)), "tra 5 e 10", "tra cinque e dieci");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(3.0), Some(4.0)), "3-4", "3 - 4");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("EUR") }, Some(20.0), Some(30.0)), "tra 20 e 30 euro");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("EUR") }, None, Some(50.0)), "meno di 50 euro");
+    example!(v, check_quantity_range(QuantityKind::Temperature { unit: Some("degree") }, Some(25.0), None), "sopra 25 gradi");
+}
+
+pub fn examples_fraction(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_fraction(3, 4), "tre quarti");
+    example!(v, check_fraction(1, 3), "un terzo");
+    example!(v, check_fraction(5, 2), "due e mezzo");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 45]), "tre quarti d'ora");
+}
+
+pub fn examples_roman_numerals(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_integer(4), "capitolo iv");
+    example!(v, check_integer(2), "tomo ii");
+    example!(v, check_ordinal(14), "luigi xiv");
+    example!(v, check_ordinal(2), "vittorio emanuele ii", "Vittorio Emanuele II");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("+33612345678"), "+33 6 12 34 56 78", "0033 6 12 34 56 78", "+33612345678");
+    example!(v, check_phone_number("0612345678"), "06 1234 5678", "06-1234-5678");
+    example!(v, check_email("john.doe@gmail.com"), "john.doe@gmail.com");
+    example!(v, check_email("mario.rossi@libero.it"), "mario punto rossi chiocciola libero punto it");
+    example!(v, check_url("https://www.example.com/path"), "https://www.example.com/path");
+    example!(v, check_url("www.example.com"), "www.example.com");
+    example!(v, check_url("example.com"), "example.com");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Mon]), "ogni lunedì", "tutti i lunedì", "i lunedì");
+    example!(v, check_recurrence(c.clone(), Grain::Day, 1, vec![]), "ogni giorno", "tutti i giorni", "quotidianamente");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 2, vec![]), "ogni 2 settimane", "ogni due settimane");
+    example!(v, check_recurrence(c.clone(), Grain::Month, 1, vec![]), "ogni mese", "mensilmente");
+    example!(v, check_recurrence(c.clone(), Grain::Year, 1, vec![]), "ogni anno", "annualmente");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Tue]), "ogni martedì fino a marzo");
+}
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age(35.0, Grain::Year), "ho 35 anni", "ha trentacinque anni", "35 anni di età");
+    example!(v, check_age(6.0, Grain::Month), "ha 6 mesi");
+}
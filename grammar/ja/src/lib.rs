@@ -9,6 +9,10 @@ pub mod training;
 use rustling_ontology_values::DimensionKind::*;
 
 pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    Ok(rule_set_builder()?.build())
+}
+
+pub fn rule_set_builder() -> ::rustling::RustlingResult<::rustling::RuleSetBuilder<rustling_ontology_values::Dimension>> {
     let mut b = ::rustling::RuleSetBuilder::new(
                     ::rustling::BoundariesChecker::no_check(),
                     ::rustling::BoundariesChecker::no_check());
@@ -19,11 +23,15 @@ pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ont
     rules::rules_temperature(&mut b)?;            
     rules::rules_finance(&mut b)?;
     rules::rules_percentage(&mut b)?;
-    Ok(b.build())
+    rules::rules_quantity_range(&mut b)?;
+    rustling_ontology_values::rules::rules_contact(&mut b)?;
+    rules::rules_recurrence(&mut b)?;
+    rules::rules_age(&mut b)?;
+    Ok(b)
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Duration, Datetime, Temperature, AmountOfMoney, Percentage];
+    return vec![Number, Ordinal, Duration, Datetime, Temperature, AmountOfMoney, Percentage, QuantityRange, PhoneNumber, Email, Url, Recurrence, Age];
 }
 
 pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
@@ -34,6 +42,10 @@ pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Di
     training::examples_temperature(&mut v);
     training::examples_finance(&mut v);
     training::examples_percentage(&mut v);
+    training::examples_quantity_range(&mut v);
+    training::examples_contact(&mut v);
+    training::examples_recurrence(&mut v);
+    training::examples_age(&mut v);
     v
 }
 
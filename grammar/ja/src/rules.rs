@@ -2,12 +2,23 @@ use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::dimension::Precision::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period};
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Period, Calendar, JapaneseEra};
 
 fn ja_quantifier_regex() -> &'static str {
     r#"(?:尾|台|名|枚|話|部|面|両|問|拍子|条|段|輪|門|倍|番|畳|合|膳|錠|ページ|頁|例|字|文|行|語|把|羽|頭|つ|人|個|冊|匹|回|曲|本|杯|点|種類|種|等|足|階|カ国|ヶ国|クラス|丁|件|体|勝|区|口|坪|基|局|席|式|振|挺|敗|束|校|株|機|歩|滴|発|社|票|組|艦|行|通り|通|隻|首|客|戸|着|箱|脚|軒|切れ|品|斤|粒|貫|句|巻|画|稿|筆|言|級)"#
 }
 
+fn japanese_era(name: &str) -> RuleResult<JapaneseEra> {
+    match name {
+        "明治" => Ok(JapaneseEra::Meiji),
+        "大正" => Ok(JapaneseEra::Taisho),
+        "昭和" => Ok(JapaneseEra::Showa),
+        "平成" => Ok(JapaneseEra::Heisei),
+        "令和" => Ok(JapaneseEra::Reiwa),
+        _ => Err(RuleError::Invalid.into()),
+    }
+}
+
 pub trait JapaneseReplace {
    fn replace_japanese_digit(&self) -> String;
    fn replace_comma(&self) -> String;
@@ -773,6 +784,10 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       b.reg(r#"バレンタインデー(?:の?日に?)?"#)?,
                       |_| Ok(helpers::month_day(2, 14)?.form(Form::Celebration)) 
     );
+    b.rule_1_terminal("chinese new year",
+                      b.reg(r#"(?:旧正月|春節)(?:の?日に?)?"#)?,
+                      |_| Ok(helpers::calendar_date(Calendar::Chinese, 1, 1)?.form(Form::Celebration))
+    );
     b.rule_1_terminal("now",
                       b.reg(r#"今(?:すぐに?)?|現在|只今|(?:ただ)?いま"#)?,
                       |_| helpers::cycle_nth(Grain::Second, 0)
@@ -813,13 +828,13 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("end of week",
         b.reg(r#"週の終わりに?"#)?,
-        |_| helpers::day_of_week(Weekday::Thu)?
-                    .span_to(&helpers::day_of_week(Weekday::Sun)?, false)
+        |_| helpers::nth_day_of_week(3)?
+                    .span_to(&helpers::nth_day_of_week(-1)?, false)
     );
     b.rule_1_terminal("by the end of week",
         b.reg(r#"週の終わり(?:までに|の前に)"#)?,
         |_| helpers::cycle_nth(Grain::Second, 0)?
-                    .span_to(&helpers::day_of_week(Weekday::Sun)?, true)
+                    .span_to(&helpers::nth_day_of_week(-1)?, true)
     );
     b.rule_1_terminal("end of day",
         b.reg(r#"日の終わりに?"#)?,
@@ -2053,21 +2068,28 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
 
     b.rule_4("date using emperor years",
-             b.reg(r#"(平成|昭和)"#)?,
+             b.reg(r#"(明治|大正|昭和|平成|令和)"#)?,
              integer_check!(),
              b.reg(r#"年"#)?,
              datetime_check!(form!(Form::MonthDay(_))),
              |emperor, integer, _, month_day| {
-            let start_year = match emperor.group(1).as_ref() {
-                "平成" => 1989,
-                "昭和" => 1926,
-                _ => return Err(RuleError::Invalid.into()),
-            };
-
-            let year = integer.value().value + &start_year - 1;
-            month_day.value().intersect(&helpers::year(year as i32)?)
+            let era = japanese_era(emperor.group(1).as_ref())?;
+            month_day.value().intersect(&helpers::japanese_era_year(era, integer.value().value as i32)?)
         }
     );
+    b.rule_3("emperor year",
+             b.reg(r#"(明治|大正|昭和|平成|令和)"#)?,
+             integer_check_by_range!(1),
+             b.reg(r#"年"#)?,
+             |emperor, integer, _| {
+            let era = japanese_era(emperor.group(1).as_ref())?;
+            helpers::japanese_era_year(era, integer.value().value as i32)
+        }
+    );
+    b.rule_1_terminal("first emperor year",
+                      b.reg(r#"(明治|大正|昭和|平成|令和)元年"#)?,
+                      |text_match| helpers::japanese_era_year(japanese_era(text_match.group(1).as_ref())?, 1)
+    );
     Ok(())
 }
 
@@ -2398,4 +2420,122 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              |datetime, _, integer, _| helpers::cycle_nth_after(Grain::Quarter, integer.value().value - 1, datetime.value())
     );
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn rules_quantity_range(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("between <number> and <number>",
+             number_check!(),
+             b.reg(r#"と"#)?,
+             number_check!(),
+             b.reg(r#"の間"#)?,
+             |start, _, end, _| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <number>",
+             number_check_by_range!(0.0, 999.0),
+             b.reg(r#"〜|~|\-"#)?,
+             number_check_by_range!(0.0, 999.0),
+             |start, _, end| Ok(helpers::quantity_between(start.value(), end.value())?.latent(true)));
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"から|〜|~|\-"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"から|〜|~|\-"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <temperature>",
+             number_check!(),
+             b.reg(r#"から|〜|~|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<temperature> - <temperature>",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"から|〜|~|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<ordinal> - <ordinal>",
+             ordinal_check!(),
+             b.reg(r#"から|〜|~|\-"#)?,
+             ordinal_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_2("<quantity-range> until",
+             quantity_range_check!(|range: &QuantityRangeValue| range.start.is_some() && range.end.is_some()),
+             b.reg(r#"まで"#)?,
+             |a, _| Ok(a.value().clone()));
+    b.rule_2("<number> over",
+             number_check!(),
+             b.reg(r#"超|を?超え(?:る)?"#)?,
+             |a, _| helpers::quantity_above(a.value()));
+    b.rule_2("<number> or more",
+             number_check!(),
+             b.reg(r#"以上"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<amount-of-money> over",
+             amount_of_money_check!(),
+             b.reg(r#"超|を?超え(?:る)?"#)?,
+             |a, _| helpers::quantity_above(a.value()));
+    b.rule_2("<amount-of-money> or more",
+             amount_of_money_check!(),
+             b.reg(r#"以上"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<temperature> over",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"超|を?超え(?:る)?"#)?,
+             |a, _| helpers::quantity_above(a.value()));
+    b.rule_2("<temperature> or more",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"以上"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<number> under",
+             number_check!(),
+             b.reg(r#"未満"#)?,
+             |a, _| helpers::quantity_below(a.value()));
+    b.rule_2("<number> or less",
+             number_check!(),
+             b.reg(r#"以下"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<amount-of-money> under",
+             amount_of_money_check!(),
+             b.reg(r#"未満"#)?,
+             |a, _| helpers::quantity_below(a.value()));
+    b.rule_2("<amount-of-money> or less",
+             amount_of_money_check!(),
+             b.reg(r#"以下"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<temperature> under",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"未満"#)?,
+             |a, _| helpers::quantity_below(a.value()));
+    b.rule_2("<temperature> or less",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"以下"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    Ok(())
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_recurrence(b, &rustling_ontology_values::rules::RecurrenceWords {
+        every: r#"毎"#,
+        every_other: None,
+        every_integer: None,
+        hourly: r#"毎時"#,
+        daily: r#"毎日"#,
+        weekly: r#"毎週"#,
+        monthly: r#"毎月"#,
+        quarterly: None,
+        yearly: r#"毎年"#,
+        and: None,
+        until: None,
+        times: None,
+    })
+}
+
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number>歳",
+             number_check!(),
+             b.reg(r#"歳|才"#)?,
+             |number, _| helpers::age(number.value(), Grain::Year)
+    );
+    Ok(())
+}
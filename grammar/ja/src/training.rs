@@ -96,7 +96,7 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     example!(v, check_moment!(c, [2013, 2, 10]), "一昨日", "二千十三年二月十日", "前の日曜日", "先週の日曜日");
     example!(v, check_moment!(c, [2013, 2, 11]), "昨日", "前の日", "前日");
     example!(v, check_moment!(c, [2013, 2, 13]), "明日", "次の日", "二千十三年二月十三日", "今週の水曜日", "バレンタインデーの前の日");
@@ -125,6 +125,9 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2014]), "来年", "新年");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4], [2013, 2, 12, 12]), "朝", "午前", "今朝");
     example!(v, check_moment!(c, [2013, 12, 25]), "クリスマス", "次のクリスマス",  "二千十三年十二月二十五日", "十二月二十五日");
+    example!(v, check_moment!(c, [2014, 1, 31]), "旧正月", "春節");
+    example!(v, check_moment!(c, [2013]), "平成25年", "平成二十五年");
+    example!(v, check_moment!(c, [2019]), "令和元年", "平成31年");
     example!(v, check_moment_span!(c, [2013, 2, 11, 18], [2013, 2, 12, 3]), "昨晚");
     example!(v, check_moment_span!(c, [2013, 2, 12, 18], [2013, 2, 13, 3]), "今晚", "今夜");
     example!(v, check_moment!(c, [2013, 8, 1]), "二千十三年八月一日", "八月一日");
@@ -187,4 +190,34 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment_span!(c, [2013, 3, 1], [2013, 3, 6]), "一日から五日", "一日から五日まで");
     example!(v, check_moment_span!(c, [2013, 2, 28], [2013, 3, 4]), "二十八日から三日", "二十八日から三日まで");
     example!(v, check_moment!(c, [2013, 2, 10, 14, 57]), "一昨日の午後三時三分前");
-}
\ No newline at end of file
+}
+
+pub fn examples_quantity_range(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity_range(QuantityKind::Number, Some(25.0), None), "25以上");
+    example!(v, check_quantity_range(QuantityKind::Number, None, Some(3.0)), "3未満");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(5.0), Some(10.0)), "5と10の間");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(3.0), Some(4.0)), "3〜4", "3-4");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("$") }, Some(20.0), Some(30.0)), "20から30ドル");
+    example!(v, check_quantity_range(QuantityKind::Temperature { unit: Some("degree") }, Some(25.0), None), "25度以上");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("+33612345678"), "+33 6 12 34 56 78", "0033 6 12 34 56 78", "+33612345678");
+    example!(v, check_phone_number("0312345678"), "03-1234-5678", "03 1234 5678");
+    example!(v, check_email("john.doe@gmail.com"), "john.doe@gmail.com");
+    example!(v, check_url("https://www.example.com/path"), "https://www.example.com/path");
+    example!(v, check_url("www.example.com"), "www.example.com");
+    example!(v, check_url("example.com"), "example.com");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Mon]), "毎週月曜日", "毎週月曜日8時");
+    example!(v, check_recurrence(c.clone(), Grain::Day, 1, vec![]), "毎日", "毎日8時");
+    example!(v, check_recurrence(c.clone(), Grain::Month, 1, vec![]), "毎月");
+    example!(v, check_recurrence(c.clone(), Grain::Year, 1, vec![]), "毎年");
+}
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age(7.0, Grain::Year), "7歳", "7才");
+}
@@ -12,6 +12,10 @@ pub mod training;
 use rustling_ontology_values::DimensionKind::*;
 
 pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
+    Ok(rule_set_builder()?.build())
+}
+
+pub fn rule_set_builder() -> ::rustling::RustlingResult<::rustling::RuleSetBuilder<rustling_ontology_values::Dimension>> {
     let mut b = ::rustling::RuleSetBuilder::new(
                     ::rustling::BoundariesChecker::detailed(),
                     ::rustling::BoundariesChecker::separated_alphanumeric_word());
@@ -21,11 +25,15 @@ pub fn rule_set() -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ont
     rules::rules_duration(&mut b)?;
     rules::rules_temperature(&mut b)?;
     rules::rules_finance(&mut b)?;
-    Ok(b.build())
+    rules::rules_quantity_range(&mut b)?;
+    rustling_ontology_values::rules::rules_contact(&mut b)?;
+    rules::rules_recurrence(&mut b)?;
+    rules::rules_age(&mut b)?;
+    Ok(b)
 }
 
 pub fn dims() -> Vec<rustling_ontology_values::DimensionKind> {
-    return vec![Number, Ordinal, Datetime, Duration, Temperature, AmountOfMoney];
+    return vec![Number, Ordinal, Datetime, Duration, Temperature, AmountOfMoney, QuantityRange, PhoneNumber, Email, Url, Recurrence, Age];
 }
 
 
@@ -35,6 +43,10 @@ pub fn examples() -> Vec<::rustling::train::Example<rustling_ontology_values::Di
     training::examples_datetime(&mut v);
     training::examples_finance(&mut v);
     training::examples_temperature(&mut v);
+    training::examples_quantity_range(&mut v);
+    training::examples_contact(&mut v);
+    training::examples_recurrence(&mut v);
+    training::examples_age(&mut v);
     v
 }
 
@@ -1,6 +1,6 @@
 use regex::Regex;
 use rustling::{RuleError, RuleResult, RuleSetBuilder, RustlingResult};
-use rustling_ontology_moment::{Grain, PeriodComp, Weekday};
+use rustling_ontology_moment::{Calendar, Grain, PeriodComp, Weekday};
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
 
@@ -345,25 +345,22 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         helpers::month_day(1, 1)
     });
 
-    // b.rule_1("Korean New Year",
-    //     b.reg(r#"설날|설|원일|구정"#)?,
-    //     based on the lunear calendar which is not supported yet
-    // );
-
-    // b.rule_1("Buddha’s Birthday",
-    //     b.reg(r#"부처님 오신 날|석존성탄절|석가탄신일|석탄일|석탄절"#)?,
-    //     based on the lunear calendar which is not supported yet
-    // );
-
-    // b.rule_1("Chuseok",
-    //     b.reg(r#"추석|한가위"#)?,
-    //     based on the lunear calendar which is not supported yet
-    // );
-
-    // b.rule_1("Chuseok Holidays",
-    //     b.reg(r#"추석연휴"#),
-    //     based on the lunear calendar which is not supported yet
-    // );
+    b.rule_1_terminal("Korean New Year", b.reg(r#"설날|설|원일|구정"#)?, |_| {
+        helpers::calendar_date(Calendar::Korean, 1, 1)
+    });
+    b.rule_1_terminal(
+        "Buddha’s Birthday",
+        b.reg(r#"부처님 오신 날|석존성탄절|석가탄신일|석탄일|석탄절"#)?,
+        |_| helpers::calendar_date(Calendar::Korean, 4, 8),
+    );
+    b.rule_1_terminal("Chuseok", b.reg(r#"추석|한가위"#)?, |_| {
+        helpers::calendar_date(Calendar::Korean, 8, 15)
+    });
+    b.rule_1_terminal("Chuseok Holidays", b.reg(r#"추석 ?연휴"#)?, |_| {
+        let start = helpers::calendar_date(Calendar::Korean, 8, 14)?;
+        let end = helpers::calendar_date(Calendar::Korean, 8, 16)?;
+        start.span_to(&end, true)
+    });
 
     b.rule_1_terminal("Independence Movement Day", b.reg(r#"삼일절"#)?, |_| {
         helpers::month_day(3, 1)
@@ -1027,11 +1024,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
         |datetime, pod| pod.value().intersect(datetime.value()),
     );
 
-    b.rule_1_terminal("week-end", b.reg(r#"주말"#)?, |_| {
-        let friday = helpers::day_of_week(Weekday::Fri)?.intersect(&helpers::hour(18, false)?)?;
-        let monday = helpers::day_of_week(Weekday::Mon)?.intersect(&helpers::hour(0, false)?)?;
-        friday.span_to(&monday, false)
-    });
+    b.rule_1_terminal("week-end", b.reg(r#"주말"#)?, |_| helpers::weekend());
     b.rule_1_terminal("season - summer", b.reg(r#"여름"#)?, |_| {
         helpers::month_day(6, 21)?.span_to(&helpers::month_day(9, 23)?, false)
     });
@@ -1817,3 +1810,121 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_quantity_range(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("between <number> and <number>",
+             number_check!(),
+             b.reg(r#"와|과|에서"#)?,
+             number_check!(),
+             b.reg(r#"사이"#)?,
+             |start, _, end, _| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <number>",
+             number_check_by_range!(0.0, 999.0),
+             b.reg(r#"~|\-"#)?,
+             number_check_by_range!(0.0, 999.0),
+             |start, _, end| Ok(helpers::quantity_between(start.value(), end.value())?.latent(true)));
+    b.rule_3("<number> - <amount-of-money>",
+             number_check!(),
+             b.reg(r#"에서|부터|~|\-"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<amount-of-money> - <amount-of-money>",
+             amount_of_money_check!(),
+             b.reg(r#"에서|부터|~|\-"#)?,
+             amount_of_money_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <temperature>",
+             number_check!(),
+             b.reg(r#"에서|부터|~|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<temperature> - <temperature>",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"에서|부터|~|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<ordinal> - <ordinal>",
+             ordinal_check!(),
+             b.reg(r#"에서|부터|~|\-"#)?,
+             ordinal_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_2("<quantity-range> until",
+             quantity_range_check!(|range: &QuantityRangeValue| range.start.is_some() && range.end.is_some()),
+             b.reg(r#"까지"#)?,
+             |a, _| Ok(a.value().clone()));
+    b.rule_2("<number> over",
+             number_check!(),
+             b.reg(r#"초과|넘게"#)?,
+             |a, _| helpers::quantity_above(a.value()));
+    b.rule_2("<number> or more",
+             number_check!(),
+             b.reg(r#"이상"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<amount-of-money> over",
+             amount_of_money_check!(),
+             b.reg(r#"초과|넘게"#)?,
+             |a, _| helpers::quantity_above(a.value()));
+    b.rule_2("<amount-of-money> or more",
+             amount_of_money_check!(),
+             b.reg(r#"이상"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<temperature> over",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"초과|넘게"#)?,
+             |a, _| helpers::quantity_above(a.value()));
+    b.rule_2("<temperature> or more",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"이상"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<number> under",
+             number_check!(),
+             b.reg(r#"미만"#)?,
+             |a, _| helpers::quantity_below(a.value()));
+    b.rule_2("<number> or less",
+             number_check!(),
+             b.reg(r#"이하"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<amount-of-money> under",
+             amount_of_money_check!(),
+             b.reg(r#"미만"#)?,
+             |a, _| helpers::quantity_below(a.value()));
+    b.rule_2("<amount-of-money> or less",
+             amount_of_money_check!(),
+             b.reg(r#"이하"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<temperature> under",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"미만"#)?,
+             |a, _| helpers::quantity_below(a.value()));
+    b.rule_2("<temperature> or less",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"이하"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    Ok(())
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_recurrence(b, &rustling_ontology_values::rules::RecurrenceWords {
+        every: r#"매"#,
+        every_other: None,
+        every_integer: None,
+        hourly: r#"매시간"#,
+        daily: r#"매일|날마다"#,
+        weekly: r#"매주"#,
+        monthly: r#"매달|매월"#,
+        quarterly: None,
+        yearly: r#"매년|해마다"#,
+        and: None,
+        until: None,
+        times: None,
+    })
+}
+
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number>살",
+             number_check!(),
+             b.reg(r#"살|세"#)?,
+             |number, _| helpers::age(number.value(), Grain::Year)
+    );
+    Ok(())
+}
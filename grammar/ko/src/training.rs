@@ -29,7 +29,7 @@ pub fn examples_temperature(v: &mut Vec<::rustling::train::Example<Dimension>>)
 }
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 0]), "방금", "지금");
     example!(v, check_moment!(c, [2013, 2, 12]), "오늘");
     example!(v, check_moment!(c, [2013, 2, 11]), "어제");
@@ -149,6 +149,10 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 12, 25]), "크리스마스");
     example!(v, check_moment!(c, [2013, 12, 24]), "크리스마스이브");
     example!(v, check_moment!(c, [2014, 1, 1]), "신정");
+    example!(v, check_moment!(c, [2014, 1, 31]), "설날", "구정");
+    example!(v, check_moment!(c, [2013, 5, 17]), "석가탄신일", "부처님 오신 날");
+    example!(v, check_moment!(c, [2013, 9, 19]), "추석", "한가위");
+    example!(v, check_moment_span!(c, [2013, 9, 18], [2013, 9, 21]), "추석연휴");
     example!(v, check_moment!(c, [2013, 3, 1]), "삼일절");
     example!(v, check_moment!(c, [2013, 5, 5]), "어린이날");
     example!(v, check_moment!(c, [2013, 6, 6]), "현충일");
@@ -314,3 +318,32 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(25), "스물다섯번째", "이십오번째");
     example!(v, check_ordinal(1), "첫번째", "첫째", "첫번", "첫");
 }
+
+pub fn examples_quantity_range(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity_range(QuantityKind::Number, Some(25.0), None), "25 이상");
+    example!(v, check_quantity_range(QuantityKind::Number, None, Some(3.0)), "3 미만");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(5.0), Some(10.0)), "5와 10 사이", "5에서 10 사이");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(3.0), Some(4.0)), "3~4", "3-4");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("$") }, None, Some(50.0)), "50달러 이하");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("+33612345678"), "+33 6 12 34 56 78", "0033 6 12 34 56 78", "+33612345678");
+    example!(v, check_phone_number("0212345678"), "02-1234-5678", "02 1234 5678");
+    example!(v, check_email("john.doe@gmail.com"), "john.doe@gmail.com");
+    example!(v, check_url("https://www.example.com/path"), "https://www.example.com/path");
+    example!(v, check_url("www.example.com"), "www.example.com");
+    example!(v, check_url("example.com"), "example.com");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Mon]), "매주 월요일");
+    example!(v, check_recurrence(c.clone(), Grain::Day, 1, vec![]), "매일", "날마다");
+    example!(v, check_recurrence(c.clone(), Grain::Month, 1, vec![]), "매달", "매월");
+    example!(v, check_recurrence(c.clone(), Grain::Year, 1, vec![]), "매년");
+}
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age(7.0, Grain::Year), "7살", "7세");
+}
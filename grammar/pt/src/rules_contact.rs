@@ -0,0 +1,12 @@
+use rustling::*;
+use rustling_ontology_values::dimension::*;
+use rustling_ontology_values::helpers;
+
+pub fn rules_contact(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_contact(b)?;
+    b.rule_1_terminal("spoken email address",
+                      b.reg(r#"((?:[a-z0-9]+ (?:sublinhado|underline|hífen|traço|ponto) )*[a-z0-9]+) (?:arroba) ((?:[a-z0-9]+ (?:sublinhado|underline|hífen|traço|ponto) )+[a-z]{2,})"#)?,
+                      |text_match| helpers::spoken_email(text_match.group(1), text_match.group(2), &[("sublinhado", "_"), ("underline", "_"), ("hífen", "-"), ("traço", "-"), ("ponto", ".")])
+    );
+    Ok(())
+}
@@ -377,18 +377,16 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     b.rule_1_terminal("end of week (not weekend)",
                       b.reg(r#"(fim|final) d[ae] semana"#)?,
-                      |_| helpers::day_of_week(Weekday::Thu)
-                          ?.span_to(&helpers::day_of_week(Weekday::Sun)?, false)
+                      |_| helpers::nth_day_of_week(3)
+                          ?.span_to(&helpers::nth_day_of_week(-1)?, false)
+    );
+    b.rule_1_terminal("week <number> (ISO 8601)",
+                      b.reg(r#"(?:a )?semana (?:n[°º] ?|n[úu]mero )?(\d{1,2})"#)?,
+                      |text_match| helpers::week_of_year(text_match.group(1).parse()?)
     );
     b.rule_1_terminal("week-end",
                       b.reg(r#"(fim|final) de semana"#)?,
-                      |_| {
-                          let friday = helpers::day_of_week(Weekday::Fri)?
-                              .intersect(&helpers::hour(18, false)?)?;
-                          let monday = helpers::day_of_week(Weekday::Mon)?
-                              .intersect(&helpers::hour(0, false)?)?;
-                          friday.span_to(&monday, false)
-                      }
+                      |_| helpers::weekend()
     );
     b.rule_2("dernier week-end de <time>",
              b.reg(r#"último (fim|final) de semana(?: de| do)?"#)?,
@@ -1011,6 +1009,21 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                           text_match.group(1).parse()?
                       )
     );
+    b.rule_1_terminal("mm/dd/yy or mm/dd/yyyy - Ambiguous cases - month first",
+                      b.reg(r#"(0?[1-9]|1[0-2])[-/.](0?[1-9]|1[0-2])[-/.](\d{2,4})"#)?,
+                      |text_match| Ok(helpers::year_month_day(
+                          text_match.group(3).parse()?,
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)?
+                          .date_order(Some(DateOrder::MonthDayYear)))
+    );
+    b.rule_1_terminal("mm/dd - Ambiguous cases - month first",
+                      b.reg(r#"(0?[1-9]|1[0-2])[-/](0?[1-9]|1[0-2])"#)?,
+                      |text_match| Ok(helpers::month_day(
+                          text_match.group(1).parse()?,
+                          text_match.group(2).parse()?)?
+                          .date_order(Some(DateOrder::MonthDayYear)))
+    );
     //    b.rule_3("<time> <part-of-day>",
 //            datetime_check!(|time: &DatetimeValue| excluding_form!(Form::Year(_))(time) && excluding_form!(Form::Month(_))(time)),
 //            b.reg(r#"à|de|da"#)?,
@@ -1162,3 +1175,37 @@ pub fn rules_cycle(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_recurrence(b, &rustling_ontology_values::rules::RecurrenceWords {
+        every: r#"cada|todo|toda|todos os|todas as"#,
+        every_other: Some(r#"a cada duas|cada duas|a cada dois|cada dois"#),
+        every_integer: Some(r#"a cada|cada"#),
+        hourly: r#"de hora em hora"#,
+        daily: r#"diariamente"#,
+        weekly: r#"semanalmente"#,
+        monthly: r#"mensalmente"#,
+        quarterly: Some(r#"trimestralmente"#),
+        yearly: r#"anualmente"#,
+        and: Some(r#"e"#),
+        until: Some(r#"até(?: o| a)?"#),
+        times: Some(r#"vezes"#),
+    })?;
+    b.rule_1_terminal("every <day-of-week> (plural)",
+                      b.reg(r#"(?:tod[ao]s )?(?:as|os) (segunda|terça|quarta|quinta|sexta|sábado|domingo)s(?:[- ]feiras)?"#)?,
+                      |text_match| {
+                          let weekday = match text_match.group(1) {
+                              "segunda" => Weekday::Mon,
+                              "terça" => Weekday::Tue,
+                              "quarta" => Weekday::Wed,
+                              "quinta" => Weekday::Thu,
+                              "sexta" => Weekday::Fri,
+                              "sábado" => Weekday::Sat,
+                              "domingo" => Weekday::Sun,
+                              _ => return Err(RuleError::Invalid.into()),
+                          };
+                          helpers::recurrence(&helpers::day_of_week(weekday)?, 1)
+                      }
+    );
+    Ok(())
+}
@@ -145,5 +145,32 @@ pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
              b.reg(r#"exactamente|precisamente"#)?,
              |duration , _| Ok(duration.value().clone().precision(Precision::Exact))
     );
+    b.rule_2("<fraction> <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             unit_of_duration_check!(),
+             |number, uod| helpers::fractional_duration(number.value(), uod.value())
+    );
+    b.rule_3("<fraction> of <unit-of-duration>",
+             number_check!(|number: &NumberValue| number.fraction().is_some()),
+             b.reg(r#"de"#)?,
+             unit_of_duration_check!(),
+             |number, _, uod| helpers::fractional_duration(number.value(), uod.value())
+    );
+    Ok(())
+}
+
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_3("tenho <number> <unit-of-duration>",
+             b.reg(r#"tenho|tens|tem|temos|tendes|t[eê]m"#)?,
+             number_check!(),
+             unit_of_duration_check!(|uod: &UnitOfDurationValue| uod.grain == Grain::Year || uod.grain == Grain::Month),
+             |_, number, uod| helpers::age(number.value(), uod.value().grain)
+    );
+    b.rule_3("<number> <unit-of-duration> de idade",
+             number_check!(),
+             unit_of_duration_check!(),
+             b.reg(r#"de idade"#)?,
+             |number, uod, _| helpers::age(number.value(), uod.value().grain)
+    );
     Ok(())
 }
@@ -506,3 +506,43 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
     );
     Ok(())
 }
+
+pub fn rules_fraction(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1("<numerator>/<denominator> (latent)",
+             b.reg(r#"(\d+)/(\d+)"#)?,
+             |text_match| Ok(helpers::fraction(text_match.group(1).parse()?, text_match.group(2).parse()?)?.latent(true)));
+    b.rule_2("<integer> <denominator>",
+             integer_check_by_range!(1, 99),
+             b.reg(r#"(meio|ter[çc]o|quarto|quinto|sexto|s[eé]timo|oitavo|nono|d[eé]cimo)s?"#)?,
+             |integer, text_match| {
+                 let denominator = match text_match.group(1).as_ref() {
+                     "meio" => 2,
+                     "terço" | "terco" => 3,
+                     "quarto" => 4,
+                     "quinto" => 5,
+                     "sexto" => 6,
+                     "sétimo" | "setimo" => 7,
+                     "oitavo" => 8,
+                     "nono" => 9,
+                     "décimo" | "decimo" => 10,
+                     _ => return Err(RuleError::Invalid.into()),
+                 };
+                 helpers::fraction(integer.value().value, denominator)
+             });
+    b.rule_2("<integer> and a half",
+             integer_check_by_range!(0),
+             b.reg(r#"e mei[oa]"#)?,
+             |integer, _| helpers::fraction(integer.value().value * 2 + 1, 2));
+    b.rule_2("<integer> <fraction>",
+             integer_check_by_range!(0),
+             number_check!(|number: &NumberValue| number.fraction().is_some() && number.value() < 1.0),
+             |integer, fraction| helpers::mixed_number(integer.value(), fraction.value()));
+    Ok(())
+}
+
+pub fn rules_roman_numerals(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_roman_numerals(b, &rustling_ontology_values::rules::RomanNumeralWords {
+        nouns: r#"cap[íi]tulo|parte|tomo|volume|livro|ato|cena|artigo|se[çc][ãa]o|t[íi]tulo|anexo|fase|etapa|n[íi]vel|classe|tipo"#,
+        names: r#"lu[íi]s|henrique|carlos|filipe|pedro|manuel|afonso|isabel|jo[ãa]o|papa (?:pio|le[ãa]o|bento|greg[óo]rio|jo[ãa]o paulo)"#,
+    })
+}
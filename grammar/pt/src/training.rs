@@ -87,7 +87,7 @@ pub fn examples_finance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 }
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
 
     // Days
     example!(v, check_moment!(c, [2013, 2, 12, 4, 30, 00]), "agora", "agora mesmo", "neste exato momento", "neste momento");
@@ -141,6 +141,7 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     //example!(v, check_moment!(c, [2013, 2, 11], Grain::Week), "esta semana");
     example!(v, check_moment!(c, [2013, 2, 4], Grain::Week), "a semana passada", "a última semana");
     example!(v, check_moment!(c, [2013, 2, 18], Grain::Week), "a próxima semana", "a semana que vem");
+    example!(v, check_moment!(c, [2013, 10, 14], Grain::Week), "semana 42", "a semana número 42");
     example!(v, check_moment!(c, [2013, 1]), "o mês passado", "o último mês");
     example!(v, check_moment!(c, [2013, 3]), "o mês que vem", "o próximo mês");
     example!(v, check_moment!(c, [2012]), "o ano passado", "o último ano");
@@ -347,3 +348,93 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_duration!([0, 0, 0, 0, 1]), "durante uma hora", "por uma hora");
     example!(v, check_duration!([0, 0, 2]), "durante 2 semanas", "por duas semanas");
 }
+
+pub fn examples_distance(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_distance(5.0, Some("km"), Precision::Exact), "5 km", "5km", "cinco quilômetros");
+    example!(v, check_distance(200.0, Some("m"), Precision::Exact), "200 metros", "duzentos metros");
+    example!(v, check_distance(3.0, Some("mi"), Precision::Exact), "3 milhas", "três milhas");
+    example!(v, check_distance(1.0, Some("km"), Precision::Exact), "um quilômetro");
+    example!(v, check_distance(12.0, Some("cm"), Precision::Exact), "12 cm", "doze centímetros");
+    example!(v, check_distance(4.0, Some("mm"), Precision::Exact), "4 mm", "quatro milímetros");
+    example!(v, check_distance(6.0, Some("ft"), Precision::Exact), "6 pés");
+    example!(v, check_distance(2.0, Some("in"), Precision::Exact), "2 polegadas", "duas polegadas");
+    example!(v, check_distance(10.0, Some("km"), Precision::Approximate), "aproximadamente 10 km", "cerca de dez quilômetros");
+    example!(v, check_distance(400.0, Some("m"), Precision::Exact), "exatamente 400 metros");
+}
+
+pub fn examples_volume(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_volume(2.0, Some("l"), Precision::Exact), "2 litros", "dois litros", "2l");
+    example!(v, check_volume(500.0, Some("ml"), Precision::Exact), "500 ml", "quinhentos mililitros");
+    example!(v, check_volume(25.0, Some("cl"), Precision::Exact), "25 cl");
+    example!(v, check_volume(3.0, Some("cup"), Precision::Exact), "três xícaras");
+    example!(v, check_volume(2.0, Some("tbsp"), Precision::Exact), "duas colheres de sopa");
+    example!(v, check_volume(1.0, Some("tsp"), Precision::Exact), "uma colher de chá");
+    example!(v, check_volume(1.0, Some("l"), Precision::Approximate), "aproximadamente um litro");
+}
+
+pub fn examples_mass(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_mass(500.0, Some("g"), Precision::Exact), "500 gramas", "500g", "quinhentos gramas");
+    example!(v, check_mass(1.0, Some("lb"), Precision::Exact), "uma libra");
+    example!(v, check_mass(2.0, Some("kg"), Precision::Exact), "2 kg", "dois quilos", "dois quilogramas");
+    example!(v, check_mass(250.0, Some("mg"), Precision::Exact), "250 mg");
+    example!(v, check_mass(3.0, Some("oz"), Precision::Exact), "três onças");
+    example!(v, check_mass(1.0, Some("t"), Precision::Exact), "uma tonelada");
+    example!(v, check_mass(5.0, Some("kg"), Precision::Approximate), "aproximadamente 5 kg");
+    example!(v, check_mass(100.0, Some("g"), Precision::Exact), "exatamente 100 gramas");
+}
+
+pub fn examples_rate(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_rate(50.0, Some("km"), "hour", Precision::Exact), "50 km/h", "cinquenta quilômetros por hora");
+    example!(v, check_rate(5.0, Some("minute"), "km", Precision::Exact), "5 minutos por quilômetro", "cinco minutos por km");
+    example!(v, check_rate(3.0, None, "day", Precision::Exact), "3 por dia");
+    example!(v, check_rate(90.0, Some("km"), "hour", Precision::Approximate), "aproximadamente 90 km/h");
+}
+
+pub fn examples_quantity_range(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity_range(QuantityKind::Number, Some(25.0), None), "mais de 25", "25 ou mais");
+    example!(v, check_quantity_range(QuantityKind::Number, None, Some(3.0)), "menos de 3");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(5.0), Some(10.0)), "entre 5 e 10", "entre cinco e dez");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(3.0), Some(4.0)), "3-4", "3 - 4");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("EUR") }, Some(20.0), Some(30.0)), "entre 20 e 30 euros");
+    example!(v, check_quantity_range(QuantityKind::AmountOfMoney { unit: Some("EUR") }, None, Some(50.0)), "menos de 50 euros");
+    example!(v, check_quantity_range(QuantityKind::Temperature { unit: Some("degree") }, Some(25.0), None), "acima de 25 graus");
+}
+
+pub fn examples_fraction(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_fraction(3, 4), "três quartos");
+    example!(v, check_fraction(1, 3), "um terço");
+    example!(v, check_fraction(5, 2), "dois e meio");
+    example!(v, check_duration!([0, 0, 0, 0, 0, 45]), "três quartos de hora");
+}
+
+pub fn examples_roman_numerals(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_integer(4), "capítulo iv");
+    example!(v, check_integer(2), "tomo ii");
+    example!(v, check_ordinal(14), "luís xiv");
+    example!(v, check_ordinal(2), "pedro ii", "Pedro II");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("+33612345678"), "+33 6 12 34 56 78", "0033 6 12 34 56 78", "+33612345678");
+    example!(v, check_phone_number("211234567"), "21 123 4567", "21-123-4567");
+    example!(v, check_email("john.doe@gmail.com"), "john.doe@gmail.com");
+    example!(v, check_email("joao.silva@gmail.com"), "joao ponto silva arroba gmail ponto com");
+    example!(v, check_url("https://www.example.com/path"), "https://www.example.com/path");
+    example!(v, check_url("www.example.com"), "www.example.com");
+    example!(v, check_url("example.com"), "example.com");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Mon]), "toda segunda", "todas as segundas", "toda segunda-feira");
+    example!(v, check_recurrence(c.clone(), Grain::Day, 1, vec![]), "todo dia", "todos os dias", "diariamente");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 2, vec![]), "a cada 2 semanas", "cada duas semanas");
+    example!(v, check_recurrence(c.clone(), Grain::Month, 1, vec![]), "todo mês", "mensalmente");
+    example!(v, check_recurrence(c.clone(), Grain::Year, 1, vec![]), "todo ano", "anualmente");
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Tue]), "toda terça até março");
+}
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age(35.0, Grain::Year), "tenho 35 anos", "tem trinta e cinco anos", "35 anos de idade");
+    example!(v, check_age(6.0, Grain::Month), "tem 6 meses");
+}
@@ -1,29 +1,52 @@
 extern crate rustling;
 extern crate rustling_ontology_values;
+#[cfg(feature = "lang-de")]
 extern crate rustling_ontology_de as de;
+#[cfg(feature = "lang-en")]
 extern crate rustling_ontology_en as en;
+#[cfg(feature = "lang-es")]
 extern crate rustling_ontology_es as es;
+#[cfg(feature = "lang-fr")]
 extern crate rustling_ontology_fr as fr;
+#[cfg(feature = "lang-pt")]
 extern crate rustling_ontology_pt as pt;
+#[cfg(feature = "lang-ja")]
 extern crate rustling_ontology_ja as ja;
+#[cfg(feature = "lang-ko")]
 extern crate rustling_ontology_ko as ko;
+#[cfg(feature = "lang-zh")]
 extern crate rustling_ontology_zh as zh;
+#[cfg(feature = "lang-it")]
 extern crate rustling_ontology_it as it;
 
 use std::result;
 
+#[cfg(not(any(
+    feature = "lang-de",
+    feature = "lang-en",
+    feature = "lang-es",
+    feature = "lang-fr",
+    feature = "lang-pt",
+    feature = "lang-ja",
+    feature = "lang-ko",
+    feature = "lang-zh",
+    feature = "lang-it"
+)))]
+compile_error!("At least one language must be enabled with its `lang-*` cargo feature");
+
 macro_rules! lang_enum {
-    ([$($lang:ident),*]) => {
-        /// Enumerates all language supported for the general purpose ontology.
+    ([$($lang:ident: $feature:tt),*]) => {
+        /// Enumerates all language supported for the general purpose ontology. Each language is
+        /// enabled by its `lang-*` cargo feature.
         #[derive(Copy,Clone,Debug,PartialEq, Eq)]
         pub enum Lang {
-            $( $lang, )*
+            $( #[cfg(feature = $feature)] $lang, )*
         }
 
         impl Lang {
             pub fn all() -> Vec<Lang> {
                 vec![
-                    $( Lang::$lang, )*
+                    $( #[cfg(feature = $feature)] Lang::$lang, )*
                 ]
             }
         }
@@ -32,7 +55,7 @@ macro_rules! lang_enum {
             type Err = String;
             fn from_str(it: &str) -> result::Result<Lang, Self::Err> {
                 match &*it.to_uppercase() {
-                    $( stringify!($lang) => Ok(Lang::$lang),  )*
+                    $( #[cfg(feature = $feature)] stringify!($lang) => Ok(Lang::$lang),  )*
                     _ => Err(format!("Unknown language {}", it)),
                 }
             }
@@ -41,7 +64,7 @@ macro_rules! lang_enum {
         impl ::std::string::ToString for Lang {
             fn to_string(&self) -> String {
                 match self {
-                    $( &Lang::$lang => stringify!($lang).to_string(),)*
+                    $( #[cfg(feature = $feature)] &Lang::$lang => stringify!($lang).to_string(),)*
                 }
             }
         }
@@ -49,34 +72,133 @@ macro_rules! lang_enum {
     }
 }
 
-lang_enum!([DE, EN, ES, FR, PT, JA, KO, ZH, IT]);
+lang_enum!([
+    DE: "lang-de",
+    EN: "lang-en",
+    ES: "lang-es",
+    FR: "lang-fr",
+    PT: "lang-pt",
+    JA: "lang-ja",
+    KO: "lang-ko",
+    ZH: "lang-zh",
+    IT: "lang-it"
+]);
 
 /// Obtain rules for a given language.
 pub fn rules(lang: Lang) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>> {
     match lang {
+        #[cfg(feature = "lang-de")]
         Lang::DE => de::rule_set(),
+        #[cfg(feature = "lang-en")]
         Lang::EN => en::rule_set(),
+        #[cfg(feature = "lang-es")]
         Lang::ES => es::rule_set(),
+        #[cfg(feature = "lang-fr")]
         Lang::FR => fr::rule_set(),
+        #[cfg(feature = "lang-pt")]
         Lang::PT => pt::rule_set(),
+        #[cfg(feature = "lang-ja")]
         Lang::JA => ja::rule_set(),
+        #[cfg(feature = "lang-ko")]
         Lang::KO => ko::rule_set(),
+        #[cfg(feature = "lang-zh")]
         Lang::ZH => zh::rule_set(),
+        #[cfg(feature = "lang-it")]
         Lang::IT => it::rule_set(),
     }
 }
 
+/// Obtain rules for a given language, extended with the custom rules added by `extension` to the
+/// builder of the built-in rules.
+pub fn rules_with<F>(lang: Lang, extension: F) -> ::rustling::RustlingResult<::rustling::RuleSet<rustling_ontology_values::Dimension>>
+    where F: FnOnce(&mut ::rustling::RuleSetBuilder<rustling_ontology_values::Dimension>) -> ::rustling::RustlingResult<()>
+{
+    let mut b = match lang {
+        #[cfg(feature = "lang-de")]
+        Lang::DE => de::rule_set_builder()?,
+        #[cfg(feature = "lang-en")]
+        Lang::EN => en::rule_set_builder()?,
+        #[cfg(feature = "lang-es")]
+        Lang::ES => es::rule_set_builder()?,
+        #[cfg(feature = "lang-fr")]
+        Lang::FR => fr::rule_set_builder()?,
+        #[cfg(feature = "lang-pt")]
+        Lang::PT => pt::rule_set_builder()?,
+        #[cfg(feature = "lang-ja")]
+        Lang::JA => ja::rule_set_builder()?,
+        #[cfg(feature = "lang-ko")]
+        Lang::KO => ko::rule_set_builder()?,
+        #[cfg(feature = "lang-zh")]
+        Lang::ZH => zh::rule_set_builder()?,
+        #[cfg(feature = "lang-it")]
+        Lang::IT => it::rule_set_builder()?,
+    };
+    extension(&mut b)?;
+    Ok(b.build())
+}
+
+/// Add the opt-in Roman numeral rules of a language to a builder, e.g. "chapter iv" or
+/// "louis xiv", typically as the extension of `rules_with`. A bare Roman numeral is a latent number
+/// and ordinal. Only Latin-script languages have Roman numeral rules.
+pub fn rules_roman_numerals(lang: Lang, b: &mut ::rustling::RuleSetBuilder<rustling_ontology_values::Dimension>) -> ::rustling::RustlingResult<()> {
+    match lang {
+        #[cfg(feature = "lang-de")]
+        Lang::DE => de::rules_roman_numerals(b),
+        #[cfg(feature = "lang-en")]
+        Lang::EN => en::rules_roman_numerals(b),
+        #[cfg(feature = "lang-es")]
+        Lang::ES => es::rules_roman_numerals(b),
+        #[cfg(feature = "lang-fr")]
+        Lang::FR => fr::rules_roman_numerals(b),
+        #[cfg(feature = "lang-pt")]
+        Lang::PT => pt::rules_roman_numerals(b),
+        #[cfg(feature = "lang-it")]
+        Lang::IT => it::rules_roman_numerals(b),
+        #[allow(unreachable_patterns)]
+        _ => Ok(()),
+    }
+}
+
+/// Obtain the training examples of the opt-in Roman numeral rules for a given language.
+pub fn examples_roman_numerals(lang: Lang) -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
+    match lang {
+        #[cfg(feature = "lang-de")]
+        Lang::DE => de::examples_roman_numerals(),
+        #[cfg(feature = "lang-en")]
+        Lang::EN => en::examples_roman_numerals(),
+        #[cfg(feature = "lang-es")]
+        Lang::ES => es::examples_roman_numerals(),
+        #[cfg(feature = "lang-fr")]
+        Lang::FR => fr::examples_roman_numerals(),
+        #[cfg(feature = "lang-pt")]
+        Lang::PT => pt::examples_roman_numerals(),
+        #[cfg(feature = "lang-it")]
+        Lang::IT => it::examples_roman_numerals(),
+        #[allow(unreachable_patterns)]
+        _ => vec![],
+    }
+}
+
 /// Obtain dimensions for a given language.
 pub fn dims(lang: Lang) -> Vec<rustling_ontology_values::DimensionKind> {
     match lang {
+        #[cfg(feature = "lang-de")]
         Lang::DE => de::dims(),
+        #[cfg(feature = "lang-en")]
         Lang::EN => en::dims(),
+        #[cfg(feature = "lang-es")]
         Lang::ES => es::dims(),
+        #[cfg(feature = "lang-fr")]
         Lang::FR => fr::dims(),
+        #[cfg(feature = "lang-pt")]
         Lang::PT => pt::dims(),
+        #[cfg(feature = "lang-ja")]
         Lang::JA => ja::dims(),
+        #[cfg(feature = "lang-ko")]
         Lang::KO => ko::dims(),
+        #[cfg(feature = "lang-zh")]
         Lang::ZH => zh::dims(),
+        #[cfg(feature = "lang-it")]
         Lang::IT => it::dims(),
     }
 }
@@ -84,14 +206,23 @@ pub fn dims(lang: Lang) -> Vec<rustling_ontology_values::DimensionKind> {
 /// Obtain examples for a given language.
 pub fn examples(lang: Lang) -> Vec<::rustling::train::Example<rustling_ontology_values::Dimension>> {
     match lang {
+        #[cfg(feature = "lang-de")]
         Lang::DE => de::examples(),
+        #[cfg(feature = "lang-en")]
         Lang::EN => en::examples(),
+        #[cfg(feature = "lang-es")]
         Lang::ES => es::examples(),
+        #[cfg(feature = "lang-fr")]
         Lang::FR => fr::examples(),
+        #[cfg(feature = "lang-pt")]
         Lang::PT => pt::examples(),
+        #[cfg(feature = "lang-ja")]
         Lang::JA => ja::examples(),
+        #[cfg(feature = "lang-ko")]
         Lang::KO => ko::examples(),
+        #[cfg(feature = "lang-zh")]
         Lang::ZH => zh::examples(),
+        #[cfg(feature = "lang-it")]
         Lang::IT => it::examples(),
     }
 }
@@ -1,7 +1,7 @@
 use rustling::*;
 use rustling_ontology_values::dimension::*;
 use rustling_ontology_values::helpers;
-use rustling_ontology_moment::{Weekday, Grain, PeriodComp};
+use rustling_ontology_moment::{Weekday, Grain, PeriodComp, Calendar};
 
 
 pub fn rules_duration(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
@@ -213,6 +213,41 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
                       |_| helpers::month_day(12, 25)
     );
 
+    b.rule_1_terminal("chinese new year",
+                      b.reg(r#"春(?:节|節)|(?:农|農)(?:历|曆)新年|大年初一"#)?,
+                      |_| helpers::calendar_date(Calendar::Chinese, 1, 1)
+    );
+
+    b.rule_1_terminal("chinese new year's eve",
+                      b.reg(r#"除夕(?:夜)?|大年(?:三十|夜)"#)?,
+                      |_| helpers::cycle_nth_after(Grain::Day, -1, &helpers::calendar_date(Calendar::Chinese, 1, 1)?)
+    );
+
+    b.rule_1_terminal("lantern festival",
+                      b.reg(r#"元宵(?:节|節)?"#)?,
+                      |_| helpers::calendar_date(Calendar::Chinese, 1, 15)
+    );
+
+    b.rule_1_terminal("dragon boat festival",
+                      b.reg(r#"端午(?:节|節)?"#)?,
+                      |_| helpers::calendar_date(Calendar::Chinese, 5, 5)
+    );
+
+    b.rule_1_terminal("qixi festival",
+                      b.reg(r#"七夕(?:节|節)?"#)?,
+                      |_| helpers::calendar_date(Calendar::Chinese, 7, 7)
+    );
+
+    b.rule_1_terminal("mid-autumn festival",
+                      b.reg(r#"中秋(?:节|節)?"#)?,
+                      |_| helpers::calendar_date(Calendar::Chinese, 8, 15)
+    );
+
+    b.rule_1_terminal("double ninth festival",
+                      b.reg(r#"重(?:阳|陽)(?:节|節)?"#)?,
+                      |_| helpers::calendar_date(Calendar::Chinese, 9, 9)
+    );
+
     b.rule_1_terminal("now",
                       b.reg(r#"现在|此时|此刻|当前|現在|此時|當前|宜家|而家|依家"#)?,
                       |_| helpers::cycle_nth(Grain::Second, 0)
@@ -237,13 +272,7 @@ pub fn rules_datetime(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     b.rule_1_terminal("week-end",
                       b.reg(r#"(周|週)末"#)?,
-                      |_| {
-                          let friday = helpers::day_of_week(Weekday::Fri)?
-                              .intersect(&helpers::hour(18, false)?)?;
-                          let monday = helpers::day_of_week(Weekday::Mon)?
-                              .intersect(&helpers::hour(0, false)?)?;
-                          friday.span_to(&monday, false)
-                      }
+                      |_| helpers::weekend()
     );
 
     b.rule_1_terminal("last year",
@@ -931,3 +960,107 @@ pub fn rules_numbers(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
 
     Ok(())
 }
+
+pub fn rules_quantity_range(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_4("between <number> and <number>",
+             number_check!(),
+             b.reg(r#"和|与|到|至"#)?,
+             number_check!(),
+             b.reg(r#"之间"#)?,
+             |start, _, end, _| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<number> - <number>",
+             number_check_by_range!(0.0, 999.0),
+             b.reg(r#"~|～|\-"#)?,
+             number_check_by_range!(0.0, 999.0),
+             |start, _, end| Ok(helpers::quantity_between(start.value(), end.value())?.latent(true)));
+    b.rule_3("<number> - <temperature>",
+             number_check!(),
+             b.reg(r#"到|至|~|～|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<temperature> - <temperature>",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"到|至|~|～|\-"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_3("<ordinal> - <ordinal>",
+             ordinal_check!(),
+             b.reg(r#"到|至|~|～|\-"#)?,
+             ordinal_check!(),
+             |start, _, end| helpers::quantity_between(start.value(), end.value()));
+    b.rule_2("more than <number>",
+             b.reg(r#"超过|大于|多于|高于"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <number>",
+             b.reg(r#"至少"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("more than <temperature>",
+             b.reg(r#"超过|大于|多于|高于"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_above(a.value()));
+    b.rule_2("at least <temperature>",
+             b.reg(r#"至少"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_at_least(a.value()));
+    b.rule_2("less than <number>",
+             b.reg(r#"少于|小于|低于|不到"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <number>",
+             b.reg(r#"至多"#)?,
+             number_check!(),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("less than <temperature>",
+             b.reg(r#"少于|小于|低于|不到"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_below(a.value()));
+    b.rule_2("at most <temperature>",
+             b.reg(r#"至多"#)?,
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             |_, a| helpers::quantity_at_most(a.value()));
+    b.rule_2("<number> or more",
+             number_check!(),
+             b.reg(r#"以上"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<temperature> or more",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"以上"#)?,
+             |a, _| helpers::quantity_at_least(a.value()));
+    b.rule_2("<number> or less",
+             number_check!(),
+             b.reg(r#"以下|以内"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    b.rule_2("<temperature> or less",
+             temperature_check!(|temp: &TemperatureValue| !temp.latent),
+             b.reg(r#"以下|以内"#)?,
+             |a, _| helpers::quantity_at_most(a.value()));
+    Ok(())
+}
+
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    rustling_ontology_values::rules::rules_recurrence(b, &rustling_ontology_values::rules::RecurrenceWords {
+        every: r#"每"#,
+        every_other: None,
+        every_integer: None,
+        hourly: r#"每小时|每小時"#,
+        daily: r#"每天|每日|天天"#,
+        weekly: r#"每周|每週|每星期|每礼拜|每禮拜"#,
+        monthly: r#"每个月|每個月|每月"#,
+        quarterly: None,
+        yearly: r#"每年"#,
+        and: None,
+        until: None,
+        times: None,
+    })
+}
+
+pub fn rules_age(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_2("<number>岁",
+             number_check!(),
+             b.reg(r#"周岁|週歲|岁|歲"#)?,
+             |number, _| helpers::age(number.value(), Grain::Year)
+    );
+    Ok(())
+}
@@ -13,7 +13,7 @@ pub fn examples_durations(v: &mut Vec<::rustling::train::Example<Dimension>>) {
 
 
 pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
-    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Local.ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
     example!(v, check_moment!(c, [2013, 2, 10]), "前天", "前日", "上周日", "上星期天", "上礼拜天", "上週日", "上星期天", "上禮拜天", "上禮拜日");
     example!(v, check_moment!(c, [2013, 2, 10]), "周日, 二月十号", "星期天, 二月十号", "礼拜天, 二月十号", "週日, 二月十號", "星期天, 二月十號", "禮拜天, 二月十號", "禮拜日, 二月十號");
     example!(v, check_moment!(c, [2013, 2, 13]),"星期三", "周三", "礼拜三", "禮拜三", "週三", "明天", "明日", "聽日");
@@ -39,6 +39,13 @@ pub fn examples_datetime(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_moment!(c, [2013, 2, 11]), "昨天", "昨日", "尋日");
     example!(v, check_moment_span!(c, [2013, 2, 12, 4], [2013, 2, 12, 12]), "早上", "早晨", "朝頭早", "朝早");
     example!(v, check_moment!(c, [2013, 12, 25]), "圣诞", "圣诞节",  "圣诞節", "聖誕", "聖誕节", "聖誕節");
+    example!(v, check_moment!(c, [2014, 1, 31]), "春节", "春節", "农历新年", "農曆新年", "大年初一");
+    example!(v, check_moment!(c, [2014, 1, 30]), "除夕", "大年三十");
+    example!(v, check_moment!(c, [2013, 2, 24]), "元宵节", "元宵節");
+    example!(v, check_moment!(c, [2013, 6, 12]), "端午节", "端午節");
+    example!(v, check_moment!(c, [2013, 8, 13]), "七夕");
+    example!(v, check_moment!(c, [2013, 9, 19]), "中秋节", "中秋節");
+    example!(v, check_moment!(c, [2013, 10, 13]), "重阳节", "重陽節");
     example!(v, check_moment_span!(c, [2013, 2, 11, 18], [2013, 2, 12, 0]), "昨晚", "昨天晚上", "尋晚");
     example!(v, check_moment_span!(c, [2013, 2, 12, 18], [2013, 2, 13, 0]), "今晚", "今天晚上");
     example!(v, check_moment!(c, [2013, 8, 1]), "建军节", "建軍節");
@@ -149,3 +156,32 @@ pub fn examples_numbers(v: &mut Vec<::rustling::train::Example<Dimension>>) {
     example!(v, check_ordinal(11), "第十一");
     example!(v, check_ordinal(91), "第九十一");
 }
+
+pub fn examples_quantity_range(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_quantity_range(QuantityKind::Number, Some(25.0), None), "超过25", "25以上");
+    example!(v, check_quantity_range(QuantityKind::Number, None, Some(3.0)), "少于3");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(5.0), Some(10.0)), "5到10之间", "5和10之间");
+    example!(v, check_quantity_range(QuantityKind::Number, Some(3.0), Some(4.0)), "3-4", "3~4");
+    example!(v, check_quantity_range(QuantityKind::Temperature { unit: Some("degree") }, Some(25.0), None), "25度以上");
+}
+
+pub fn examples_contact(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_phone_number("+33612345678"), "+33 6 12 34 56 78", "0033 6 12 34 56 78", "+33612345678");
+    example!(v, check_phone_number("01012345678"), "010-1234-5678", "010 1234 5678");
+    example!(v, check_email("john.doe@gmail.com"), "john.doe@gmail.com");
+    example!(v, check_url("https://www.example.com/path"), "https://www.example.com/path");
+    example!(v, check_url("www.example.com"), "www.example.com");
+    example!(v, check_url("example.com"), "example.com");
+}
+
+pub fn examples_recurrence(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    let c = ResolverContext::for_reference(Interval::starting_at(Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)), Grain::Second));
+    example!(v, check_recurrence(c.clone(), Grain::Week, 1, vec![Weekday::Mon]), "每周一", "每星期一");
+    example!(v, check_recurrence(c.clone(), Grain::Day, 1, vec![]), "每天", "每日");
+    example!(v, check_recurrence(c.clone(), Grain::Month, 1, vec![]), "每个月", "每月");
+    example!(v, check_recurrence(c.clone(), Grain::Year, 1, vec![]), "每年");
+}
+
+pub fn examples_age(v: &mut Vec<::rustling::train::Example<Dimension>>) {
+    example!(v, check_age(7.0, Grain::Year), "7岁", "7周岁", "7歲");
+}
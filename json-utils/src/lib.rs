@@ -3,8 +3,12 @@ extern crate rustling_ontology_moment as moment;
 extern crate serde;
 extern crate serde_json;
 
-use moment::{Local, Moment};
-use rustling_ontology::{dimension, output::DatetimeIntervalKind, Output};
+use moment::{Moment, Tz};
+use rustling_ontology::{
+    dimension,
+    output::{DatetimeIntervalKind, QuantityIntervalKind},
+    Output,
+};
 use serde::{Serialize, Deserialize};
 use std::f64;
 
@@ -13,7 +17,7 @@ use std::f64;
 pub struct Utterance {
     pub phrase: String,
     #[serde(with = "moment_json")]
-    pub context: Moment<Local>,
+    pub context: Moment<Tz>,
     #[serde(rename = "in_grammar")]
     pub in_grammar: Option<bool>,
     pub skip_rustling: Option<bool>,
@@ -87,7 +91,7 @@ pub struct TestOutput {
     pub in_grammar: Option<bool>,
     pub skip_rustling: Option<bool>,
     #[serde(with = "moment_json")]
-    pub context: Moment<Local>,
+    pub context: Moment<Tz>,
     pub translation: Option<String>,
     pub output: TestAssertion<Vec<SlotValue>, Vec<SlotValue>>,
 }
@@ -103,6 +107,19 @@ pub enum SlotValue {
     AmountOfMoney(AmountOfMoneyValue),
     Temperature(TemperatureValue),
     Duration(DurationValue),
+    Distance(DistanceValue),
+    Volume(VolumeValue),
+    Mass(MassValue),
+    Rate(RateValue),
+    NumberInterval(NumberIntervalValue),
+    OrdinalInterval(OrdinalIntervalValue),
+    AmountOfMoneyInterval(AmountOfMoneyIntervalValue),
+    TemperatureInterval(TemperatureIntervalValue),
+    PhoneNumber(PhoneNumberValue),
+    Email(EmailValue),
+    Url(UrlValue),
+    Recurrence(RecurrenceValue),
+    Age(AgeValue),
 }
 
 impl From<Output> for SlotValue {
@@ -114,6 +131,9 @@ impl From<Output> for SlotValue {
             Output::Float(float) => SlotValue::Number(NumberValue {
                 value: float.0.into(),
             }),
+            Output::Fraction(fraction) => SlotValue::Number(NumberValue {
+                value: fraction.value,
+            }),
             Output::Ordinal(ordinal) => SlotValue::Ordinal(OrdinalValue {
                 value: ordinal.0 as i64,
             }),
@@ -165,10 +185,90 @@ impl From<Output> for SlotValue {
                 seconds: *duration.period.0.get(Grain::Second as usize).unwrap_or(&0),
                 precision: duration.precision.into(),
             }),
+            Output::Distance(distance) => SlotValue::Distance(DistanceValue {
+                value: distance.value,
+                precision: distance.precision.into(),
+                unit: distance.unit.map(|it| it.to_string()),
+            }),
+            Output::Volume(volume) => SlotValue::Volume(VolumeValue {
+                value: volume.value,
+                precision: volume.precision.into(),
+                unit: volume.unit.map(|it| it.to_string()),
+            }),
+            Output::Mass(mass) => SlotValue::Mass(MassValue {
+                value: mass.value,
+                precision: mass.precision.into(),
+                unit: mass.unit.map(|it| it.to_string()),
+            }),
+            Output::Rate(rate) => SlotValue::Rate(RateValue {
+                value: rate.value,
+                precision: rate.precision.into(),
+                numerator_unit: rate.numerator_unit.map(|it| it.to_string()),
+                denominator_unit: rate.denominator_unit.to_string(),
+            }),
+            Output::NumberInterval(interval) => {
+                let (from, to) = interval_bounds(interval.interval_kind);
+                SlotValue::NumberInterval(NumberIntervalValue { from, to })
+            }
+            Output::OrdinalInterval(interval) => {
+                let (from, to) = interval_bounds(interval.interval_kind);
+                SlotValue::OrdinalInterval(OrdinalIntervalValue { from, to })
+            }
+            Output::AmountOfMoneyInterval(interval) => {
+                let (from, to) = interval_bounds(interval.interval_kind);
+                SlotValue::AmountOfMoneyInterval(AmountOfMoneyIntervalValue {
+                    from,
+                    to,
+                    precision: interval.precision.into(),
+                    unit: interval.unit.map(|it| it.to_string()),
+                })
+            }
+            Output::TemperatureInterval(interval) => {
+                let (from, to) = interval_bounds(interval.interval_kind);
+                SlotValue::TemperatureInterval(TemperatureIntervalValue {
+                    from,
+                    to,
+                    unit: interval.unit.map(|it| it.to_string()),
+                })
+            }
+            Output::PhoneNumber(phone_number) => SlotValue::PhoneNumber(PhoneNumberValue {
+                value: phone_number.value,
+            }),
+            Output::Email(email) => SlotValue::Email(EmailValue { value: email.value }),
+            Output::Url(url) => SlotValue::Url(UrlValue { value: url.value }),
+            Output::Recurrence(recurrence) => SlotValue::Recurrence(RecurrenceValue {
+                frequency: recurrence.frequency.into(),
+                interval: recurrence.interval,
+                by_day: recurrence
+                    .by_day
+                    .iter()
+                    .map(|it| format!("{:?}", it)[..2].to_uppercase())
+                    .collect(),
+                by_month_day: recurrence.by_month_day,
+                by_month: recurrence.by_month,
+                by_hour: recurrence.by_hour,
+                by_minute: recurrence.by_minute,
+                by_second: recurrence.by_second,
+                start: recurrence.start,
+                until: recurrence.until,
+                count: recurrence.count,
+            }),
+            Output::Age(age) => SlotValue::Age(AgeValue {
+                value: age.value,
+                unit: age.unit.into(),
+            }),
         }
     }
 }
 
+fn interval_bounds<T>(interval_kind: QuantityIntervalKind<T>) -> (Option<T>, Option<T>) {
+    match interval_kind {
+        QuantityIntervalKind::Above { start, .. } => (Some(start), None),
+        QuantityIntervalKind::Below { end, .. } => (None, Some(end)),
+        QuantityIntervalKind::Between { start, end } => (Some(start), Some(end)),
+    }
+}
+
 fn nearly_equal_f64(a: f64, b: f64) -> bool {
     let abs_a = a.abs();
     let abs_b = b.abs();
@@ -216,7 +316,7 @@ impl PartialEq for PercentageValue {
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct InstantTimeValue {
     #[serde(with = "moment_json")]
-    pub value: Moment<Local>,
+    pub value: Moment<Tz>,
     pub grain: Grain,
     pub precision: Precision,
 }
@@ -224,9 +324,9 @@ pub struct InstantTimeValue {
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct TimeIntervalValue {
     #[serde(with = "optional_moment_json")]
-    pub from: Option<Moment<Local>>,
+    pub from: Option<Moment<Tz>>,
     #[serde(with = "optional_moment_json")]
-    pub to: Option<Moment<Local>>,
+    pub to: Option<Moment<Tz>>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
@@ -242,6 +342,100 @@ pub struct TemperatureValue {
     pub unit: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct DistanceValue {
+    pub value: f64,
+    pub precision: Precision,
+    pub unit: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct VolumeValue {
+    pub value: f64,
+    pub precision: Precision,
+    pub unit: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct MassValue {
+    pub value: f64,
+    pub precision: Precision,
+    pub unit: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RateValue {
+    pub value: f64,
+    pub precision: Precision,
+    pub numerator_unit: Option<String>,
+    pub denominator_unit: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct NumberIntervalValue {
+    pub from: Option<f64>,
+    pub to: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct OrdinalIntervalValue {
+    pub from: Option<i64>,
+    pub to: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct AmountOfMoneyIntervalValue {
+    pub from: Option<f64>,
+    pub to: Option<f64>,
+    pub precision: Precision,
+    pub unit: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct TemperatureIntervalValue {
+    pub from: Option<f64>,
+    pub to: Option<f64>,
+    pub unit: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PhoneNumberValue {
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct EmailValue {
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct UrlValue {
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct AgeValue {
+    pub value: f64,
+    pub unit: Grain,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct RecurrenceValue {
+    pub frequency: Grain,
+    pub interval: i64,
+    pub by_day: Vec<String>,
+    pub by_month_day: Option<u32>,
+    pub by_month: Option<u32>,
+    pub by_hour: Option<u32>,
+    pub by_minute: Option<u32>,
+    pub by_second: Option<u32>,
+    #[serde(with = "moment_json")]
+    pub start: Moment<Tz>,
+    #[serde(with = "optional_moment_json")]
+    pub until: Option<Moment<Tz>>,
+    pub count: Option<i64>,
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct DurationValue {
     pub years: i64,
@@ -298,11 +492,11 @@ impl From<dimension::Precision> for Precision {
 }
 
 mod moment_json {
-    use moment::{Local, Moment, TimeZone};
+    use moment::{Moment, TimeZone, Tz};
     use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
     pub fn serialize<S: Serializer>(
-        moment: &Moment<Local>,
+        moment: &Moment<Tz>,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
         moment
@@ -314,9 +508,9 @@ mod moment_json {
 
     pub fn deserialize<'de, D: Deserializer<'de>>(
         deserializer: D,
-    ) -> Result<Moment<Local>, D::Error> {
+    ) -> Result<Moment<Tz>, D::Error> {
         let time: String = Deserialize::deserialize(deserializer)?;
-        let datetime = Local
+        let datetime = Tz::local()
             .datetime_from_str(time.as_ref(), "%Y-%m-%d %T")
             .map_err(D::Error::custom)?;
         Ok(Moment(datetime))
@@ -325,11 +519,11 @@ mod moment_json {
 
 mod optional_moment_json {
     use super::*;
-    use moment::{Local, Moment, TimeZone};
+    use moment::{Moment, TimeZone, Tz};
     use serde::{de::Error, Deserialize, Deserializer, Serializer};
 
     pub fn serialize<S: Serializer>(
-        moment: &Option<Moment<Local>>,
+        moment: &Option<Moment<Tz>>,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
         match moment {
@@ -340,10 +534,10 @@ mod optional_moment_json {
 
     pub fn deserialize<'de, D: Deserializer<'de>>(
         deserializer: D,
-    ) -> Result<Option<Moment<Local>>, D::Error> {
+    ) -> Result<Option<Moment<Tz>>, D::Error> {
         let time: Option<String> = Deserialize::deserialize(deserializer)?;
         if let Some(time) = time {
-            let datetime = Local
+            let datetime = Tz::local()
                 .datetime_from_str(time.as_ref(), "%Y-%m-%d %T")
                 .map_err(D::Error::custom)?;
             Ok(Some(Moment(datetime)))
@@ -0,0 +1,519 @@
+use std::f64::consts::PI;
+
+use chrono::{Datelike, NaiveDate};
+
+/// Non-gregorian calendars in which holidays can be defined with the `CalendarDate` constraint.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Calendar {
+    /// Chinese lunisolar calendar, computed for China Standard Time (UTC+8).
+    Chinese,
+    /// Korean lunisolar calendar, computed for Korea Standard Time (UTC+9). It is the chinese
+    /// one, apart from the new moons falling between 15:00 and 16:00 UTC.
+    Korean,
+    /// Arithmetical islamic calendar. Observed dates, which depend on the sighting of the moon,
+    /// may differ by a day or two.
+    Islamic,
+    /// Hebrew calendar, with months numbered from Nisan (1) to Adar (12), Adar II being 13 in
+    /// leap years. Years start on Tishrei (7).
+    Hebrew,
+}
+
+impl Calendar {
+    /// The gregorian date of the given date of this calendar, if it exists. The months of the
+    /// lunisolar calendars are the regular ones, leap months are never returned.
+    pub fn to_gregorian(&self, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+        let fixed = match *self {
+            Calendar::Chinese => lunisolar::fixed_from_date(year, month, day, 8.0),
+            Calendar::Korean => lunisolar::fixed_from_date(year, month, day, 9.0),
+            Calendar::Islamic => islamic::fixed_from_date(year, month, day),
+            Calendar::Hebrew => hebrew::fixed_from_date(year, month, day),
+        }?;
+        NaiveDate::from_num_days_from_ce_opt(fixed as i32)
+    }
+
+    /// The year of this calendar the given gregorian date belongs to.
+    pub fn year_of(&self, date: NaiveDate) -> i32 {
+        let fixed = date.num_days_from_ce() as i64;
+        match *self {
+            Calendar::Chinese => lunisolar::year_from_fixed(fixed, date.year(), 8.0),
+            Calendar::Korean => lunisolar::year_from_fixed(fixed, date.year(), 9.0),
+            Calendar::Islamic => islamic::year_from_fixed(fixed),
+            Calendar::Hebrew => hebrew::year_from_fixed(fixed, date.year()),
+        }
+    }
+
+    /// The gregorian dates of the given year on which falls the given month and day of this
+    /// calendar. There may be none or two of them for the islamic calendar.
+    pub fn dates_in_gregorian_year(&self, month: u32, day: u32, year: i32) -> Vec<NaiveDate> {
+        let first = match NaiveDate::from_ymd_opt(year, 1, 1) {
+            Some(date) => self.year_of(date),
+            None => return vec![],
+        };
+        let last = match NaiveDate::from_ymd_opt(year, 12, 31) {
+            Some(date) => self.year_of(date),
+            None => return vec![],
+        };
+        (first..last + 1)
+            .filter_map(|y| self.to_gregorian(y, month, day))
+            .filter(|date| date.year() == year)
+            .collect()
+    }
+}
+
+/// Eras of the japanese calendar since the Meiji restoration.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum JapaneseEra {
+    Meiji,
+    Taisho,
+    Showa,
+    Heisei,
+    Reiwa,
+}
+
+impl JapaneseEra {
+    /// The gregorian year in which the era started, its first year.
+    pub fn first_year(&self) -> i32 {
+        match *self {
+            JapaneseEra::Meiji => 1868,
+            JapaneseEra::Taisho => 1912,
+            JapaneseEra::Showa => 1926,
+            JapaneseEra::Heisei => 1989,
+            JapaneseEra::Reiwa => 2019,
+        }
+    }
+
+    /// The number of years of the era, none for the current one.
+    pub fn length(&self) -> Option<i32> {
+        match *self {
+            JapaneseEra::Meiji => Some(45),
+            JapaneseEra::Taisho => Some(15),
+            JapaneseEra::Showa => Some(64),
+            JapaneseEra::Heisei => Some(31),
+            JapaneseEra::Reiwa => None,
+        }
+    }
+
+    /// The gregorian year of the given year of the era, e.g. 2013 for Heisei 25.
+    pub fn to_gregorian_year(&self, year: i32) -> Option<i32> {
+        if year < 1 || self.length().map(|length| year > length).unwrap_or(false) {
+            None
+        } else {
+            Some(self.first_year() + year - 1)
+        }
+    }
+}
+
+// Dates are handled as fixed day numbers, the 1st of january of the year 1 being 1, which is the
+// chrono `num_days_from_ce`.
+
+mod islamic {
+    /// 16 july 622 of the julian calendar.
+    const EPOCH: i64 = 227_015;
+
+    fn is_leap_year(year: i64) -> bool {
+        (14 + 11 * year).rem_euclid(30) < 11
+    }
+
+    fn last_day_of_month(year: i64, month: i64) -> i64 {
+        if month % 2 == 1 || (month == 12 && is_leap_year(year)) {
+            30
+        } else {
+            29
+        }
+    }
+
+    pub fn fixed_from_date(year: i32, month: u32, day: u32) -> Option<i64> {
+        let (year, month, day) = (year as i64, month as i64, day as i64);
+        if month < 1 || month > 12 || day < 1 || day > last_day_of_month(year, month) {
+            return None;
+        }
+        Some(
+            EPOCH - 1
+                + 354 * (year - 1)
+                + (3 + 11 * year).div_euclid(30)
+                + 29 * (month - 1)
+                + (6 * month - 1).div_euclid(11)
+                + day,
+        )
+    }
+
+    pub fn year_from_fixed(fixed: i64) -> i32 {
+        (30 * (fixed - EPOCH) + 10646).div_euclid(10631) as i32
+    }
+}
+
+mod hebrew {
+    /// 7 october -3761 of the julian calendar.
+    const EPOCH: i64 = -1_373_427;
+
+    fn is_leap_year(year: i64) -> bool {
+        (7 * year + 1).rem_euclid(19) < 7
+    }
+
+    fn last_month_of_year(year: i64) -> i64 {
+        if is_leap_year(year) {
+            13
+        } else {
+            12
+        }
+    }
+
+    /// Days from the epoch to the molad of Tishrei of the year, postponed when it falls on a
+    /// sunday, a wednesday or a friday.
+    fn calendar_elapsed_days(year: i64) -> i64 {
+        let months_elapsed = (235 * year - 234).div_euclid(19);
+        let parts_elapsed = 12084 + 13753 * months_elapsed;
+        let days = 29 * months_elapsed + parts_elapsed.div_euclid(25920);
+        if (3 * (days + 1)).rem_euclid(7) < 3 {
+            days + 1
+        } else {
+            days
+        }
+    }
+
+    /// Postponement keeping the length of the years between 353 and 356 or 383 and 386 days.
+    fn year_length_correction(year: i64) -> i64 {
+        let ny0 = calendar_elapsed_days(year - 1);
+        let ny1 = calendar_elapsed_days(year);
+        let ny2 = calendar_elapsed_days(year + 1);
+        if ny2 - ny1 == 356 {
+            2
+        } else if ny1 - ny0 == 382 {
+            1
+        } else {
+            0
+        }
+    }
+
+    pub fn new_year(year: i64) -> i64 {
+        EPOCH + calendar_elapsed_days(year) + year_length_correction(year)
+    }
+
+    fn days_in_year(year: i64) -> i64 {
+        new_year(year + 1) - new_year(year)
+    }
+
+    fn last_day_of_month(year: i64, month: i64) -> i64 {
+        match month {
+            2 | 4 | 6 | 10 | 13 => 29,
+            12 if !is_leap_year(year) => 29,
+            8 if days_in_year(year) % 10 != 5 => 29,
+            9 if days_in_year(year) % 10 == 3 => 29,
+            _ => 30,
+        }
+    }
+
+    pub fn fixed_from_date(year: i32, month: u32, day: u32) -> Option<i64> {
+        let (year, month, day) = (year as i64, month as i64, day as i64);
+        if month < 1 || month > last_month_of_year(year) || day < 1
+            || day > last_day_of_month(year, month)
+        {
+            return None;
+        }
+        let days_before_month: i64 = if month < 7 {
+            (7..last_month_of_year(year) + 1)
+                .chain(1..month)
+                .map(|m| last_day_of_month(year, m))
+                .sum()
+        } else {
+            (7..month).map(|m| last_day_of_month(year, m)).sum()
+        };
+        Some(new_year(year) + days_before_month + day - 1)
+    }
+
+    pub fn year_from_fixed(fixed: i64, gregorian_year: i32) -> i32 {
+        let year = gregorian_year as i64 + 3761;
+        if fixed >= new_year(year) {
+            year as i32
+        } else {
+            year as i32 - 1
+        }
+    }
+}
+
+mod lunisolar {
+    use super::{Datelike, NaiveDate, PI};
+
+    const MEAN_SYNODIC_MONTH: f64 = 29.530_588_861;
+    /// Julian day at the midnight starting the fixed day 0.
+    const JD_OFFSET: f64 = 1_721_424.5;
+
+    fn sin_deg(angle: f64) -> f64 {
+        (angle * PI / 180.0).sin()
+    }
+
+    /// Difference between the terrestrial and the universal time in seconds, after Espenak and
+    /// Meeus.
+    fn delta_t(year: f64) -> f64 {
+        if year >= 2005.0 {
+            let t = year - 2000.0;
+            62.92 + 0.32217 * t + 0.005589 * t * t
+        } else if year >= 1961.0 {
+            let t = year - 1975.0;
+            45.45 + 1.067 * t - t * t / 260.0 - t * t * t / 718.0
+        } else {
+            let t = year - 1950.0;
+            29.07 + 0.407 * t - t * t / 233.0 + t * t * t / 2547.0
+        }
+    }
+
+    fn universal_from_dynamical(jde: f64) -> f64 {
+        jde - delta_t(2000.0 + (jde - 2_451_545.0) / 365.25) / 86400.0
+    }
+
+    fn dynamical_from_universal(jd: f64) -> f64 {
+        jd + delta_t(2000.0 + (jd - 2_451_545.0) / 365.25) / 86400.0
+    }
+
+    /// Julian ephemeris day of the k-th new moon after the one of 6 january 2000 (Meeus, chapter
+    /// 49).
+    fn new_moon(k: i64) -> f64 {
+        let k = k as f64;
+        let t = k / 1236.85;
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let t4 = t3 * t;
+        let jde = 2_451_550.097_66 + MEAN_SYNODIC_MONTH * k + 0.000_154_37 * t2
+            - 0.000_000_150 * t3
+            + 0.000_000_000_73 * t4;
+        let e = 1.0 - 0.002_516 * t - 0.000_007_4 * t2;
+        let m = 2.5534 + 29.105_356_70 * k - 0.000_001_4 * t2 - 0.000_000_11 * t3;
+        let mp = 201.5643 + 385.816_935_28 * k + 0.010_758_2 * t2 + 0.000_012_38 * t3
+            - 0.000_000_058 * t4;
+        let f = 160.7108 + 390.670_502_84 * k - 0.001_611_8 * t2 - 0.000_002_27 * t3
+            + 0.000_000_011 * t4;
+        let omega = 124.7746 - 1.563_755_88 * k + 0.002_067_2 * t2 + 0.000_002_15 * t3;
+        let correction = -0.40720 * sin_deg(mp)
+            + 0.17241 * e * sin_deg(m)
+            + 0.01608 * sin_deg(2.0 * mp)
+            + 0.01039 * sin_deg(2.0 * f)
+            + 0.00739 * e * sin_deg(mp - m)
+            - 0.00514 * e * sin_deg(mp + m)
+            + 0.00208 * e * e * sin_deg(2.0 * m)
+            - 0.00111 * sin_deg(mp - 2.0 * f)
+            - 0.00057 * sin_deg(mp + 2.0 * f)
+            + 0.00056 * e * sin_deg(2.0 * mp + m)
+            - 0.00042 * sin_deg(3.0 * mp)
+            + 0.00042 * e * sin_deg(m + 2.0 * f)
+            + 0.00038 * e * sin_deg(m - 2.0 * f)
+            - 0.00024 * e * sin_deg(2.0 * mp - m)
+            - 0.00017 * sin_deg(omega)
+            - 0.00007 * sin_deg(mp + 2.0 * m)
+            + 0.00004 * sin_deg(2.0 * mp - 2.0 * f)
+            + 0.00004 * sin_deg(3.0 * m)
+            + 0.00003 * sin_deg(mp + m - 2.0 * f)
+            + 0.00003 * sin_deg(2.0 * mp + 2.0 * f)
+            - 0.00003 * sin_deg(mp + m + 2.0 * f)
+            + 0.00003 * sin_deg(mp - m + 2.0 * f)
+            - 0.00002 * sin_deg(mp - m - 2.0 * f)
+            - 0.00002 * sin_deg(3.0 * mp + m)
+            + 0.00002 * sin_deg(4.0 * mp);
+        let planetary = [
+            (0.000_325, 299.77 + 0.107_408 * k - 0.009_173 * t2),
+            (0.000_165, 251.88 + 0.016_321 * k),
+            (0.000_164, 251.83 + 26.651_886 * k),
+            (0.000_126, 349.42 + 36.412_478 * k),
+            (0.000_110, 84.66 + 18.206_239 * k),
+            (0.000_062, 141.74 + 53.303_771 * k),
+            (0.000_060, 207.14 + 2.453_732 * k),
+            (0.000_056, 154.84 + 7.306_860 * k),
+            (0.000_047, 34.52 + 27.261_239 * k),
+            (0.000_042, 207.19 + 0.121_824 * k),
+            (0.000_040, 291.34 + 1.844_379 * k),
+            (0.000_037, 161.72 + 24.198_154 * k),
+            (0.000_035, 239.56 + 25.513_099 * k),
+            (0.000_023, 331.55 + 3.592_518 * k),
+        ]
+        .iter()
+        .map(|&(coefficient, angle)| coefficient * sin_deg(angle))
+        .sum::<f64>();
+        jde + correction + planetary
+    }
+
+    /// Apparent longitude of the sun in degrees at the given julian ephemeris day (Meeus,
+    /// chapter 25).
+    fn solar_longitude(jde: f64) -> f64 {
+        let t = (jde - 2_451_545.0) / 36525.0;
+        let l0 = 280.46646 + 36000.76983 * t + 0.000_303_2 * t * t;
+        let m = 357.52911 + 35999.05029 * t - 0.000_153_7 * t * t;
+        let c = (1.914_602 - 0.004_817 * t - 0.000_014 * t * t) * sin_deg(m)
+            + (0.019_993 - 0.000_101 * t) * sin_deg(2.0 * m)
+            + 0.000_289 * sin_deg(3.0 * m);
+        let omega = 125.04 - 1934.136 * t;
+        (l0 + c - 0.00569 - 0.00478 * sin_deg(omega)).rem_euclid(360.0)
+    }
+
+    /// Fixed day on which the given julian (universal) day falls at the given offset from UTC.
+    fn fixed_from_jd(jd: f64, offset: f64) -> i64 {
+        (jd - JD_OFFSET + offset / 24.0).floor() as i64
+    }
+
+    /// Solar longitude at midnight starting the given fixed day at the given offset from UTC.
+    fn solar_longitude_at(fixed: i64, offset: f64) -> f64 {
+        solar_longitude(dynamical_from_universal(fixed as f64 + JD_OFFSET - offset / 24.0))
+    }
+
+    fn new_moon_day(k: i64, offset: f64) -> i64 {
+        fixed_from_jd(universal_from_dynamical(new_moon(k)), offset)
+    }
+
+    /// Index of the new moon starting the month the given fixed day belongs to.
+    fn new_moon_on_or_before(fixed: i64, offset: f64) -> i64 {
+        let jd = fixed as f64 + JD_OFFSET;
+        let mut k = ((jd - 2_451_550.097_66) / MEAN_SYNODIC_MONTH).floor() as i64;
+        while new_moon_day(k + 1, offset) <= fixed {
+            k += 1;
+        }
+        while new_moon_day(k, offset) > fixed {
+            k -= 1;
+        }
+        k
+    }
+
+    /// Fixed day of the december solstice of the given gregorian year.
+    fn winter_solstice(year: i32, offset: f64) -> i64 {
+        let mut jde = match NaiveDate::from_ymd_opt(year, 12, 21) {
+            Some(date) => date.num_days_from_ce() as f64 + JD_OFFSET + 0.5,
+            None => return 0,
+        };
+        for _ in 0..5 {
+            jde += 58.0 * sin_deg(270.0 - solar_longitude(jde));
+        }
+        fixed_from_jd(universal_from_dynamical(jde), offset)
+    }
+
+    /// The months from the one containing the winter solstice of the previous year to the one
+    /// containing the solstice of the given year excluded, as their number, whether they are a
+    /// leap month and the fixed days they start and end on.
+    fn months_of_solar_year(year: i32, offset: f64) -> Vec<(u32, bool, i64, i64)> {
+        let first = new_moon_on_or_before(winter_solstice(year - 1, offset), offset);
+        let last = new_moon_on_or_before(winter_solstice(year, offset), offset);
+        // A leap year has 13 months, the first one without a major solar term is the leap month
+        let mut leap_pending = last - first == 13;
+        let mut months = Vec::with_capacity((last - first) as usize);
+        let mut number = 10;
+        for k in first..last {
+            let start = new_moon_day(k, offset);
+            let end = new_moon_day(k + 1, offset);
+            let has_major_term = (solar_longitude_at(start, offset) / 30.0).floor()
+                != (solar_longitude_at(end, offset) / 30.0).floor();
+            if leap_pending && k != first && !has_major_term {
+                leap_pending = false;
+                months.push((number, true, start, end));
+            } else {
+                number = number % 12 + 1;
+                months.push((number, false, start, end));
+            }
+        }
+        months
+    }
+
+    pub fn fixed_from_date(year: i32, month: u32, day: u32, offset: f64) -> Option<i64> {
+        if month < 1 || month > 12 || day < 1 || day > 30 {
+            return None;
+        }
+        // Months 11 and 12 of a year end in the solar year following the next solstice
+        let solar_year = if month >= 11 { year + 1 } else { year };
+        months_of_solar_year(solar_year, offset)
+            .into_iter()
+            .find(|&(number, leap, _, _)| number == month && !leap)
+            .and_then(|(_, _, start, end)| {
+                if (day as i64) <= end - start {
+                    Some(start + day as i64 - 1)
+                } else {
+                    None
+                }
+            })
+    }
+
+    pub fn year_from_fixed(fixed: i64, gregorian_year: i32, offset: f64) -> i32 {
+        match fixed_from_date(gregorian_year, 1, 1, offset) {
+            Some(new_year) if fixed < new_year => gregorian_year - 1,
+            _ => gregorian_year,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chinese_new_year() {
+        let new_years = [
+            (2013, 2, 10),
+            (2014, 1, 31),
+            (2015, 2, 19),
+            (2016, 2, 8),
+            (2017, 1, 28),
+            (2018, 2, 16),
+            (2019, 2, 5),
+            (2020, 1, 25),
+            (2021, 2, 12),
+            (2022, 2, 1),
+            (2023, 1, 22),
+            (2024, 2, 10),
+            (2025, 1, 29),
+            (2033, 1, 31),
+        ];
+        for &(y, m, d) in new_years.iter() {
+            assert_eq!(Some(NaiveDate::from_ymd(y, m, d)), Calendar::Chinese.to_gregorian(y, 1, 1));
+        }
+    }
+
+    #[test]
+    fn test_chinese_mid_autumn() {
+        let mid_autumns = [
+            (2013, 9, 19),
+            (2014, 9, 8),
+            (2017, 10, 4),
+            (2020, 10, 1),
+            (2023, 9, 29),
+        ];
+        for &(y, m, d) in mid_autumns.iter() {
+            assert_eq!(Some(NaiveDate::from_ymd(y, m, d)), Calendar::Chinese.to_gregorian(y, 8, 15));
+        }
+        assert_eq!(Some(NaiveDate::from_ymd(2013, 9, 19)), Calendar::Korean.to_gregorian(2013, 8, 15));
+    }
+
+    #[test]
+    fn test_lunisolar_year_of() {
+        assert_eq!(2012, Calendar::Chinese.year_of(NaiveDate::from_ymd(2013, 2, 9)));
+        assert_eq!(2013, Calendar::Chinese.year_of(NaiveDate::from_ymd(2013, 2, 10)));
+        // The new moon of 7 february 1997 fell at 15:06 UTC, past midnight in Seoul
+        assert_eq!(Some(NaiveDate::from_ymd(1997, 2, 7)), Calendar::Chinese.to_gregorian(1997, 1, 1));
+        assert_eq!(Some(NaiveDate::from_ymd(1997, 2, 8)), Calendar::Korean.to_gregorian(1997, 1, 1));
+        assert_eq!(
+            vec![NaiveDate::from_ymd(2013, 2, 10)],
+            Calendar::Korean.dates_in_gregorian_year(1, 1, 2013)
+        );
+    }
+
+    #[test]
+    fn test_islamic_calendar() {
+        assert_eq!(Some(NaiveDate::from_ymd(2013, 7, 9)), Calendar::Islamic.to_gregorian(1434, 9, 1));
+        assert_eq!(1434, Calendar::Islamic.year_of(NaiveDate::from_ymd(2013, 7, 9)));
+        assert_eq!(None, Calendar::Islamic.to_gregorian(1434, 2, 30));
+        // Eid al-Fitr fell twice in 2000
+        assert_eq!(2, Calendar::Islamic.dates_in_gregorian_year(10, 1, 2000).len());
+    }
+
+    #[test]
+    fn test_hebrew_calendar() {
+        // Rosh Hashanah, Yom Kippur, Hanukkah and Passover
+        assert_eq!(Some(NaiveDate::from_ymd(2013, 9, 5)), Calendar::Hebrew.to_gregorian(5774, 7, 1));
+        assert_eq!(Some(NaiveDate::from_ymd(2013, 9, 14)), Calendar::Hebrew.to_gregorian(5774, 7, 10));
+        assert_eq!(Some(NaiveDate::from_ymd(2013, 11, 28)), Calendar::Hebrew.to_gregorian(5774, 9, 25));
+        assert_eq!(Some(NaiveDate::from_ymd(2014, 4, 15)), Calendar::Hebrew.to_gregorian(5774, 1, 15));
+        assert_eq!(5773, Calendar::Hebrew.year_of(NaiveDate::from_ymd(2013, 9, 4)));
+        assert_eq!(None, Calendar::Hebrew.to_gregorian(5773, 13, 1));
+    }
+
+    #[test]
+    fn test_japanese_era() {
+        assert_eq!(Some(2013), JapaneseEra::Heisei.to_gregorian_year(25));
+        assert_eq!(Some(2019), JapaneseEra::Reiwa.to_gregorian_year(1));
+        assert_eq!(None, JapaneseEra::Heisei.to_gregorian_year(32));
+        assert_eq!(None, JapaneseEra::Showa.to_gregorian_year(0));
+    }
+}
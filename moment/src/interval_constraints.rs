@@ -1,27 +1,44 @@
 use crate::bidirectional_walker::*;
 use crate::period::*;
 use crate::walker::*;
-use crate::{last_day_in_month, Interval, Moment, MomentError, MomentResult};
-use chrono::{Datelike, Local, TimeZone, Timelike, Weekday};
+use crate::timezone::from_local;
+use crate::{last_day_in_month, Calendar, DstPolicy, Interval, Moment, MomentError, MomentResult, Tz};
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Timelike, Weekday};
+use std::collections::HashSet;
 use std::fmt;
 use std::ops;
-use std::rc::Rc;
+use std::sync::Arc;
 
 #[derive(Clone, PartialEq)]
 pub struct Context<T: TimeZone> {
     pub reference: Interval<T>,
     pub min: Interval<T>,
     pub max: Interval<T>,
+    /// First day of the weeks walked by `Cycle(Grain::Week)`, `WeekOfYear` always walks ISO weeks.
+    pub week_start: Weekday,
+    /// First and last days of the weekends skipped by `BusinessDay` and `BusinessHours`.
+    pub weekend: (Weekday, Weekday),
+    /// Local dates skipped by `BusinessDay` and `BusinessHours` on top of the weekends.
+    pub holidays: Arc<HashSet<NaiveDate>>,
+    /// Opening and closing hours of the working days walked by `BusinessHours`.
+    pub business_hours: (u32, u32),
+    /// Month starting the fiscal years walked by `FiscalCycle`, `FiscalQuarter` and `FiscalYear`.
+    pub fiscal_year_start: u32,
 }
 
-impl<T: TimeZone> Copy for Context<T> where <T as TimeZone>::Offset: Copy {}
-
 impl<T: TimeZone> fmt::Debug for Context<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "Context {{ reference: {:?}, min: {:?}, max: {:?} }}",
-            self.reference, self.min, self.max
+            "Context {{ reference: {:?}, min: {:?}, max: {:?}, week_start: {:?}, weekend: {:?}, holidays: {:?}, business_hours: {:?}, fiscal_year_start: {:?} }}",
+            self.reference,
+            self.min,
+            self.max,
+            self.week_start,
+            self.weekend,
+            self.holidays,
+            self.business_hours,
+            self.fiscal_year_start
         )
     }
 }
@@ -38,6 +55,31 @@ impl Context<Local> {
     }
 }
 
+impl Default for Context<Tz> {
+    fn default() -> Context<Tz> {
+        Self::now_in(Tz::local())
+    }
+}
+
+impl Context<Tz> {
+    /// Returns a context referenced on the current moment in the given timezone.
+    pub fn now_in(tz: Tz) -> Context<Tz> {
+        Context::for_reference(Interval::starting_at(Moment::now_in(tz), Grain::Second))
+    }
+
+    /// Returns the same context with local times skipped or repeated by a daylight saving time
+    /// transition resolved according to the given policy.
+    pub fn with_dst_policy(self, dst_policy: DstPolicy) -> Context<Tz> {
+        let tz = self.reference.timezone().with_dst_policy(dst_policy);
+        Context {
+            reference: self.reference.with_timezone(&tz),
+            min: self.min.with_timezone(&tz),
+            max: self.max.with_timezone(&tz),
+            ..self
+        }
+    }
+}
+
 impl<T: TimeZone> Context<T>
 where
     <T as TimeZone>::Offset: Copy,
@@ -49,9 +91,88 @@ where
             reference,
             min,
             max,
+            week_start: Weekday::Mon,
+            weekend: (Weekday::Sat, Weekday::Sun),
+            holidays: Arc::new(HashSet::new()),
+            business_hours: (9, 17),
+            fiscal_year_start: 1,
         }
     }
 
+    /// Returns the same context with weeks starting on the given day, e.g. sunday for "next
+    /// week" in the US. Weeks start on monday by default.
+    pub fn with_week_start(self, week_start: Weekday) -> Context<T> {
+        Context { week_start, ..self }
+    }
+
+    /// Returns the same context with weekends running from the first to the last given day,
+    /// e.g. friday and saturday in many middle eastern countries. Weekends run from saturday to
+    /// sunday by default.
+    pub fn with_weekend(self, first: Weekday, last: Weekday) -> Context<T> {
+        Context {
+            weekend: (first, last),
+            ..self
+        }
+    }
+
+    /// Returns the same context with the given local dates off work, e.g. the public holidays of
+    /// a country.
+    pub fn with_holidays<I: IntoIterator<Item = NaiveDate>>(self, holidays: I) -> Context<T> {
+        Context {
+            holidays: Arc::new(holidays.into_iter().collect()),
+            ..self
+        }
+    }
+
+    /// Returns the same context with working days opening and closing at the given hours,
+    /// 9 and 17 by default. The opening needs to be before the closing, which is at 24 at the
+    /// latest.
+    pub fn with_business_hours(self, open: u32, close: u32) -> MomentResult<Context<T>> {
+        if open < close && close <= 24 {
+            Ok(Context {
+                business_hours: (open, close),
+                ..self
+            })
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("business hours {:?}", (open, close)),
+            })
+        }
+    }
+
+    /// Returns the same context with fiscal years starting on the first day of the given month,
+    /// e.g. 10 for fiscal years running from october to september. Fiscal years are the calendar
+    /// years by default.
+    pub fn with_fiscal_year_start(self, month: u32) -> MomentResult<Context<T>> {
+        if is_valid_month(month) {
+            Ok(Context {
+                fiscal_year_start: month,
+                ..self
+            })
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("fiscal year start {:?}", month),
+            })
+        }
+    }
+
+    /// Number of months between the start of the fiscal year and the given month, from 0 to 11.
+    fn fiscal_month0(&self, month0: u32) -> u32 {
+        (month0 + 13 - self.fiscal_year_start) % 12
+    }
+
+    /// Whether the weekday is part of the weekends.
+    pub fn is_weekend(&self, weekday: Weekday) -> bool {
+        let (first, last) = self.weekend;
+        let offset = |day: Weekday| (day.num_days_from_monday() + 7 - first.num_days_from_monday()) % 7;
+        offset(weekday) <= offset(last)
+    }
+
+    /// Whether the local date is a working day, i.e. neither on a weekend nor a holiday.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !self.is_weekend(date.weekday()) && !self.holidays.contains(&date)
+    }
+
     /// Returns a context based on the given reference date. To avoid undefined behaviour for 
     /// 32 bits operating system. The max and min date restricted to 1970 and 2038. To avoid this 
     /// restriction, `new` function should be used. 
@@ -82,7 +203,7 @@ where
 
 pub type IntervalWalker<T> = BidirectionalWalker<Interval<T>>;
 
-pub trait IntervalConstraint<T: TimeZone>
+pub trait IntervalConstraint<T: TimeZone>: Send + Sync
 where
     <T as TimeZone>::Offset: Copy,
 {
@@ -95,13 +216,13 @@ where
 }
 
 #[derive(Clone)]
-pub struct RcConstraint<T: TimeZone>(pub Rc<dyn IntervalConstraint<T>>);
+pub struct RcConstraint<T: TimeZone>(pub Arc<dyn IntervalConstraint<T>>);
 
 impl<T: TimeZone> ops::Deref for RcConstraint<T>
 where
     <T as TimeZone>::Offset: Copy,
 {
-    type Target = Rc<dyn IntervalConstraint<T>>;
+    type Target = Arc<dyn IntervalConstraint<T>>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
@@ -109,7 +230,7 @@ where
 
 macro_rules! rc {
     ($obj:expr) => {
-        RcConstraint(Rc::new($obj))
+        RcConstraint(Arc::new($obj))
     };
 }
 
@@ -133,6 +254,10 @@ fn is_valid_month_day(m: u32, d: u32) -> bool {
         }
 }
 
+fn is_valid_week(w: u32) -> bool {
+    1 <= w && w <= 53
+}
+
 fn is_valid_hour(h: u32) -> bool {
     h <= 24
 }
@@ -155,9 +280,9 @@ where
 
     pub fn translate_with<Offset>(&self, offset: Offset) -> RcConstraint<T>
     where
-        Offset: Fn(&Interval<T>, &Context<T>) -> Option<Interval<T>> + 'static,
+        Offset: Fn(&Interval<T>, &Context<T>) -> Option<Interval<T>> + Send + Sync + 'static,
     {
-        Translate::new(self, Rc::new(offset))
+        Translate::new(self, Arc::new(offset))
     }
 
     pub fn take_the_nth(&self, n: i64) -> RcConstraint<T> {
@@ -598,6 +723,87 @@ where
     }
 }
 
+/// ISO 8601 week of the year, starting on monday, the first week of a year being the one
+/// holding its first thursday. Week 53 only exists in some years. Week numbers are always ISO
+/// ones, whatever the `week_start` of the context.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WeekOfYear(pub u32);
+
+impl WeekOfYear {
+    pub fn new<T: TimeZone + 'static>(w: u32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = WeekOfYear(w);
+        if is_valid_week(w) {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for WeekOfYear
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
+        let week = self.0;
+        let origin_start = origin.start;
+        // Years are walked from the one after the origin backward and from the one before the
+        // origin forward, as the first week of a year may start in december.
+        let year_of = move |year: Interval<T>| -> Option<Interval<T>> {
+            let monday = NaiveDate::from_isoywd_opt(year.start.year(), week, Weekday::Mon)?;
+            let start = year
+                .timezone()
+                .ymd(monday.year(), monday.month(), monday.day())
+                .and_hms(0, 0, 0);
+            Some(Interval::starting_at(Moment(start), Grain::Week))
+        };
+        let year = |y: i32| {
+            Interval::starting_at(
+                Moment(origin.timezone().ymd(y, 1, 1).and_hms(0, 0, 0)),
+                Grain::Year,
+            )
+        };
+        let forward_walker = Walker::generator(year(origin.start.year() - 1), |prev| {
+            prev + PeriodComp::years(1)
+        })
+        .filter_map(year_of)
+        .skip_while(move |interval| interval.end_moment() <= origin_start);
+        let backward_walker = Walker::generator(year(origin.start.year() + 1), |prev| {
+            prev - PeriodComp::years(1)
+        })
+        .filter_map(year_of)
+        .skip_while(move |interval| interval.end_moment() > origin_start);
+        BidirectionalWalker::new()
+            .forward(forward_walker)
+            .backward(backward_walker)
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct DayOfWeek(pub Weekday);
 
@@ -650,6 +856,508 @@ where
     }
 }
 
+/// N-th day of the weeks of the context, 0 being the first day of the week, counted from its
+/// last day when negative, e.g. -1 is sunday for weeks starting on monday.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NthDayOfWeek(pub i64);
+
+impl NthDayOfWeek {
+    pub fn new<T: TimeZone>(n: i64) -> RcConstraint<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        rc!(NthDayOfWeek(n))
+    }
+}
+
+impl<T: TimeZone> IntervalConstraint<T> for NthDayOfWeek
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let mut weekday = context.week_start;
+        for _ in 0..(self.0 % 7 + 7) % 7 {
+            weekday = weekday.succ();
+        }
+        DayOfWeek(weekday).to_walker(origin, context)
+    }
+}
+
+/// Working days of the context, skipping weekends and holidays.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BusinessDay;
+
+impl BusinessDay {
+    pub fn rc<T: TimeZone + 'static>() -> RcConstraint<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        rc!(BusinessDay)
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for BusinessDay
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let walker = Cycle(Grain::Day).to_walker(origin, context);
+        let context = context.clone();
+        let is_business_day =
+            move |interval: &Interval<T>| context.is_business_day(interval.start.naive_local().date());
+        BidirectionalWalker::new()
+            .forward(walker.forward.filter(is_business_day.clone()))
+            .backward(walker.backward.filter(is_business_day))
+    }
+}
+
+/// Working hours of the business days of the context, e.g. 9:00 to 17:00 on weekdays.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BusinessHours;
+
+impl BusinessHours {
+    pub fn rc<T: TimeZone + 'static>() -> RcConstraint<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        rc!(BusinessHours)
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for BusinessHours
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let (open, close) = context.business_hours;
+        let day_origin = origin.start_round_to(Grain::Day);
+        let origin_start = origin.start;
+        // Working hours keep the wall-clock time of the opening and closing across daylight
+        // saving time transitions.
+        let hours = move |day: Interval<T>| -> Interval<T> {
+            let date = day.start.naive_local().date();
+            let at = |hour: u32| {
+                let local = date.and_hms(0, 0, 0) + Duration::hours(hour as i64);
+                Moment(from_local(&day.timezone(), &local))
+            };
+            Interval::new(at(open), Some(at(close)), Grain::Hour)
+        };
+        let walker = BusinessDay.to_walker(&day_origin, context);
+        let forward = walker.forward.map(hours);
+        let backward = forward
+            .take_while(move |interval| interval.end_moment() <= origin_start)
+            .chain(&walker.backward.map(hours));
+        BidirectionalWalker::new()
+            .forward(forward.skip_while(move |interval| interval.end_moment() <= origin_start))
+            .backward(backward)
+    }
+}
+
+/// Weekends of the context, from 18:00 on the eve of their first day to the midnight ending
+/// their last day, e.g. friday 18:00 to monday 0:00 for weekends on saturday and sunday.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Weekend;
+
+impl Weekend {
+    pub fn rc<T: TimeZone + 'static>() -> RcConstraint<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        rc!(Weekend)
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for Weekend
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Week
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let (first, last) = context.weekend;
+        let days = (last.num_days_from_monday() + 7 - first.num_days_from_monday()) % 7 + 1;
+        let origin_start = origin.start;
+        // Walking from a week earlier catches the weekend the origin may be in.
+        let week_origin = origin.start_round_to(Grain::Day) - PeriodComp::days(7);
+        let weekend = move |day: Interval<T>| -> Interval<T> {
+            let date = day.start.naive_local().date();
+            let at = |date: NaiveDate, hour: u32| {
+                Moment(from_local(&day.timezone(), &date.and_hms(hour, 0, 0)))
+            };
+            Interval::new(
+                at(date.pred(), 18),
+                Some(at(date + Duration::days(days as i64), 0)),
+                Grain::Hour,
+            )
+        };
+        let walker = DayOfWeek(first).to_walker(&week_origin, context);
+        let forward = walker.forward.map(weekend);
+        let backward = forward
+            .take_while(move |interval| interval.end_moment() <= origin_start)
+            .chain(&walker.backward.map(weekend));
+        BidirectionalWalker::new()
+            .forward(forward.skip_while(move |interval| interval.end_moment() <= origin_start))
+            .backward(backward)
+    }
+}
+
+/// Quarters and years of the fiscal calendar of the context, e.g. quarters starting in october,
+/// january, april and july for fiscal years starting in october. Other grains are walked like
+/// `Cycle`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FiscalCycle(pub Grain);
+
+impl FiscalCycle {
+    pub fn rc<T: TimeZone + 'static>(grain: Grain) -> RcConstraint<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        rc!(FiscalCycle(grain))
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for FiscalCycle
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        self.0
+    }
+
+    fn grain_min(&self) -> Grain {
+        self.0
+    }
+
+    fn grain_left(&self) -> Grain {
+        self.0
+    }
+
+    fn grain_right(&self) -> Grain {
+        self.0
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        self.0
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let months = match self.0 {
+            Grain::Quarter => 3,
+            Grain::Year => 12,
+            _ => return Cycle(self.0).to_walker(origin, context),
+        };
+        let offset = context.fiscal_month0(origin.start.month0()) % months;
+        let anchor = Interval::starting_at(
+            origin.start_round_to(Grain::Month).start - PeriodComp::months(offset as i64),
+            self.0,
+        );
+        let grain = self.0;
+        BidirectionalWalker::new()
+            .forward_with(anchor, move |prev| prev + PeriodComp::new(grain, 1))
+            .backward_with(anchor - PeriodComp::new(grain, 1), move |prev| {
+                prev - PeriodComp::new(grain, 1)
+            })
+    }
+}
+
+/// N-th quarter of the fiscal years of the context, from 1 to 4.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FiscalQuarter(pub u32);
+
+impl FiscalQuarter {
+    pub fn new<T: TimeZone + 'static>(q: u32) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = FiscalQuarter(q);
+        if 1 <= q && q <= 4 {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for FiscalQuarter
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Quarter
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Quarter
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Quarter
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Quarter
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let walker = FiscalCycle(Grain::Quarter).to_walker(origin, context);
+        let quarter = self.0;
+        let context = context.clone();
+        let is_quarter = move |interval: &Interval<T>| {
+            context.fiscal_month0(interval.start.month0()) / 3 + 1 == quarter
+        };
+        BidirectionalWalker::new()
+            .forward(walker.forward.filter(is_quarter.clone()))
+            .backward(walker.backward.filter(is_quarter))
+    }
+}
+
+/// Fiscal year of the context ending in the given calendar year, e.g. FY2024 runs from october
+/// 2023 to september 2024 for fiscal years starting in october.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FiscalYear(pub i32);
+
+impl FiscalYear {
+    pub fn new<T: TimeZone + 'static>(y: i32) -> RcConstraint<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        rc!(FiscalYear(y))
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for FiscalYear
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let month = context.fiscal_year_start;
+        let year = if month == 1 { self.0 } else { self.0 - 1 };
+        if year > context.max.start.year() || self.0 < context.min.start.year() {
+            return BidirectionalWalker::new();
+        }
+        let start = Moment(origin.timezone().ymd(year, month, 1).and_hms(0, 0, 0));
+        let interval = Interval::starting_at(start, Grain::Year);
+        if origin.start < interval.end_moment() {
+            BidirectionalWalker::new().forward_values(vec![interval])
+        } else {
+            BidirectionalWalker::new().backward_values(vec![interval])
+        }
+    }
+}
+
+/// Day of a month of a non-gregorian calendar, e.g. the 15th day of the 8th month of the chinese
+/// calendar for the Mid-Autumn festival.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CalendarDate {
+    pub calendar: Calendar,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl CalendarDate {
+    pub fn new<T: TimeZone + 'static>(
+        calendar: Calendar,
+        month: u32,
+        day: u32,
+    ) -> MomentResult<RcConstraint<T>>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let args = CalendarDate {
+            calendar,
+            month,
+            day,
+        };
+        let max_month = if calendar == Calendar::Hebrew { 13 } else { 12 };
+        if month >= 1 && month <= max_month && day >= 1 && day <= 30 {
+            Ok(rc!(args))
+        } else {
+            Err(MomentError::ConstraintsInvalidArgs {
+                context: format!("{:?}", args),
+            })
+        }
+    }
+}
+
+impl<T: TimeZone + 'static> IntervalConstraint<T> for CalendarDate
+where
+    <T as TimeZone>::Offset: Copy,
+{
+    fn grain(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_min(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_left(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Year
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let rounded_moment = Moment(
+            origin
+                .timezone()
+                .ymd(origin.start.year(), 1, 1)
+                .and_hms(0, 0, 0),
+        );
+        let anchor = Interval::starting_at(rounded_moment, Grain::Year);
+        let origin_start = origin.start;
+        // Boundaries for iteration
+        let max_context_moment = context.max.end_moment();
+        let min_context_moment = context.min.start;
+
+        let date = *self;
+        let dates_in_year = move |year: Interval<T>| -> Vec<Interval<T>> {
+            date.calendar
+                .dates_in_gregorian_year(date.month, date.day, year.start.year())
+                .into_iter()
+                .map(|d| {
+                    Interval::starting_at(
+                        Moment(
+                            year.timezone()
+                                .ymd(d.year(), d.month(), d.day())
+                                .and_hms(0, 0, 0),
+                        ),
+                        Grain::Day,
+                    )
+                })
+                .collect()
+        };
+
+        let forward_walker = Walker::generator(anchor, |prev| prev + PeriodComp::years(1))
+            .take_while(move |i| i.start <= max_context_moment)
+            .flat_map(move |year| Walker::vec(dates_in_year(year)))
+            .skip_while(move |i| i.end_moment() <= origin_start);
+
+        let backward_walker = Walker::generator(anchor, |prev| prev - PeriodComp::years(1))
+            .take_while(move |i| i.end_moment() >= min_context_moment)
+            .flat_map(move |year| {
+                let mut dates = dates_in_year(year);
+                dates.reverse();
+                Walker::vec(dates)
+            })
+            .skip_while(move |i| i.end_moment() > origin_start);
+
+        BidirectionalWalker::new()
+            .forward(forward_walker)
+            .backward(backward_walker)
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub struct HourMinute {
     pub hour: u32,
@@ -720,21 +1428,7 @@ where
     }
 
     fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
-        let clock_step = if self.hour <= 12 && self.is_12_clock {
-            12
-        } else {
-            24
-        };
-        let offset_hour = (self.hour as i64 - origin.start.hour() as i64 + clock_step) % clock_step;
-        let offset_minute = self.minute as i64 % 60;
-        let anchor = origin.start_round_to(Grain::Hour)
-            + PeriodComp::hours(offset_hour)
-            + PeriodComp::minutes(offset_minute);
-        BidirectionalWalker::new()
-            .forward_with(anchor, move |prev| prev + PeriodComp::hours(clock_step))
-            .backward_with(anchor - PeriodComp::hours(clock_step), move |prev| {
-                prev - PeriodComp::hours(clock_step)
-            })
+        WallClock::new(self.hour, self.minute % 60, self.is_12_clock, Grain::Minute).to_walker(origin)
     }
 }
 
@@ -793,32 +1487,87 @@ where
         Grain::Hour
     }
 
-    fn grain_left(&self) -> Grain {
-        Grain::Hour
+    fn grain_left(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn grain_right(&self) -> Grain {
+        Grain::Hour
+    }
+
+    fn coarse_grain_step(&self) -> Grain {
+        Grain::Day
+    }
+
+    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
+        WallClock::new(self.hour, 0, self.is_12_clock, Grain::Hour).to_walker(origin)
+    }
+}
+
+/// Wall-clock time repeated every 12 or 24 hours. Occurrences are computed on local times so that
+/// they don't drift across daylight saving time transitions.
+#[derive(Debug, Copy, Clone)]
+struct WallClock {
+    hour: u32,
+    minute: u32,
+    clock_step: u32,
+    grain: Grain,
+}
+
+impl WallClock {
+    fn new(hour: u32, minute: u32, is_12_clock: bool, grain: Grain) -> WallClock {
+        let clock_step = if hour <= 12 && is_12_clock { 12 } else { 24 };
+        WallClock {
+            hour: hour % clock_step,
+            minute,
+            clock_step,
+            grain,
+        }
+    }
+
+    /// Latest occurrence at or before the given local time.
+    fn floor(&self, local: NaiveDateTime) -> NaiveDateTime {
+        let mut slot = local.date().and_hms(self.hour + 24 - self.clock_step, self.minute, 0);
+        while slot > local {
+            slot = slot - Duration::hours(self.clock_step as i64);
+        }
+        slot
     }
 
-    fn grain_right(&self) -> Grain {
-        Grain::Hour
+    fn at<T: TimeZone>(&self, tz: &T, slot: NaiveDateTime) -> Interval<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        Interval::starting_at(Moment(from_local(tz, &slot)), self.grain)
     }
 
-    fn coarse_grain_step(&self) -> Grain {
-        Grain::Day
+    /// Moves `n` occurrences away from the given one. The occurrence is recovered from the local
+    /// time, which may have been shifted by a daylight saving time gap.
+    fn step<T: TimeZone>(&self, interval: Interval<T>, n: i64) -> Interval<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let slot = self.floor(interval.start.naive_local())
+            + Duration::hours(n * self.clock_step as i64);
+        self.at(&interval.timezone(), slot)
     }
 
-    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
-        let clock_step = if self.hour <= 12 && self.is_12_clock {
-            12
+    fn to_walker<T: TimeZone>(self, origin: &Interval<T>) -> IntervalWalker<T>
+    where
+        <T as TimeZone>::Offset: Copy,
+    {
+        let local = origin.start.naive_local();
+        let threshold = local.date().and_hms(local.hour(), 0, 0);
+        let floor = self.floor(threshold);
+        let anchor = if floor == threshold {
+            floor
         } else {
-            24
+            floor + Duration::hours(self.clock_step as i64)
         };
-        let offset = (self.hour as i64 - origin.start.hour() as i64 + clock_step) % clock_step;
-        let anchor = origin.start_round_to(Grain::Hour) + PeriodComp::hours(offset);
-
+        let anchor = self.at(&origin.timezone(), anchor);
         BidirectionalWalker::new()
-            .forward_with(anchor, move |prev| prev + PeriodComp::hours(clock_step))
-            .backward_with(anchor - PeriodComp::hours(clock_step), move |prev| {
-                prev - PeriodComp::hours(clock_step)
-            })
+            .forward_with(anchor, move |prev| self.step(prev, 1))
+            .backward_with(self.step(anchor, -1), move |prev| self.step(prev, -1))
     }
 }
 
@@ -997,8 +1746,12 @@ where
         self.0
     }
 
-    fn to_walker(&self, origin: &Interval<T>, _context: &Context<T>) -> IntervalWalker<T> {
-        let anchor = origin.start_round_to(self.0);
+    fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
+        let anchor = if self.0 == Grain::Week {
+            origin.start_round_to_week(context.week_start)
+        } else {
+            origin.start_round_to(self.0)
+        };
         let grain = self.0;
         BidirectionalWalker::new()
             .forward_with(anchor, move |prev| prev + PeriodComp::new(grain, 1))
@@ -1234,7 +1987,7 @@ where
         let not_immediate = self.not_immediate;
         let translate = Translate {
             generator: self.after.clone(),
-            offset: Rc::new(
+            offset: Arc::new(
                 move |after: &Interval<T>, c: &Context<T>| -> Option<Interval<T>> {
                     let walker = cycle.to_walker(after, c);
                     if n >= 0 {
@@ -1303,7 +2056,7 @@ where
         let cycle = self.cycle.clone();
         let translate = Translate {
             generator: self.base.clone(),
-            offset: Rc::new(
+            offset: Arc::new(
                 move |i: &Interval<T>, c: &Context<T>| -> Option<Interval<T>> {
                     let pivot = i.after();
                     let walker = cycle.to_walker(&pivot, c);
@@ -1360,13 +2113,17 @@ where
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         fn walk_from<U: TimeZone + 'static>(
             origin: &Interval<U>,
-            context: Context<U>,
+            context: &Context<U>,
             constraint: RcConstraint<U>,
         ) -> Walker<Interval<U>>
         where
             <U as TimeZone>::Offset: Copy,
         {
-            let context = Context::new(context.reference, *origin, *origin);
+            let context = Context {
+                min: *origin,
+                max: *origin,
+                ..context.clone()
+            };
             let max_moment = origin.end_moment();
             let origin_copied = origin.clone();
             constraint
@@ -1379,33 +2136,36 @@ where
 
         fn combine<U: TimeZone + 'static>(
             origin: &Interval<U>,
-            context: Context<U>,
+            context: &Context<U>,
             fine: RcConstraint<U>,
             coarse: RcConstraint<U>,
         ) -> IntervalWalker<U>
         where
             <U as TimeZone>::Offset: Copy,
         {
-            let coarse_walker = coarse.to_walker(origin, &context);
+            let coarse_walker = coarse.to_walker(origin, context);
             let max_moment = context.max.end_moment();
+            let min_moment = context.min.start;
             let fine_for_walker = fine.clone();
+            let context_for_walker = context.clone();
             let fore = coarse_walker
                 .forward
                 .take_while(move |i| i.start <= max_moment)
                 .take(183)
-                .flat_map(move |i| walk_from(&i, context, fine_for_walker.clone()));
+                .flat_map(move |i| walk_from(&i, &context_for_walker, fine_for_walker.clone()));
+            let context = context.clone();
             let back = coarse_walker
                 .backward
-                .take_while(move |i| i.end_moment() >= context.min.start)
+                .take_while(move |i| i.end_moment() >= min_moment)
                 .take(183)
-                .flat_map(move |i| walk_from(&i, context, fine.clone()));
+                .flat_map(move |i| walk_from(&i, &context, fine.clone()));
             IntervalWalker::new().forward(fore).backward(back)
         }
 
         if self.lhs.coarse_grain_step() <= self.rhs.coarse_grain_step() {
-            combine(origin, *context, self.rhs.clone(), self.lhs.clone())
+            combine(origin, context, self.rhs.clone(), self.lhs.clone())
         } else {
-            combine(origin, *context, self.lhs.clone(), self.rhs.clone())
+            combine(origin, context, self.lhs.clone(), self.rhs.clone())
         }
     }
 }
@@ -1413,7 +2173,7 @@ where
 #[derive(Clone)]
 pub struct Translate<T: TimeZone> {
     generator: RcConstraint<T>,
-    offset: Rc<dyn Fn(&Interval<T>, &Context<T>) -> Option<Interval<T>>>,
+    offset: Arc<dyn Fn(&Interval<T>, &Context<T>) -> Option<Interval<T>> + Send + Sync>,
 }
 
 impl<T: TimeZone + 'static> Translate<T>
@@ -1422,7 +2182,7 @@ where
 {
     pub fn new(
         generator: &RcConstraint<T>,
-        offset: Rc<dyn Fn(&Interval<T>, &Context<T>) -> Option<Interval<T>>>,
+        offset: Arc<dyn Fn(&Interval<T>, &Context<T>) -> Option<Interval<T>> + Send + Sync>,
     ) -> RcConstraint<T> {
         rc!(Translate {
             generator: generator.clone(),
@@ -1457,42 +2217,47 @@ where
 
     fn to_walker(&self, origin: &Interval<T>, context: &Context<T>) -> IntervalWalker<T> {
         let generator_walker = self.generator.to_walker(origin, context);
-        let context = *context;
+        let max_moment = context.max.end_moment();
+        let min_moment = context.min.start;
 
         let offset = self.offset.clone();
+        let context_for_offset = context.clone();
         let origin = *origin;
         let prepend_to_fore = generator_walker
             .backward
             .take(12)
-            .filter_map(move |i| offset(&i, &context))
+            .filter_map(move |i| offset(&i, &context_for_offset))
             .take_while(move |i| origin.start <= i.end_moment());
         let mut prepend_to_fore: Vec<Interval<T>> = prepend_to_fore.into_iter().collect();
         prepend_to_fore.reverse();
 
         let offset = self.offset.clone();
+        let context_for_offset = context.clone();
         let still_fore = generator_walker
             .forward
             .take(12)
-            .filter_map(move |i| offset(&i, &context))
+            .filter_map(move |i| offset(&i, &context_for_offset))
             .skip_while(move |i| origin.start > i.end_moment())
-            .take_while(move |i| i.start <= context.max.end_moment());
+            .take_while(move |i| i.start <= max_moment);
 
         let offset = self.offset.clone();
+        let context_for_offset = context.clone();
         let prepend_to_back = generator_walker
             .forward
             .take(12)
-            .filter_map(move |i| offset(&i, &context))
+            .filter_map(move |i| offset(&i, &context_for_offset))
             .take_while(move |i| origin.start > i.end_moment());
         let mut prepend_to_back: Vec<Interval<T>> = prepend_to_back.into_iter().collect();
         prepend_to_back.reverse();
 
         let offset = self.offset.clone();
+        let context_for_offset = context.clone();
         let still_back = generator_walker
             .backward
             .take(12)
-            .filter_map(move |i| offset(&i, &context))
+            .filter_map(move |i| offset(&i, &context_for_offset))
             .skip_while(move |i| origin.start <= i.end_moment())
-            .take_while(move |i| context.min.start <= i.end_moment());
+            .take_while(move |i| min_moment <= i.end_moment());
 
         IntervalWalker::new()
             .forward(Walker::vec(prepend_to_fore).chain(&still_fore))
@@ -1549,7 +2314,7 @@ where
         let to = self.to.clone();
         let translate = Translate {
             generator: self.from.clone(),
-            offset: Rc::new(
+            offset: Arc::new(
                 move |start: &Interval<T>, c: &Context<T>| -> Option<Interval<T>> {
                     to.to_walker(start, c).forward.next().map(|end| {
                         if inclusive {
@@ -1614,7 +2379,7 @@ where
             let grain_after_shift = period_grain.after_shift();
             let translate = Translate {
                 generator: self.base.clone(),
-                offset: Rc::new(
+                offset: Arc::new(
                     move |i: &Interval<T>, _: &Context<T>| -> Option<Interval<T>> {
                         if is_forward && i.is_span() {
                             Some(i.after().interval_round_to(grain_after_shift) + &period)
@@ -1836,6 +2601,322 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cycle_week_start() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)))
+            .with_week_start(Weekday::Sun);
+        let walker = Cycle(Grain::Week).to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 04, 23).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            walker.forward.clone().next()
+        );
+        let next_week = Cycle::rc(Grain::Week).take_the_nth(1);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 04, 30).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            next_week.to_walker(&context.reference, &context).forward.next()
+        );
+    }
+
+    #[test]
+    fn test_nth_day_of_week() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let last_day = |context: &Context<Paris>| {
+            NthDayOfWeek(-1)
+                .to_walker(&context.reference, context)
+                .forward
+                .next()
+        };
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 04, 30).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            last_day(&context)
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 04, 29).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            last_day(&context.with_week_start(Weekday::Sun))
+        );
+    }
+
+    #[test]
+    fn test_business_day() {
+        // friday
+        let context = build_context(Moment(Paris.ymd(2017, 04, 28).and_hms(9, 10, 11)))
+            .with_holidays(vec![NaiveDate::from_ymd(2017, 5, 1)]);
+        let days = BusinessDay
+            .to_walker(&context.reference, &context)
+            .forward
+            .take(3)
+            .into_iter()
+            .map(|interval| interval.start)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Moment(Paris.ymd(2017, 04, 28).and_hms(0, 0, 0)),
+                Moment(Paris.ymd(2017, 05, 02).and_hms(0, 0, 0)),
+                Moment(Paris.ymd(2017, 05, 03).and_hms(0, 0, 0)),
+            ],
+            days
+        );
+        let next = BusinessDay::rc().take_the_nth_not_immediate(0);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 05, 02).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            next.to_walker(&context.reference, &context).forward.next()
+        );
+        let context = context.with_weekend(Weekday::Fri, Weekday::Sat);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 04, 30).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            next.to_walker(&context.reference, &context).forward.next()
+        );
+    }
+
+    #[test]
+    fn test_weekend() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 28).and_hms(9, 10, 11)));
+        assert!(context.is_weekend(Weekday::Sat));
+        assert!(context.is_weekend(Weekday::Sun));
+        assert!(!context.is_weekend(Weekday::Mon));
+        let context = context.with_weekend(Weekday::Sun, Weekday::Sun);
+        assert!(!context.is_weekend(Weekday::Sat));
+        assert!(context.is_weekend(Weekday::Sun));
+    }
+
+    #[test]
+    fn test_weekend_walker() {
+        // tuesday
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let walker = Weekend.to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::new(
+                Moment(Paris.ymd(2017, 04, 28).and_hms(18, 0, 0)),
+                Some(Moment(Paris.ymd(2017, 05, 01).and_hms(0, 0, 0))),
+                Grain::Hour
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::new(
+                Moment(Paris.ymd(2017, 04, 21).and_hms(18, 0, 0)),
+                Some(Moment(Paris.ymd(2017, 04, 24).and_hms(0, 0, 0))),
+                Grain::Hour
+            )),
+            walker.backward.clone().next()
+        );
+        // sunday
+        let context = build_context(Moment(Paris.ymd(2017, 04, 30).and_hms(9, 10, 11)))
+            .with_weekend(Weekday::Fri, Weekday::Sat);
+        assert_eq!(
+            Some(Interval::new(
+                Moment(Paris.ymd(2017, 05, 04).and_hms(18, 0, 0)),
+                Some(Moment(Paris.ymd(2017, 05, 07).and_hms(0, 0, 0))),
+                Grain::Hour
+            )),
+            Weekend.to_walker(&context.reference, &context).forward.next()
+        );
+        let context = context.with_weekend(Weekday::Sat, Weekday::Sun);
+        assert_eq!(
+            Some(Interval::new(
+                Moment(Paris.ymd(2017, 04, 28).and_hms(18, 0, 0)),
+                Some(Moment(Paris.ymd(2017, 05, 01).and_hms(0, 0, 0))),
+                Grain::Hour
+            )),
+            Weekend.to_walker(&context.reference, &context).forward.next()
+        );
+    }
+
+    #[test]
+    fn test_business_hours() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 28).and_hms(18, 10, 11)))
+            .with_business_hours(8, 18)
+            .unwrap();
+        let walker = BusinessHours.to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::new(
+                Moment(Paris.ymd(2017, 05, 01).and_hms(8, 0, 0)),
+                Some(Moment(Paris.ymd(2017, 05, 01).and_hms(18, 0, 0))),
+                Grain::Hour
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::new(
+                Moment(Paris.ymd(2017, 04, 28).and_hms(8, 0, 0)),
+                Some(Moment(Paris.ymd(2017, 04, 28).and_hms(18, 0, 0))),
+                Grain::Hour
+            )),
+            walker.backward.clone().next()
+        );
+        let context = context.with_business_hours(20, 24).unwrap();
+        assert_eq!(
+            Some(Interval::new(
+                Moment(Paris.ymd(2017, 04, 28).and_hms(20, 0, 0)),
+                Some(Moment(Paris.ymd(2017, 04, 29).and_hms(0, 0, 0))),
+                Grain::Hour
+            )),
+            BusinessHours
+                .to_walker(&context.reference, &context)
+                .forward
+                .next()
+        );
+        assert!(context.clone().with_business_hours(18, 8).is_err());
+        assert!(context.with_business_hours(8, 25).is_err());
+    }
+
+    #[test]
+    fn test_fiscal_calendar() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)))
+            .with_fiscal_year_start(10)
+            .unwrap();
+        let quarter = FiscalCycle(Grain::Quarter).to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 04, 01).and_hms(0, 0, 0)),
+                Grain::Quarter
+            )),
+            quarter.forward.clone().next()
+        );
+        let year = FiscalCycle(Grain::Year).to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2016, 10, 01).and_hms(0, 0, 0)),
+                Grain::Year
+            )),
+            year.forward.clone().next()
+        );
+        let q1 = FiscalQuarter(1).to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 10, 01).and_hms(0, 0, 0)),
+                Grain::Quarter
+            )),
+            q1.forward.clone().next()
+        );
+        let q3_fy18 = FiscalQuarter::new(3).unwrap().intersect(&FiscalYear::new(2018));
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2018, 04, 01).and_hms(0, 0, 0)),
+                Grain::Quarter
+            )),
+            q3_fy18.to_walker(&context.reference, &context).forward.next()
+        );
+        assert!(context.clone().with_fiscal_year_start(0).is_err());
+        assert!(context.with_fiscal_year_start(13).is_err());
+    }
+
+    #[test]
+    fn test_calendar_date() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let chinese_new_year = CalendarDate::new(Calendar::Chinese, 1, 1)
+            .unwrap()
+            .to_walker(&context.reference, &context);
+        assert_eq!(
+            vec![
+                Interval::starting_at(Moment(Paris.ymd(2018, 02, 16).and_hms(0, 0, 0)), Grain::Day),
+                Interval::starting_at(Moment(Paris.ymd(2019, 02, 05).and_hms(0, 0, 0)), Grain::Day),
+            ],
+            chinese_new_year.forward.take(2).into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![
+                Interval::starting_at(Moment(Paris.ymd(2017, 01, 28).and_hms(0, 0, 0)), Grain::Day),
+                Interval::starting_at(Moment(Paris.ymd(2016, 02, 08).and_hms(0, 0, 0)), Grain::Day),
+            ],
+            chinese_new_year.backward.take(2).into_iter().collect::<Vec<_>>()
+        );
+        let rosh_hashanah = CalendarDate::new(Calendar::Hebrew, 7, 1)
+            .unwrap()
+            .to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 09, 21).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            rosh_hashanah.forward.clone().next()
+        );
+        assert!(CalendarDate::new::<Tz>(Calendar::Islamic, 13, 1).is_err());
+    }
+
+    #[test]
+    fn test_week_of_year() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
+        let walker = WeekOfYear(42).to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 10, 16).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2016, 10, 17).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            walker.backward.clone().next()
+        );
+        let first_weeks = WeekOfYear(1)
+            .to_walker(&context.reference, &context)
+            .forward
+            .take(3)
+            .into_iter()
+            .map(|interval| interval.start)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                Moment(Paris.ymd(2018, 01, 01).and_hms(0, 0, 0)),
+                Moment(Paris.ymd(2018, 12, 31).and_hms(0, 0, 0)),
+                Moment(Paris.ymd(2019, 12, 30).and_hms(0, 0, 0)),
+            ],
+            first_weeks
+        );
+        let walker = WeekOfYear(53).to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2020, 12, 28).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            walker.forward.clone().next()
+        );
+    }
+
+    #[test]
+    fn test_week_of_year_ignores_week_start() {
+        let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)))
+            .with_week_start(Weekday::Sun);
+        let walker = WeekOfYear(42).to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2017, 10, 16).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            walker.forward.clone().next()
+        );
+        let walker = WeekOfYear(1).to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(Paris.ymd(2018, 01, 01).and_hms(0, 0, 0)),
+                Grain::Week
+            )),
+            walker.forward.clone().next()
+        );
+    }
+
     #[test]
     fn test_take_the_nth_forward_positive() {
         let context = build_context(Moment(Paris.ymd(2017, 04, 25).and_hms(9, 10, 11)));
@@ -3065,4 +4146,115 @@ mod tests {
         assert!(DayOfMonth::new::<Local>(0).is_err());
         assert!(DayOfMonth::new::<Local>(32).is_err());
     }
+
+    #[test]
+    fn test_constraint_is_send_sync() {
+        fn assert_send_sync<S: Send + Sync>(_: &S) {}
+        let constraint = Cycle::rc::<Tz>(Grain::Day)
+            .take_the_nth(1)
+            .intersect(&Hour::clock_24(9).unwrap());
+        assert_send_sync(&constraint);
+    }
+
+    fn paris() -> Tz {
+        Tz::iana(chrono_tz::Europe::Paris)
+    }
+
+    #[test]
+    fn test_hour_across_dst() {
+        let context = Context::for_reference(Interval::starting_at(
+            Moment(paris().ymd(2017, 03, 25).and_hms(12, 0, 0)),
+            Grain::Second,
+        ));
+        let walker = Hour::clock_24(9)
+            .unwrap()
+            .to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(paris().ymd(2017, 03, 26).and_hms(9, 0, 0)),
+                Grain::Hour
+            )),
+            walker.forward.clone().next()
+        );
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(paris().ymd(2017, 03, 25).and_hms(9, 0, 0)),
+                Grain::Hour
+            )),
+            walker.backward.clone().next()
+        );
+
+        let context = Context::for_reference(Interval::starting_at(
+            Moment(paris().ymd(2017, 10, 30).and_hms(12, 0, 0)),
+            Grain::Second,
+        ));
+        let walker = Hour::clock_12(9)
+            .unwrap()
+            .to_walker(&context.reference, &context);
+        assert_eq!(
+            vec![
+                Moment(paris().ymd(2017, 10, 30).and_hms(9, 0, 0)),
+                Moment(paris().ymd(2017, 10, 29).and_hms(21, 0, 0)),
+                Moment(paris().ymd(2017, 10, 29).and_hms(9, 0, 0)),
+                Moment(paris().ymd(2017, 10, 28).and_hms(21, 0, 0)),
+            ],
+            walker
+                .backward
+                .take(4)
+                .into_iter()
+                .map(|i| i.start)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_hour_minute_in_dst_gap() {
+        let context = Context::for_reference(Interval::starting_at(
+            Moment(paris().ymd(2017, 03, 25).and_hms(12, 0, 0)),
+            Grain::Second,
+        ));
+        let walker = HourMinute::clock_24(2, 30)
+            .unwrap()
+            .to_walker(&context.reference, &context);
+        assert_eq!(
+            vec![
+                Moment(paris().ymd(2017, 03, 26).and_hms(3, 30, 0)),
+                Moment(paris().ymd(2017, 03, 27).and_hms(2, 30, 0)),
+            ],
+            walker
+                .forward
+                .take(2)
+                .into_iter()
+                .map(|i| i.start)
+                .collect::<Vec<_>>()
+        );
+
+        let context = context.with_dst_policy(DstPolicy::Earliest);
+        let walker = HourMinute::clock_24(2, 30)
+            .unwrap()
+            .to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Moment(paris().ymd(2017, 03, 26).and_hms(3, 0, 0))),
+            walker.forward.clone().next().map(|i| i.start)
+        );
+    }
+
+    #[test]
+    fn test_shift_by_days_across_dst() {
+        let context = Context::for_reference(Interval::starting_at(
+            Moment(paris().ymd(2017, 10, 28).and_hms(9, 10, 11)),
+            Grain::Second,
+        ));
+        let walker = Cycle::rc(Grain::Day)
+            .take_the_nth(0)
+            .shift_by(PeriodComp::days(2).into())
+            .to_walker(&context.reference, &context);
+        assert_eq!(
+            Some(Interval::starting_at(
+                Moment(paris().ymd(2017, 10, 30).and_hms(0, 0, 0)),
+                Grain::Day
+            )),
+            walker.forward.clone().next()
+        );
+    }
 }
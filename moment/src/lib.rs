@@ -1,4 +1,5 @@
 extern crate chrono;
+extern crate chrono_tz;
 #[macro_use]
 extern crate derive_new;
 #[macro_use]
@@ -8,18 +9,22 @@ extern crate failure;
 extern crate vec_map;
 
 pub mod bidirectional_walker;
+mod calendar;
 pub mod interval_constraints;
 mod period;
+mod timezone;
 pub mod walker;
 
 use std::cmp::Ordering;
 use std::fmt;
 use std::ops;
 
-use chrono::{DateTime, Datelike, Duration, Timelike};
-pub use chrono::{Local, TimeZone, Weekday};
+use chrono::{DateTime, Duration, Utc};
+pub use calendar::*;
+pub use chrono::{Datelike, FixedOffset, Local, NaiveDate, TimeZone, Timelike, Weekday};
 pub use interval_constraints::*;
 pub use period::*;
+pub use timezone::*;
 
 pub type MomentResult<T> = Result<T, MomentError>;
 
@@ -69,7 +74,8 @@ where
     }
 }
 
-fn last_day_in_month<T: TimeZone>(y: i32, m: u32, tz: T) -> u32 {
+/// Number of days of the month, i.e. the day of its last day.
+pub fn last_day_in_month<T: TimeZone>(y: i32, m: u32, tz: T) -> u32 {
     assert!(m >= 1 && m <= 12);
     for d in 28..31 {
         if (tz.ymd_opt(y, m, d + 1)).single().is_none() {
@@ -108,6 +114,19 @@ impl Moment<Local> {
     }
 }
 
+impl Moment<Tz> {
+    pub fn now_in(tz: Tz) -> Moment<Tz> {
+        Moment(Utc::now().with_timezone(&tz))
+    }
+}
+
+impl<T: TimeZone> Moment<T> {
+    /// Returns the same moment expressed in the given timezone.
+    pub fn with_timezone<U: TimeZone>(&self, tz: &U) -> Moment<U> {
+        Moment(self.0.with_timezone(tz))
+    }
+}
+
 impl<T: TimeZone> Moment<T>
 where
     <T as TimeZone>::Offset: Copy,
@@ -130,42 +149,53 @@ where
         };
         let target_month_days = last_day_in_month(year, month0 + 1, self.timezone());
         let day = ::std::cmp::min(target_month_days, self.day());
-        Moment(self.timezone().ymd(year, month0 + 1, day).and_hms(
-            self.hour(),
-            self.minute(),
-            self.second(),
-        ))
+        let date = NaiveDate::from_ymd(year, month0 + 1, day);
+        self.with_local_date(date)
+    }
+
+    /// Returns the moment at the same wall-clock time on the given day.
+    fn with_local_date(self, date: NaiveDate) -> Moment<T> {
+        Moment(from_local(&self.timezone(), &date.and_time(self.time())))
     }
 
     fn round_to(self, g: Grain) -> Moment<T> {
         match g {
-            Grain::Year => Moment(self.timezone().ymd(self.year(), 1, 1).and_hms(0, 0, 0)),
-            Grain::Month => Moment(
-                self.timezone()
-                    .ymd(self.year(), self.month(), 1)
-                    .and_hms(0, 0, 0),
+            Grain::Year => self.at_local_midnight(NaiveDate::from_ymd(self.year(), 1, 1)),
+            Grain::Month => {
+                self.at_local_midnight(NaiveDate::from_ymd(self.year(), self.month(), 1))
+            }
+            Grain::Day => self.at_local_midnight(self.naive_local().date()),
+            // Hours and minutes are rounded on elapsed time so that a moment repeated by a
+            // daylight saving time transition stays in its own hour.
+            Grain::Hour => Moment(
+                self.0
+                    - Duration::minutes(self.minute() as i64)
+                    - Duration::seconds(self.second() as i64)
+                    - Duration::nanoseconds(self.nanosecond() as i64),
+            ),
+            Grain::Minute => Moment(
+                self.0
+                    - Duration::seconds(self.second() as i64)
+                    - Duration::nanoseconds(self.nanosecond() as i64),
             ),
-            Grain::Day => Moment(self.date().and_hms(0, 0, 0)),
-            Grain::Hour => Moment(self.date().and_hms(self.hour(), 0, 0)),
-            Grain::Minute => Moment(self.date().and_hms(self.hour(), self.minute(), 0)),
             Grain::Second => self,
-            Grain::Week => {
-                // shift to monday morning
-                let day_offset = self.weekday().num_days_from_monday(); // monday is 0 here
-                self.round_to(Grain::Day) - PeriodComp::days(day_offset as i64)
-            }
+            Grain::Week => self.round_to_week(Weekday::Mon),
             Grain::Quarter => {
                 self.round_to(Grain::Month) - PeriodComp::months(self.month0() as i64 % 3)
             }
         }
     }
 
-    fn adjust_for_daylight_saving(self) -> Moment<T> {
-        Moment(
-            self.timezone()
-                .ymd(self.year(), self.month(), self.day())
-                .and_hms(self.hour(), self.minute(), self.second()),
-        )
+    /// Shifts to the midnight starting the week, weeks starting on the given day.
+    fn round_to_week(self, week_start: Weekday) -> Moment<T> {
+        let day_offset = (self.weekday().num_days_from_monday() + 7
+            - week_start.num_days_from_monday())
+            % 7;
+        self.round_to(Grain::Day) - PeriodComp::days(day_offset as i64)
+    }
+
+    fn at_local_midnight(self, date: NaiveDate) -> Moment<T> {
+        Moment(from_local(&self.timezone(), &date.and_hms(0, 0, 0)))
     }
 }
 
@@ -218,10 +248,15 @@ where
             Grain::Year => self.add_months(12 * p.quantity as i32),
             Grain::Quarter => self.add_months(3 * p.quantity as i32),
             Grain::Month => self.add_months(p.quantity as i32),
+            // Days and weeks keep the wall-clock time, hours and below are elapsed time.
             Grain::Week => {
-                Moment(self.0 + Duration::weeks(p.quantity)).adjust_for_daylight_saving()
+                let date = self.naive_local().date() + Duration::weeks(p.quantity);
+                self.with_local_date(date)
+            }
+            Grain::Day => {
+                let date = self.naive_local().date() + Duration::days(p.quantity);
+                self.with_local_date(date)
             }
-            Grain::Day => Moment(self.0 + Duration::days(p.quantity)).adjust_for_daylight_saving(),
             Grain::Hour => Moment(self.0 + Duration::hours(p.quantity)),
             Grain::Minute => Moment(self.0 + Duration::minutes(p.quantity)),
             Grain::Second => Moment(self.0 + Duration::seconds(p.quantity)),
@@ -257,9 +292,18 @@ pub struct Interval<T: TimeZone> {
 }
 
 impl<T: TimeZone> Interval<T> {
-    fn timezone(&self) -> T {
+    pub fn timezone(&self) -> T {
         self.start.0.timezone()
     }
+
+    /// Returns the same interval expressed in the given timezone.
+    pub fn with_timezone<U: TimeZone>(&self, tz: &U) -> Interval<U> {
+        Interval {
+            start: self.start.with_timezone(tz),
+            end: self.end.as_ref().map(|end| end.with_timezone(tz)),
+            grain: self.grain,
+        }
+    }
 }
 
 impl Interval<Local> {
@@ -298,6 +342,14 @@ where
         }
     }
 
+    fn start_round_to_week(self, week_start: Weekday) -> Interval<T> {
+        Interval {
+            start: self.start.round_to_week(week_start),
+            grain: Grain::Week,
+            end: None,
+        }
+    }
+
     fn interval_round_to(self, g: Grain) -> Interval<T> {
         Interval {
             start: self.start.round_to(g),
@@ -761,4 +813,58 @@ mod tests {
         };
         assert_eq!(5 * 86400, interval.seconds());
     }
+
+    fn paris() -> Tz {
+        Tz::iana(chrono_tz::Europe::Paris)
+    }
+
+    fn new_york() -> Tz {
+        Tz::iana(chrono_tz::America::New_York)
+    }
+
+    #[test]
+    fn add_days_across_dst_keeps_wall_clock() {
+        let before_spring = Moment(paris().ymd(2017, 03, 25).and_hms(9, 0, 0));
+        let after_spring = before_spring + PeriodComp::days(1);
+        assert_eq!(Moment(paris().ymd(2017, 03, 26).and_hms(9, 0, 0)), after_spring);
+        assert_eq!(9, after_spring.hour());
+        assert_eq!(23 * 3600, after_spring.signed_duration_since(before_spring.0).num_seconds());
+
+        let before_fall = Moment(new_york().ymd(2017, 11, 01).and_hms(18, 30, 0));
+        let after_fall = before_fall + PeriodComp::weeks(1);
+        assert_eq!(Moment(new_york().ymd(2017, 11, 08).and_hms(18, 30, 0)), after_fall);
+        assert_eq!(before_fall, after_fall - PeriodComp::weeks(1));
+    }
+
+    #[test]
+    fn add_hours_across_dst_is_elapsed_time() {
+        let moment = Moment(paris().ymd(2017, 03, 26).and_hms(1, 0, 0)) + PeriodComp::hours(3);
+        assert_eq!(Moment(paris().ymd(2017, 03, 26).and_hms(5, 0, 0)), moment);
+        let moment = Moment(paris().ymd(2017, 10, 29).and_hms(1, 0, 0)) + PeriodComp::hours(3);
+        assert_eq!(Moment(paris().ymd(2017, 10, 29).and_hms(3, 0, 0)), moment);
+    }
+
+    #[test]
+    fn add_into_dst_gap() {
+        let moment = Moment(paris().ymd(2017, 02, 26).and_hms(2, 30, 0)) + PeriodComp::months(1);
+        assert_eq!((3, 26, 3, 30), (moment.month(), moment.day(), moment.hour(), moment.minute()));
+
+        let tz = paris().with_dst_policy(DstPolicy::Earliest);
+        let moment = Moment(tz.ymd(2017, 03, 25).and_hms(2, 30, 0)) + PeriodComp::days(1);
+        assert_eq!((26, 3, 0), (moment.day(), moment.hour(), moment.minute()));
+    }
+
+    #[test]
+    fn round_in_dst_fold() {
+        let latest = paris().with_dst_policy(DstPolicy::Latest);
+        let moment = Moment(latest.ymd(2017, 10, 29).and_hms(2, 40, 0));
+        assert_eq!(
+            Moment(latest.ymd(2017, 10, 29).and_hms(2, 0, 0)),
+            moment.round_to(Grain::Hour)
+        );
+        assert_eq!(
+            Moment(paris().ymd(2017, 10, 29).and_hms(0, 0, 0)),
+            moment.round_to(Grain::Day)
+        );
+    }
 }
@@ -0,0 +1,347 @@
+use chrono::{
+    DateTime, Duration, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, Offset,
+    TimeZone,
+};
+use chrono_tz::Tz as IanaTz;
+use std::fmt;
+use std::str::FromStr;
+
+/// Zone in which a moment is expressed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Zone {
+    Local,
+    Fixed(FixedOffset),
+    Iana(IanaTz),
+}
+
+/// How local times that are skipped (gap) or repeated (fold) by a daylight saving time
+/// transition are resolved.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DstPolicy {
+    /// A repeated local time resolves to its earliest occurrence and a skipped local time is
+    /// shifted forward by the length of the gap, e.g. 2:30 becomes 3:30 when the clock jumps
+    /// from 2:00 to 3:00.
+    ShiftForward,
+    /// A repeated local time resolves to its earliest occurrence and a skipped local time
+    /// resolves to the end of the gap.
+    Earliest,
+    /// A repeated local time resolves to its latest occurrence and a skipped local time
+    /// resolves to the end of the gap.
+    Latest,
+}
+
+impl Default for DstPolicy {
+    fn default() -> DstPolicy {
+        DstPolicy::ShiftForward
+    }
+}
+
+/// Time zone used to resolve a moment, chosen at runtime.
+///
+/// It can be the timezone of the host, a fixed offset from UTC or an IANA timezone
+/// (e.g. `Asia/Tokyo`), so one process can resolve moments for users located in different
+/// timezones. The `DstPolicy` defines how local times that don't map to exactly one moment are
+/// resolved.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Tz {
+    zone: Zone,
+    dst_policy: DstPolicy,
+}
+
+impl Tz {
+    pub fn local() -> Tz {
+        Tz::from(Zone::Local)
+    }
+
+    pub fn fixed(offset: FixedOffset) -> Tz {
+        Tz::from(Zone::Fixed(offset))
+    }
+
+    pub fn iana(tz: IanaTz) -> Tz {
+        Tz::from(Zone::Iana(tz))
+    }
+
+    pub fn with_dst_policy(self, dst_policy: DstPolicy) -> Tz {
+        Tz { dst_policy, ..self }
+    }
+
+    /// Zone in which the moments are expressed.
+    pub fn zone(&self) -> Zone {
+        self.zone
+    }
+
+    /// Policy resolving the local times skipped or repeated by a daylight saving time transition.
+    pub fn dst_policy(&self) -> DstPolicy {
+        self.dst_policy
+    }
+}
+
+impl Default for Tz {
+    fn default() -> Tz {
+        Tz::local()
+    }
+}
+
+impl From<Zone> for Tz {
+    fn from(zone: Zone) -> Tz {
+        Tz {
+            zone,
+            dst_policy: DstPolicy::default(),
+        }
+    }
+}
+
+impl From<Local> for Tz {
+    fn from(_: Local) -> Tz {
+        Tz::local()
+    }
+}
+
+impl From<FixedOffset> for Tz {
+    fn from(offset: FixedOffset) -> Tz {
+        Tz::fixed(offset)
+    }
+}
+
+impl From<IanaTz> for Tz {
+    fn from(tz: IanaTz) -> Tz {
+        Tz::iana(tz)
+    }
+}
+
+impl FromStr for Tz {
+    type Err = String;
+
+    /// Parses `local`, a fixed offset such as `+09:00` or `-0530`, or an IANA timezone name.
+    fn from_str(s: &str) -> Result<Tz, Self::Err> {
+        if s.eq_ignore_ascii_case("local") {
+            return Ok(Tz::local());
+        }
+        if s.starts_with('+') || s.starts_with('-') {
+            return parse_fixed_offset(s)
+                .map(Tz::fixed)
+                .ok_or_else(|| format!("Invalid offset {}", s));
+        }
+        IanaTz::from_str(s)
+            .map(Tz::iana)
+            .map_err(|_| format!("Unknown timezone {}", s))
+    }
+}
+
+fn parse_fixed_offset(s: &str) -> Option<FixedOffset> {
+    let sign = if s.starts_with('-') { -1 } else { 1 };
+    let digits: String = s[1..].chars().filter(|c| *c != ':').collect();
+    if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let hours: i32 = digits[0..2].parse().ok()?;
+    let minutes: i32 = digits[2..4].parse().ok()?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Offset of a moment expressed in a `Tz`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct TzOffset {
+    tz: Tz,
+    fixed: FixedOffset,
+}
+
+impl TzOffset {
+    fn new(tz: Tz, fixed: FixedOffset) -> TzOffset {
+        TzOffset { tz, fixed }
+    }
+}
+
+impl Offset for TzOffset {
+    fn fix(&self) -> FixedOffset {
+        self.fixed
+    }
+}
+
+impl fmt::Debug for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.fixed)
+    }
+}
+
+impl fmt::Display for TzOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.fixed)
+    }
+}
+
+impl Tz {
+    fn pick<O>(&self, result: LocalResult<O>) -> Option<O> {
+        match self.dst_policy {
+            DstPolicy::Latest => result.latest(),
+            DstPolicy::Earliest | DstPolicy::ShiftForward => result.earliest(),
+        }
+    }
+
+    /// Offset to apply to a local time falling into a gap. The offset in effect before the gap
+    /// shifts the moment forward, the offset between the local time and the transition moment
+    /// moves it to the end of the gap.
+    fn resolve_gap<Z: TimeZone>(&self, tz: &Z, local: &NaiveDateTime) -> FixedOffset {
+        let before = tz
+            .offset_from_utc_datetime(&(*local - Duration::days(1)))
+            .fix();
+        if self.dst_policy == DstPolicy::ShiftForward {
+            return before;
+        }
+        // The transition happened between these two utc moments, search for it.
+        let mut low = (*local - before).timestamp();
+        let after = tz.offset_from_utc_datetime(&(*local - before)).fix();
+        let mut high = low;
+        low -= (after.local_minus_utc() - before.local_minus_utc()) as i64;
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            let offset = tz
+                .offset_from_utc_datetime(&NaiveDateTime::from_timestamp(mid, 0))
+                .fix();
+            if offset == before {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        let transition = NaiveDateTime::from_timestamp(high, 0);
+        FixedOffset::east((*local - transition).num_seconds() as i32)
+    }
+
+    fn resolve_local(&self, local: &NaiveDateTime) -> FixedOffset {
+        match self.zone {
+            Zone::Local => match self.pick(Local.offset_from_local_datetime(local)) {
+                Some(offset) => offset,
+                None => self.resolve_gap(&Local, local),
+            },
+            Zone::Fixed(offset) => offset,
+            Zone::Iana(tz) => match self.pick(tz.offset_from_local_datetime(local)) {
+                Some(offset) => offset.fix(),
+                None => self.resolve_gap(&tz, local),
+            },
+        }
+    }
+
+    fn resolve_utc(&self, utc: &NaiveDateTime) -> FixedOffset {
+        match self.zone {
+            Zone::Local => Local.offset_from_utc_datetime(utc),
+            Zone::Fixed(offset) => offset,
+            Zone::Iana(tz) => tz.offset_from_utc_datetime(utc).fix(),
+        }
+    }
+}
+
+impl TimeZone for Tz {
+    type Offset = TzOffset;
+
+    fn from_offset(offset: &TzOffset) -> Tz {
+        offset.tz
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<TzOffset> {
+        self.offset_from_local_datetime(&local.and_hms(0, 0, 0))
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<TzOffset> {
+        LocalResult::Single(TzOffset::new(*self, self.resolve_local(local)))
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> TzOffset {
+        self.offset_from_utc_datetime(&utc.and_hms(12, 0, 0))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> TzOffset {
+        TzOffset::new(*self, self.resolve_utc(utc))
+    }
+}
+
+/// Builds the moment corresponding to the given local time. Local times which are skipped or
+/// repeated by a daylight saving time transition are resolved by the timezone, the offset of the
+/// result is then the one actually in effect at this moment.
+pub(crate) fn from_local<T: TimeZone>(tz: &T, local: &NaiveDateTime) -> DateTime<T> {
+    let datetime = match tz.from_local_datetime(local) {
+        LocalResult::Single(datetime) => datetime,
+        LocalResult::Ambiguous(earliest, _) => earliest,
+        LocalResult::None => {
+            let before = tz
+                .offset_from_utc_datetime(&(*local - Duration::days(1)))
+                .fix();
+            tz.from_utc_datetime(&(*local - before))
+        }
+    };
+    datetime.with_timezone(tz)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, Timelike};
+
+    fn paris() -> Tz {
+        Tz::iana(chrono_tz::Europe::Paris)
+    }
+
+    #[test]
+    fn test_parse_tz() {
+        assert_eq!(Ok(Tz::local()), Tz::from_str("local"));
+        assert_eq!(
+            Ok(Tz::fixed(FixedOffset::east(9 * 3600))),
+            Tz::from_str("+09:00")
+        );
+        assert_eq!(
+            Ok(Tz::fixed(FixedOffset::west(5 * 3600 + 30 * 60))),
+            Tz::from_str("-0530")
+        );
+        assert_eq!(
+            Ok(Tz::iana(chrono_tz::Asia::Tokyo)),
+            Tz::from_str("Asia/Tokyo")
+        );
+        assert!(Tz::from_str("+9").is_err());
+        assert!(Tz::from_str("Mars/Olympus_Mons").is_err());
+    }
+
+    #[test]
+    fn test_iana_offset() {
+        let tz = paris();
+        let winter = tz.ymd(2017, 1, 10).and_hms(9, 0, 0);
+        let summer = tz.ymd(2017, 7, 10).and_hms(9, 0, 0);
+        assert_eq!(3600, winter.offset().fix().local_minus_utc());
+        assert_eq!(2 * 3600, summer.offset().fix().local_minus_utc());
+        assert_eq!(9, summer.hour());
+    }
+
+    #[test]
+    fn test_gap_shift_forward() {
+        let tz = paris();
+        let moment = from_local(&tz, &NaiveDate::from_ymd(2017, 3, 26).and_hms(2, 30, 0));
+        assert_eq!((26, 3, 30), (moment.day(), moment.hour(), moment.minute()));
+        assert_eq!(2 * 3600, moment.offset().fix().local_minus_utc());
+    }
+
+    #[test]
+    fn test_gap_end_of_gap() {
+        for policy in &[DstPolicy::Earliest, DstPolicy::Latest] {
+            let tz = paris().with_dst_policy(*policy);
+            let moment = from_local(&tz, &NaiveDate::from_ymd(2017, 3, 26).and_hms(2, 30, 0));
+            assert_eq!((26, 3, 0), (moment.day(), moment.hour(), moment.minute()));
+        }
+        let tz = Tz::iana(chrono_tz::America::New_York).with_dst_policy(DstPolicy::Earliest);
+        let moment = from_local(&tz, &NaiveDate::from_ymd(2017, 3, 12).and_hms(2, 15, 0));
+        assert_eq!((12, 3, 0), (moment.day(), moment.hour(), moment.minute()));
+    }
+
+    #[test]
+    fn test_fold() {
+        let local = NaiveDate::from_ymd(2017, 10, 29).and_hms(2, 30, 0);
+        let earliest = from_local(&paris(), &local);
+        assert_eq!(2 * 3600, earliest.offset().fix().local_minus_utc());
+        let earliest = from_local(&paris().with_dst_policy(DstPolicy::Earliest), &local);
+        assert_eq!(2 * 3600, earliest.offset().fix().local_minus_utc());
+        let latest = from_local(&paris().with_dst_policy(DstPolicy::Latest), &local);
+        assert_eq!(3600, latest.offset().fix().local_minus_utc());
+        assert_eq!(3600, latest.signed_duration_since(earliest).num_seconds());
+    }
+}
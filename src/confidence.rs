@@ -0,0 +1,229 @@
+use crate::tagger::CandidateTagger;
+use crate::RawParser;
+use grammar::Lang;
+use rustling::{ParserMatch, RustlingResult};
+use rustling_ontology_values::{OutputKind, ResolverContext};
+use serde::{Deserialize, Serialize};
+
+const NUM_FEATURES: usize = 5;
+
+/// Maps the features of a match to a confidence in [0, 1] with a logistic regression.
+///
+/// The raw `probalog` of a match is the log-probability of its parsing tree, which depends on the
+/// size of the tree and on the dimension. The calibration combines it with the span and the
+/// shape of the tree so that the resulting confidence can be compared across kinds and inputs.
+/// The features are standardized with the means and scales of the samples it was fitted on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Calibration {
+    pub means: [f32; NUM_FEATURES],
+    pub scales: [f32; NUM_FEATURES],
+    pub weights: [f32; NUM_FEATURES],
+    pub bias: f32,
+}
+
+impl Calibration {
+    /// Fits a calibration on the training examples of the language: matches covering a whole
+    /// example with the expected value are positive samples, the other selected matches are
+    /// negative ones.
+    pub fn fit_on_examples(parser: &RawParser, lang: Lang) -> RustlingResult<Calibration> {
+        let context = ResolverContext::default();
+        let all_kinds = OutputKind::all();
+        let tagger = CandidateTagger {
+            output_kind_filter: &all_kinds,
+            context: &context,
+            resolve_all_candidates: false,
+        };
+        let mut samples = vec![];
+        for example in grammar::examples(lang) {
+            for candidate in parser.candidates(example.text, &tagger)? {
+                if !candidate.tagged {
+                    continue;
+                }
+                let is_whole_input = candidate.match_.byte_range.0 == 0
+                    && candidate.match_.byte_range.1 == example.text.len();
+                let is_correct = is_whole_input && example.predicate.check(&candidate.node);
+                samples.push((features(&candidate.match_), is_correct));
+            }
+        }
+        Ok(Calibration::fit(&samples))
+    }
+
+    /// Fits the logistic regression on labelled feature vectors, standardized beforehand, with
+    /// Newton's method on the L2 regularized log-loss. Without samples every match gets a
+    /// confidence of 0.5.
+    pub fn fit(samples: &[([f32; NUM_FEATURES], bool)]) -> Calibration {
+        let mut means = [0.0; NUM_FEATURES];
+        let mut scales = [1.0; NUM_FEATURES];
+        if !samples.is_empty() {
+            let n = samples.len() as f32;
+            for i in 0..NUM_FEATURES {
+                means[i] = samples.iter().map(|(features, _)| features[i]).sum::<f32>() / n;
+                let variance = samples
+                    .iter()
+                    .map(|(features, _)| (features[i] - means[i]).powi(2))
+                    .sum::<f32>()
+                    / n;
+                // A constant feature is left as is rather than divided by zero
+                if variance > 1e-12 {
+                    scales[i] = variance.sqrt();
+                }
+            }
+        }
+        let inputs = samples
+            .iter()
+            .map(|(features, label)| {
+                let mut x = [1.0; NUM_FEATURES + 1];
+                for i in 0..NUM_FEATURES {
+                    x[i] = ((features[i] - means[i]) / scales[i]) as f64;
+                }
+                (x, if *label { 1.0 } else { 0.0 })
+            })
+            .collect::<Vec<_>>();
+        // The last coefficient is the bias, which isn't regularized
+        let regularization = 1e-2;
+        let mut coefficients = [0.0f64; NUM_FEATURES + 1];
+        for _ in 0..100 {
+            let mut gradient = [0.0f64; NUM_FEATURES + 1];
+            let mut hessian = [[0.0f64; NUM_FEATURES + 1]; NUM_FEATURES + 1];
+            for i in 0..NUM_FEATURES {
+                gradient[i] = regularization * coefficients[i];
+                hessian[i][i] = regularization;
+            }
+            for (x, y) in &inputs {
+                let score = x.iter().zip(coefficients.iter()).map(|(x, c)| x * c).sum::<f64>();
+                let p = 1.0 / (1.0 + (-score).exp());
+                for i in 0..=NUM_FEATURES {
+                    gradient[i] += (p - y) * x[i];
+                    for j in 0..=NUM_FEATURES {
+                        hessian[i][j] += p * (1.0 - p) * x[i] * x[j];
+                    }
+                }
+            }
+            let step = match solve(hessian, gradient) {
+                Some(step) => step,
+                None => break,
+            };
+            for (c, s) in coefficients.iter_mut().zip(step.iter()) {
+                *c -= s;
+            }
+            if step.iter().all(|s| s.abs() < 1e-6) {
+                break;
+            }
+        }
+        let mut weights = [0.0; NUM_FEATURES];
+        for i in 0..NUM_FEATURES {
+            weights[i] = coefficients[i] as f32;
+        }
+        Calibration {
+            means,
+            scales,
+            weights,
+            bias: coefficients[NUM_FEATURES] as f32,
+        }
+    }
+
+    /// Confidence in [0, 1] that the match is correct.
+    pub fn confidence<V: Clone>(&self, parser_match: &ParserMatch<V>) -> f32 {
+        self.predict(&features(parser_match))
+    }
+
+    fn predict(&self, features: &[f32; NUM_FEATURES]) -> f32 {
+        let score = self
+            .weights
+            .iter()
+            .zip(features.iter())
+            .zip(self.means.iter().zip(self.scales.iter()))
+            .map(|((weight, feature), (mean, scale))| weight * (feature - mean) / scale)
+            .sum::<f32>()
+            + self.bias;
+        1.0 / (1.0 + (-score).exp())
+    }
+}
+
+/// Solves the linear system with a Gaussian elimination, `None` if it is singular.
+fn solve(
+    mut matrix: [[f64; NUM_FEATURES + 1]; NUM_FEATURES + 1],
+    mut vector: [f64; NUM_FEATURES + 1],
+) -> Option<[f64; NUM_FEATURES + 1]> {
+    let size = NUM_FEATURES + 1;
+    for col in 0..size {
+        let pivot = (col..size).max_by(|&a, &b| {
+            matrix[a][col]
+                .abs()
+                .partial_cmp(&matrix[b][col].abs())
+                .unwrap_or(::std::cmp::Ordering::Equal)
+        })?;
+        if matrix[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        matrix.swap(col, pivot);
+        vector.swap(col, pivot);
+        let pivot_row = matrix[col];
+        for row in col + 1..size {
+            let factor = matrix[row][col] / pivot_row[col];
+            for (value, pivot_value) in matrix[row][col..].iter_mut().zip(pivot_row[col..].iter()) {
+                *value -= factor * pivot_value;
+            }
+            vector[row] -= factor * vector[col];
+        }
+    }
+    let mut solution = [0.0; NUM_FEATURES + 1];
+    for row in (0..size).rev() {
+        let rest = (row + 1..size).map(|k| matrix[row][k] * solution[k]).sum::<f64>();
+        solution[row] = (vector[row] - rest) / matrix[row][row];
+    }
+    Some(solution)
+}
+
+fn features<V: Clone>(parser_match: &ParserMatch<V>) -> [f32; NUM_FEATURES] {
+    let num_nodes = ::std::cmp::max(parser_match.parsing_tree_num_nodes, 1) as f32;
+    let num_chars = (parser_match.char_range.1 - parser_match.char_range.0) as f32;
+    [
+        parser_match.probalog,
+        parser_match.probalog / num_nodes,
+        (1.0 + num_chars).ln(),
+        (1.0 + parser_match.parsing_tree_height as f32).ln(),
+        if parser_match.latent { 1.0 } else { 0.0 },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_separates_samples() {
+        let good = [-0.5, -0.1, 2.0, 1.0, 0.0];
+        let bad = [-8.0, -2.0, 1.0, 1.0, 1.0];
+        let samples = vec![(good, true), (good, true), (bad, false), (bad, false)];
+        let calibration = Calibration::fit(&samples);
+        assert!(calibration.predict(&good) > 0.8);
+        assert!(calibration.predict(&bad) < 0.2);
+    }
+
+    #[test]
+    fn test_fit_is_scale_invariant() {
+        let samples = (0..20)
+            .map(|i| {
+                let probalog = -(i as f32);
+                ([probalog, probalog / 4.0, 2.0, 1.0, 0.0], i < 8)
+            })
+            .collect::<Vec<_>>();
+        let scaled = samples
+            .iter()
+            .map(|&(features, label)| {
+                let mut features = features;
+                features[0] *= 100.0;
+                (features, label)
+            })
+            .collect::<Vec<_>>();
+        let calibration = Calibration::fit(&samples);
+        let scaled_calibration = Calibration::fit(&scaled);
+        for (&(features, _), &(scaled_features, _)) in samples.iter().zip(scaled.iter()) {
+            let confidence = calibration.predict(&features);
+            assert!((confidence - scaled_calibration.predict(&scaled_features)).abs() < 1e-3);
+        }
+        assert!(calibration.predict(&samples[0].0) > 0.8);
+        assert!(calibration.predict(&samples[19].0) < 0.2);
+    }
+}
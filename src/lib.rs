@@ -18,6 +18,9 @@
 //!     assert_eq!(21, int.0);
 //! }
 //! ```
+#[macro_use]
+extern crate failure;
+extern crate regex;
 extern crate rmp_serde;
 extern crate rustling;
 extern crate rustling_ontology_grammar as grammar;
@@ -25,30 +28,98 @@ extern crate rustling_ontology_moment;
 extern crate rustling_ontology_values;
 extern crate serde;
 
-pub use grammar::{dims, Lang};
+pub use grammar::{dims, examples_roman_numerals, rules_roman_numerals, Lang};
 pub use rustling::RustlingResult;
+pub use rustling::RuleSetBuilder;
+pub use rustling::train::Example;
 pub use rustling::{AttemptInto, ParsedNode, ParserMatch, ParsingAnalysis, Range, Sym, Value};
 pub use rustling_ontology_moment::Grain;
-pub use rustling_ontology_moment::{Interval, Local, Moment, TimeZone};
+pub use rustling_ontology_moment::{DstPolicy, FixedOffset, Interval, Local, Moment, NaiveDate, TimeZone, Tz, Weekday};
 pub use rustling_ontology_values::dimension;
 pub use rustling_ontology_values::output;
 pub use rustling_ontology_values::output::{Output, OutputKind};
-pub use rustling_ontology_values::{IdentityContext, ParsingContext, ResolverContext};
+pub use rustling_ontology_values::dimension::DateOrder;
+pub use rustling_ontology_values::{
+    Clock, IdentityContext, Locale, Occurrences, ParsingContext, ResolverContext,
+};
 
+use std::fs::File;
+use std::io::{BufReader, Read, Write};
+use std::path::Path;
+
+mod confidence;
 mod mapper;
+mod named_dates;
 mod parser;
 mod tagger;
 
+pub use confidence::Calibration;
+pub use named_dates::{NamedDate, NamedDateRegistry, NamedDateRule};
 pub use tagger::CandidateTagger;
 
 // Rustling raw parser. Don't use directly
 #[doc(hidden)]
 pub type RawParser = rustling::Parser<dimension::Dimension, parser::Feat, parser::FeatureExtractor>;
 
-/// Main class to be use at runtime.
-pub struct Parser(RawParser);
+/// Main class to be use at runtime. It is `Send + Sync`, a single instance can be shared across
+/// threads behind an `Arc`.
+pub struct Parser {
+    raw: RawParser,
+    calibration: Option<Calibration>,
+}
+
+impl From<RawParser> for Parser {
+    fn from(raw: RawParser) -> Parser {
+        Parser {
+            raw,
+            calibration: None,
+        }
+    }
+}
 
 impl Parser {
+    /// Fits the calibration of the confidence scores on the training examples of the language.
+    pub fn calibrate(self, lang: Lang) -> RustlingResult<Parser> {
+        let calibration = Calibration::fit_on_examples(&self.raw, lang)?;
+        Ok(self.with_calibration(calibration))
+    }
+
+    /// Uses a calibration computed beforehand, e.g. by `calibrate`.
+    pub fn with_calibration(self, calibration: Calibration) -> Parser {
+        Parser {
+            calibration: Some(calibration),
+            ..self
+        }
+    }
+
+    pub fn calibration(&self) -> Option<&Calibration> {
+        self.calibration.as_ref()
+    }
+
+    /// Confidence in [0, 1] that the match is correct, computed with the calibration of the parser,
+    /// `None` if the parser isn't calibrated.
+    pub fn confidence(&self, parser_match: &ParserMatch<Output>) -> Option<f32> {
+        self.calibration.as_ref().map(|calibration| calibration.confidence(parser_match))
+    }
+
+    /// Same as `parse_with_kind_order`, each match comes with its confidence, `None` if the
+    /// parser isn't calibrated.
+    pub fn parse_with_confidence(
+        &self,
+        input: &str,
+        context: &ResolverContext,
+        order: &[OutputKind],
+    ) -> RustlingResult<Vec<(ParserMatch<Output>, Option<f32>)>> {
+        Ok(self
+            .parse_with_kind_order(input, context, order)?
+            .into_iter()
+            .map(|m| {
+                let confidence = self.confidence(&m);
+                (m, confidence)
+            })
+            .collect())
+    }
+
     pub fn parse_with_kind_order(
         &self,
         input: &str,
@@ -60,10 +131,12 @@ impl Parser {
             context,
             resolve_all_candidates: false,
         };
-        Ok(self
-            .0
+        let named_dates = named_date_matches(input, context, order);
+        let mut matches = self
+            .raw
             .parse(input, &tagger)?
             .into_iter()
+            .filter(|m| named_dates.iter().all(|n| n.byte_range.is_disjoint(&m.byte_range)))
             .filter_map(|m| {
                 if let Some(v) = m.value {
                     Some(ParserMatch {
@@ -79,7 +152,10 @@ impl Parser {
                     None
                 }
             })
-            .collect())
+            .chain(named_dates.iter().cloned())
+            .collect::<Vec<_>>();
+        matches.sort_by_key(|m| m.byte_range.0);
+        Ok(matches)
     }
 
     pub fn parse(
@@ -91,6 +167,159 @@ impl Parser {
         self.parse_with_kind_order(input, context, &all_output)
     }
 
+    /// Returns, for each span selected by `parse_with_kind_order`, up to `n` alternative readings
+    /// ranked from the most to the least likely. The first alternative is the one returned by
+    /// `parse_with_kind_order`, the others are the alternative resolutions of the same candidate
+    /// and the overlapping candidates of any kind in the filter, by decreasing probalog. A
+    /// candidate overlapping several selected spans is only an alternative of the one it overlaps
+    /// the most.
+    pub fn parse_nbest_with_kind_order(
+        &self,
+        input: &str,
+        context: &ResolverContext,
+        order: &[OutputKind],
+        n: usize,
+    ) -> RustlingResult<Vec<NBestMatch>> {
+        let tagger = CandidateTagger {
+            output_kind_filter: order,
+            context,
+            resolve_all_candidates: true,
+        };
+        let named_dates = named_date_matches(input, context, order);
+        let candidates = self.raw.candidates(input, &tagger)?;
+        let selected = candidates
+            .iter()
+            .filter(|candidate| candidate.tagged)
+            .filter(|candidate| {
+                named_dates
+                    .iter()
+                    .all(|n| n.byte_range.is_disjoint(&candidate.match_.byte_range))
+            })
+            .collect::<Vec<_>>();
+        let overlap = |a: &Range, b: &Range| {
+            ::std::cmp::min(a.1, b.1).saturating_sub(::std::cmp::max(a.0, b.0))
+        };
+        let mut competitors = vec![vec![]; selected.len()];
+        for candidate in candidates.iter().filter(|c| !c.tagged) {
+            let span = selected
+                .iter()
+                .enumerate()
+                .filter(|&(_, s)| !s.match_.byte_range.is_disjoint(&candidate.match_.byte_range))
+                .max_by_key(|&(ix, s)| {
+                    let shared = overlap(&s.match_.byte_range, &candidate.match_.byte_range);
+                    // Ties go to the first selected span
+                    (shared, ::std::cmp::Reverse(ix))
+                });
+            if let Some((ix, _)) = span {
+                competitors[ix].push(candidate);
+            }
+        }
+        let mut matches = selected
+            .iter()
+            .zip(competitors.into_iter())
+            .map(|(selected, competitors)| {
+                let mut alternatives: Vec<ParserMatch<Output>> = vec![];
+                for candidate in Some(*selected).into_iter().chain(competitors) {
+                    for value in context.resolve_alternatives(&candidate.node.value) {
+                        let is_duplicate = alternatives.iter().any(|a| {
+                            a.byte_range.0 == candidate.match_.byte_range.0
+                                && a.byte_range.1 == candidate.match_.byte_range.1
+                                && a.value == value
+                        });
+                        if !is_duplicate {
+                            alternatives.push(ParserMatch {
+                                byte_range: candidate.match_.byte_range,
+                                char_range: candidate.match_.char_range,
+                                parsing_tree_height: candidate.match_.parsing_tree_height,
+                                parsing_tree_num_nodes: candidate.match_.parsing_tree_num_nodes,
+                                value,
+                                probalog: candidate.match_.probalog,
+                                latent: candidate.match_.latent,
+                            });
+                        }
+                    }
+                }
+                // The reading returned by `parse_with_kind_order` stays first
+                if alternatives.len() > 1 {
+                    alternatives[1..].sort_by(|a, b| {
+                        b.probalog.partial_cmp(&a.probalog).unwrap_or(::std::cmp::Ordering::Equal)
+                    });
+                }
+                alternatives.truncate(n);
+                NBestMatch {
+                    byte_range: selected.match_.byte_range,
+                    char_range: selected.match_.char_range,
+                    alternatives,
+                }
+            })
+            .chain(named_dates.iter().cloned().map(|named_date| NBestMatch {
+                byte_range: named_date.byte_range,
+                char_range: named_date.char_range,
+                alternatives: vec![named_date],
+            }))
+            .collect::<Vec<_>>();
+        matches.sort_by_key(|m| m.byte_range.0);
+        Ok(matches)
+    }
+
+    pub fn parse_nbest(
+        &self,
+        input: &str,
+        context: &ResolverContext,
+        n: usize,
+    ) -> RustlingResult<Vec<NBestMatch>> {
+        let all_output = OutputKind::all();
+        self.parse_nbest_with_kind_order(input, context, &all_output, n)
+    }
+
+    /// Returns, for each datetime selected by `parse_with_kind_order`, its intervals overlapping
+    /// the window, at most `max_count` of them, e.g. every monday of "mondays in march".
+    pub fn occurrences(
+        &self,
+        input: &str,
+        context: &ResolverContext,
+        window: Interval<Tz>,
+        max_count: usize,
+    ) -> RustlingResult<Vec<OccurrencesMatch>> {
+        let order = [OutputKind::Datetime];
+        let tagger = CandidateTagger {
+            output_kind_filter: &order,
+            context,
+            resolve_all_candidates: false,
+        };
+        let named_dates = context.named_dates(input);
+        let mut matches = self
+            .raw
+            .candidates(input, &tagger)?
+            .into_iter()
+            .filter(|candidate| candidate.tagged)
+            .filter(|candidate| {
+                named_dates
+                    .iter()
+                    .all(|&(range, _)| range.is_disjoint(&candidate.match_.byte_range))
+            })
+            .filter_map(|candidate| match candidate.node.value {
+                dimension::Dimension::Datetime(ref datetime_value) => Some(OccurrencesMatch {
+                    byte_range: candidate.match_.byte_range,
+                    char_range: candidate.match_.char_range,
+                    occurrences: context
+                        .occurrences(datetime_value, window, max_count)
+                        .collect(),
+                }),
+                _ => None,
+            })
+            .chain(named_dates.iter().map(|&(range, datetime_value)| OccurrencesMatch {
+                byte_range: range,
+                char_range: char_range(input, range),
+                occurrences: context
+                    .occurrences(datetime_value, window, max_count)
+                    .collect(),
+            }))
+            .collect::<Vec<_>>();
+        matches.sort_by_key(|m| m.byte_range.0);
+        Ok(matches)
+    }
+
     pub fn analyse_with_kind_order(
         &self,
         examples: Vec<&str>,
@@ -102,7 +331,7 @@ impl Parser {
             context,
             resolve_all_candidates: false,
         };
-        self.0.analyse(examples, &tagger)
+        self.raw.analyse(examples, &tagger)
     }
 
     pub fn analyse(
@@ -115,51 +344,101 @@ impl Parser {
     }
 
     pub fn num_rules(&self) -> usize {
-        self.0.num_rules()
+        self.raw.num_rules()
     }
 
     pub fn num_text_patterns(&self) -> usize {
-        self.0.num_text_patterns()
+        self.raw.num_text_patterns()
     }
 }
 
-/// Obtain a parser for a given language.
+/// Matches of the dates named in the context, see `ResolverContext::with_named_dates`, when
+/// datetimes are in the filter.
+fn named_date_matches(
+    input: &str,
+    context: &ResolverContext,
+    order: &[OutputKind],
+) -> Vec<ParserMatch<Output>> {
+    context
+        .named_dates(input)
+        .into_iter()
+        .filter_map(|(range, datetime_value)| {
+            let value = dimension::Dimension::Datetime(datetime_value.clone());
+            if !order.iter().any(|kind| kind.match_dim(&value)) {
+                return None;
+            }
+            context.resolve(&value).map(|output| ParserMatch {
+                byte_range: range,
+                char_range: char_range(input, range),
+                parsing_tree_height: 1,
+                parsing_tree_num_nodes: 1,
+                value: output,
+                probalog: 0.0,
+                latent: false,
+            })
+        })
+        .collect()
+}
+
+/// Range in chars of a range in bytes of the input.
+fn char_range(input: &str, byte_range: Range) -> Range {
+    Range(
+        input[..byte_range.0].chars().count(),
+        input[..byte_range.1].chars().count(),
+    )
+}
+
+/// Alternative readings of a span of the input, the most likely first.
+#[derive(Debug, Clone)]
+pub struct NBestMatch {
+    pub byte_range: Range,
+    pub char_range: Range,
+    pub alternatives: Vec<ParserMatch<Output>>,
+}
+
+/// Intervals of a datetime span of the input within a window, in chronological order.
+#[derive(Debug, Clone)]
+pub struct OccurrencesMatch {
+    pub byte_range: Range,
+    pub char_range: Range,
+    pub occurrences: Vec<Interval<Tz>>,
+}
+
+/// Obtain a parser for a given language. The model of the language must be embedded with its
+/// `embed-*` cargo feature.
 pub fn build_parser(lang: Lang) -> RustlingResult<Parser> {
-    build_raw_parser(lang).map(crate::Parser)
+    build_raw_parser(lang).map(Parser::from)
+}
+
+/// Obtain a parser for a given language with a model loaded at runtime, e.g. from a byte slice
+/// or from a file written by `train_model`.
+pub fn build_parser_with_model<R: Read>(lang: Lang, model: R) -> RustlingResult<Parser> {
+    build_raw_parser_with_model(lang, model).map(Parser::from)
+}
+
+/// Obtain a parser for a given language with a model read from a file written by `train_model`.
+pub fn build_parser_from_path<P: AsRef<Path>>(lang: Lang, path: P) -> RustlingResult<Parser> {
+    let file = File::open(path)?;
+    build_parser_with_model(lang, BufReader::new(file))
 }
 
-/// Obtain a parser for a given language.
+/// Obtain a parser for a given language. The model of the language must be embedded with its
+/// `embed-*` cargo feature.
 pub fn build_raw_parser(lang: Lang) -> RustlingResult<RawParser> {
+    let model = embedded_model(lang).ok_or_else(|| {
+        format_err!(
+            "No model embedded for {}, enable the embed-{} feature or load a model at runtime",
+            lang.to_string(),
+            lang.to_string().to_lowercase()
+        )
+    })?;
+    build_raw_parser_with_model(lang, model)
+}
+
+/// Obtain a parser for a given language with a model loaded at runtime.
+pub fn build_raw_parser_with_model<R: Read>(lang: Lang, model: R) -> RustlingResult<RawParser> {
     let rules = grammar::rules(lang)?;
-    let model = match lang {
-        Lang::DE => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/de.rmp"))[..])
-        }
-        Lang::EN => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/en.rmp"))[..])
-        }
-        Lang::ES => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/es.rmp"))[..])
-        }
-        Lang::IT => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/it.rmp"))[..])
-        }
-        Lang::FR => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/fr.rmp"))[..])
-        }
-        Lang::PT => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/pt.rmp"))[..])
-        }
-        Lang::JA => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/ja.rmp"))[..])
-        }
-        Lang::KO => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/ko.rmp"))[..])
-        }
-        Lang::ZH => {
-            ::rmp_serde::decode::from_read(&include_bytes!(concat!(env!("OUT_DIR"), "/zh.rmp"))[..])
-        }
-    }?;
+    let model = ::rmp_serde::decode::from_read(model)?;
     Ok(crate::RawParser::new(
         rules,
         model,
@@ -167,18 +446,111 @@ pub fn build_raw_parser(lang: Lang) -> RustlingResult<RawParser> {
     ))
 }
 
+/// Model of the language embedded at build time, if its `embed-*` cargo feature is enabled.
+#[allow(unreachable_patterns)]
+fn embedded_model(lang: Lang) -> Option<&'static [u8]> {
+    match lang {
+        #[cfg(feature = "embed-de")]
+        Lang::DE => Some(&include_bytes!(concat!(env!("OUT_DIR"), "/de.rmp"))[..]),
+        #[cfg(feature = "embed-en")]
+        Lang::EN => Some(&include_bytes!(concat!(env!("OUT_DIR"), "/en.rmp"))[..]),
+        #[cfg(feature = "embed-es")]
+        Lang::ES => Some(&include_bytes!(concat!(env!("OUT_DIR"), "/es.rmp"))[..]),
+        #[cfg(feature = "embed-it")]
+        Lang::IT => Some(&include_bytes!(concat!(env!("OUT_DIR"), "/it.rmp"))[..]),
+        #[cfg(feature = "embed-fr")]
+        Lang::FR => Some(&include_bytes!(concat!(env!("OUT_DIR"), "/fr.rmp"))[..]),
+        #[cfg(feature = "embed-pt")]
+        Lang::PT => Some(&include_bytes!(concat!(env!("OUT_DIR"), "/pt.rmp"))[..]),
+        #[cfg(feature = "embed-ja")]
+        Lang::JA => Some(&include_bytes!(concat!(env!("OUT_DIR"), "/ja.rmp"))[..]),
+        #[cfg(feature = "embed-ko")]
+        Lang::KO => Some(&include_bytes!(concat!(env!("OUT_DIR"), "/ko.rmp"))[..]),
+        #[cfg(feature = "embed-zh")]
+        Lang::ZH => Some(&include_bytes!(concat!(env!("OUT_DIR"), "/zh.rmp"))[..]),
+        _ => None,
+    }
+}
+
+/// Trains the model of a language and writes it to be loaded with `build_parser_with_model`.
+pub fn train_model<W: Write>(lang: Lang, writer: &mut W) -> RustlingResult<()> {
+    let rules = grammar::rules(lang)?;
+    let examples = grammar::examples(lang);
+    let model = ::rustling::train::train(&rules, examples, crate::parser::FeatureExtractor())?;
+    ::rmp_serde::encode::write(writer, &model)?;
+    Ok(())
+}
+
+/// Trains the model of a language extended with custom rules and training examples, see
+/// `train_parser_with_rules`, and writes it to be loaded with `build_parser_with_rules`.
+pub fn train_model_with_rules<F, W>(
+    lang: Lang,
+    extension: F,
+    examples: Vec<Example<dimension::Dimension>>,
+    writer: &mut W,
+) -> RustlingResult<()>
+where
+    F: FnOnce(&mut RuleSetBuilder<dimension::Dimension>) -> RustlingResult<()>,
+    W: Write,
+{
+    let rules = grammar::rules_with(lang, extension)?;
+    let mut all_examples = grammar::examples(lang);
+    all_examples.extend(examples);
+    let model = ::rustling::train::train(&rules, all_examples, crate::parser::FeatureExtractor())?;
+    ::rmp_serde::encode::write(writer, &model)?;
+    Ok(())
+}
+
+/// Obtain a parser for a given language extended with custom rules, with a model written by
+/// `train_model_with_rules` with the same extension.
+pub fn build_parser_with_rules<F, R>(lang: Lang, extension: F, model: R) -> RustlingResult<Parser>
+where
+    F: FnOnce(&mut RuleSetBuilder<dimension::Dimension>) -> RustlingResult<()>,
+    R: Read,
+{
+    let rules = grammar::rules_with(lang, extension)?;
+    let model = ::rmp_serde::decode::from_read(model)?;
+    Ok(Parser::from(crate::RawParser::new(
+        rules,
+        model,
+        crate::parser::FeatureExtractor(),
+    )))
+}
+
 pub fn train_parser(lang: Lang) -> RustlingResult<Parser> {
     let rules = grammar::rules(lang)?;
     let examples = grammar::examples(lang);
     let model = ::rustling::train::train(&rules, examples, crate::parser::FeatureExtractor())?;
-    Ok(Parser(::rustling::Parser::new(
+    Ok(Parser::from(::rustling::Parser::new(
         rules,
         model,
         crate::parser::FeatureExtractor(),
     )))
 }
 
-#[cfg(test)]
+/// Trains a parser for a given language whose built-in rules are extended with the custom rules
+/// added by `extension`, e.g. domain specific vocabulary. The model is trained on the built-in
+/// examples of the language and on `examples`, which should cover the custom rules.
+pub fn train_parser_with_rules<F>(
+    lang: Lang,
+    extension: F,
+    examples: Vec<Example<dimension::Dimension>>,
+) -> RustlingResult<Parser>
+where
+    F: FnOnce(&mut RuleSetBuilder<dimension::Dimension>) -> RustlingResult<()>,
+{
+    let rules = grammar::rules_with(lang, extension)?;
+    let mut all_examples = grammar::examples(lang);
+    all_examples.extend(examples);
+    let model = ::rustling::train::train(&rules, all_examples, crate::parser::FeatureExtractor())?;
+    Ok(Parser::from(::rustling::Parser::new(
+        rules,
+        model,
+        crate::parser::FeatureExtractor(),
+    )))
+}
+
+#[cfg(all(test, feature = "embed-en"))]
 mod tests {
     use super::*;
 
@@ -194,6 +566,567 @@ mod tests {
         assert_eq!(1521082, int.0);
     }
 
+    #[test]
+    fn test_parser_shared_across_threads() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let parser = Arc::new(build_parser(Lang::EN).unwrap());
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let parser = parser.clone();
+                thread::spawn(move || {
+                    let ctx = ResolverContext::default();
+                    parser.parse("twenty-one", &ctx).unwrap()
+                })
+            })
+            .collect();
+        for handle in handles {
+            let result = handle.join().unwrap();
+            let int: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
+            assert_eq!(21, int.0);
+        }
+    }
+
+    #[cfg(feature = "output-serde")]
+    #[test]
+    fn test_serialize_output() {
+        let ctx = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Tz::fixed(FixedOffset::east(3600)).ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            Grain::Second,
+        ));
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser
+            .parse_with_kind_order("tomorrow", &ctx, &[OutputKind::Date])
+            .unwrap();
+        let json = ::serde_json::to_value(&result[0].value).unwrap();
+        assert_eq!(
+            ::serde_json::json!({
+                "version": 1,
+                "kind": "Date",
+                "value": {
+                    "moment": "2013-02-13T00:00:00+01:00",
+                    "grain": "day",
+                    "precision": "exact",
+                    "latent": false,
+                }
+            }),
+            json
+        );
+    }
+
+    #[test]
+    fn test_parse_nbest_twelve_hour_clock() {
+        let ctx = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            Grain::Second,
+        ));
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser
+            .parse_nbest_with_kind_order("at 8", &ctx, &[OutputKind::Time], 5)
+            .unwrap();
+        assert_eq!(1, result.len());
+        let moments = result[0]
+            .alternatives
+            .iter()
+            .map(|m| {
+                let datetime: output::DatetimeOutput = m.value.clone().attempt_into().unwrap();
+                datetime.moment
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(Moment(Tz::local().ymd(2013, 2, 12).and_hms(8, 0, 0)), moments[0]);
+        assert!(moments.contains(&Moment(Tz::local().ymd(2013, 2, 12).and_hms(20, 0, 0))));
+    }
+
+    #[test]
+    fn test_parse_nbest_ranking() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser.parse_nbest("book 3 rooms for 2 nights at 8 tomorrow", &ctx, 5).unwrap();
+        assert!(result.len() > 1);
+        for span in &result {
+            let ranked = &span.alternatives[1..];
+            assert!(ranked.windows(2).all(|w| w[0].probalog >= w[1].probalog));
+        }
+        let mut seen = vec![];
+        for alternative in result.iter().flat_map(|span| span.alternatives.iter()) {
+            let key = (alternative.byte_range.0, alternative.byte_range.1, alternative.value.clone());
+            assert!(!seen.contains(&key));
+            seen.push(key);
+        }
+    }
+
+    #[test]
+    fn test_locale_en() {
+        let ctx = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Tz::local().ymd(2013, 2, 12).and_hms(10, 0, 0)),
+            Grain::Second,
+        ));
+        let parser = build_parser(Lang::EN).unwrap();
+        let moment = |input: &str, ctx: &ResolverContext, kind: OutputKind| {
+            let result = parser.parse_with_kind_order(input, ctx, &[kind]).unwrap();
+            let datetime: output::DatetimeOutput = result[0].value.clone().attempt_into().unwrap();
+            datetime.moment
+        };
+        assert_eq!(
+            Moment(Tz::local().ymd(2013, 5, 6).and_hms(0, 0, 0)),
+            moment("05/06/2013", &ctx, OutputKind::Date)
+        );
+        assert_eq!(
+            Moment(Tz::local().ymd(2013, 2, 12).and_hms(20, 0, 0)),
+            moment("at 8", &ctx, OutputKind::Time)
+        );
+        let european = ctx.clone().with_locale(Locale::new(DateOrder::DayMonthYear, Clock::H24, Weekday::Mon));
+        assert_eq!(
+            Moment(Tz::local().ymd(2013, 6, 5).and_hms(0, 0, 0)),
+            moment("05/06/2013", &european, OutputKind::Date)
+        );
+        assert_eq!(
+            Moment(Tz::local().ymd(2013, 2, 13).and_hms(8, 0, 0)),
+            moment("at 8", &european, OutputKind::Time)
+        );
+        let year_first = ctx.with_locale(Locale::new(DateOrder::YearMonthDay, Clock::H24, Weekday::Mon));
+        assert_eq!(
+            Moment(Tz::local().ymd(2005, 6, 7).and_hms(0, 0, 0)),
+            moment("05/06/07", &year_first, OutputKind::Date)
+        );
+    }
+
+    #[cfg(feature = "embed-fr")]
+    #[test]
+    fn test_locale_fr() {
+        let ctx = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            Grain::Second,
+        ));
+        let parser = build_parser(Lang::FR).unwrap();
+        let moment = |input: &str, ctx: &ResolverContext| {
+            let result = parser.parse_with_kind_order(input, ctx, &[OutputKind::Date]).unwrap();
+            let datetime: output::DatetimeOutput = result[0].value.clone().attempt_into().unwrap();
+            datetime.moment
+        };
+        assert_eq!(Moment(Tz::local().ymd(2013, 6, 5).and_hms(0, 0, 0)), moment("05/06/2013", &ctx));
+        let us = ctx.with_locale(Locale::new(DateOrder::MonthDayYear, Clock::H12, Weekday::Sun));
+        assert_eq!(Moment(Tz::local().ymd(2013, 5, 6).and_hms(0, 0, 0)), moment("05/06/2013", &us));
+    }
+
+    #[test]
+    fn test_week_start_en() {
+        let ctx = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            Grain::Second,
+        ));
+        let parser = build_parser(Lang::EN).unwrap();
+        let moment = |input: &str, ctx: &ResolverContext| {
+            let result = parser.parse(input, ctx).unwrap();
+            let datetime: output::DatetimeOutput = result[0].value.clone().attempt_into().unwrap();
+            datetime.moment
+        };
+        assert_eq!(Moment(Tz::local().ymd(2013, 2, 18).and_hms(0, 0, 0)), moment("next week", &ctx));
+        let sunday_first = ctx.with_week_start(Weekday::Sun);
+        assert_eq!(Moment(Tz::local().ymd(2013, 2, 17).and_hms(0, 0, 0)), moment("next week", &sunday_first));
+        assert_eq!(Moment(Tz::local().ymd(2013, 10, 14).and_hms(0, 0, 0)), moment("week 42", &sunday_first));
+    }
+
+    #[test]
+    fn test_business_days_en() {
+        let ctx = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Tz::local().ymd(2013, 2, 15).and_hms(10, 0, 0)),
+            Grain::Second,
+        ))
+        .with_holidays(vec![NaiveDate::from_ymd(2013, 2, 18)])
+        .with_business_hours(8, 18)
+        .unwrap();
+        let parser = build_parser(Lang::EN).unwrap();
+        let moment = |input: &str| {
+            let result = parser.parse(input, &ctx).unwrap();
+            let datetime: output::DatetimeOutput = result[0].value.clone().attempt_into().unwrap();
+            datetime.moment
+        };
+        assert_eq!(Moment(Tz::local().ymd(2013, 2, 19).and_hms(0, 0, 0)), moment("next business day"));
+        assert_eq!(Moment(Tz::local().ymd(2013, 2, 21).and_hms(0, 0, 0)), moment("in 3 business days"));
+        assert_eq!(Moment(Tz::local().ymd(2013, 2, 15).and_hms(18, 0, 0)), moment("end of business today"));
+    }
+
+    #[test]
+    fn test_weekend_en() {
+        let ctx = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            Grain::Second,
+        ));
+        let parser = build_parser(Lang::EN).unwrap();
+        let moment = |input: &str, ctx: &ResolverContext| {
+            let result = parser.parse(input, ctx).unwrap();
+            let datetime: output::DatetimeIntervalOutput = result[0].value.clone().attempt_into().unwrap();
+            match datetime.interval_kind {
+                output::DatetimeIntervalKind::Between { start, .. } => start,
+                _ => panic!("{} is not a span", input),
+            }
+        };
+        assert_eq!(Moment(Tz::local().ymd(2013, 2, 15).and_hms(18, 0, 0)), moment("this weekend", &ctx));
+        let ctx = ctx.with_weekend(Weekday::Fri, Weekday::Sat);
+        assert_eq!(Moment(Tz::local().ymd(2013, 2, 14).and_hms(18, 0, 0)), moment("this weekend", &ctx));
+    }
+
+    #[test]
+    fn test_fiscal_year_en() {
+        let ctx = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            Grain::Second,
+        ))
+        .with_fiscal_year_start(10)
+        .unwrap();
+        let parser = build_parser(Lang::EN).unwrap();
+        let moment = |input: &str| {
+            let result = parser.parse(input, &ctx).unwrap();
+            let datetime: output::DatetimeOutput = result[0].value.clone().attempt_into().unwrap();
+            datetime.moment
+        };
+        assert_eq!(Moment(Tz::local().ymd(2013, 10, 1).and_hms(0, 0, 0)), moment("FY2014"));
+        assert_eq!(Moment(Tz::local().ymd(2014, 4, 1).and_hms(0, 0, 0)), moment("Q3 FY14"));
+        assert_eq!(Moment(Tz::local().ymd(2013, 4, 1).and_hms(0, 0, 0)), moment("next fiscal quarter"));
+        assert_eq!(Moment(Tz::local().ymd(2012, 10, 1).and_hms(0, 0, 0)), moment("this fiscal year"));
+    }
+
+    #[test]
+    fn test_calibrated_confidence() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap().calibrate(Lang::EN).unwrap();
+        let result = parser
+            .parse_with_confidence("twenty-one", &ctx, &[OutputKind::Number])
+            .unwrap();
+        let confidence = result[0].1.unwrap();
+        assert!(0.0 <= confidence && confidence <= 1.0);
+        assert!(confidence > 0.5);
+        let uncalibrated = build_parser(Lang::EN).unwrap();
+        let result = uncalibrated
+            .parse_with_confidence("twenty-one", &ctx, &[OutputKind::Number])
+            .unwrap();
+        assert_eq!(None, result[0].1);
+    }
+
+    #[test]
+    fn test_build_parser_with_model() {
+        let ctx = ResolverContext::default();
+        let model = embedded_model(Lang::EN).unwrap();
+        let parser = build_parser_with_model(Lang::EN, model).unwrap();
+        let result = parser
+            .parse_with_kind_order("twenty-one", &ctx, &[OutputKind::Number])
+            .unwrap();
+        let int: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(21, int.0);
+    }
+
+    #[test]
+    fn test_distance_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser
+            .parse_with_kind_order("about five kilometers", &ctx, &[OutputKind::Distance])
+            .unwrap();
+        let distance: output::DistanceOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(5.0, distance.value);
+        assert_eq!(Some("km"), distance.unit);
+        assert_eq!(dimension::Precision::Approximate, distance.precision);
+        assert_eq!(Some(5000.0), distance.value_in("m"));
+        let result = parser
+            .parse_with_kind_order("about 5 km", &ctx, &[OutputKind::Distance])
+            .unwrap();
+        let distance: output::DistanceOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((5.0, Some("km")), (distance.value, distance.unit));
+        assert_eq!(dimension::Precision::Approximate, distance.precision);
+    }
+
+    #[test]
+    fn test_volume_and_mass_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser
+            .parse_with_kind_order("2 litres of milk", &ctx, &[OutputKind::Volume])
+            .unwrap();
+        let volume: output::VolumeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((2.0, Some("l")), (volume.value, volume.unit));
+        let result = parser
+            .parse_with_kind_order("500 grammes of flour", &ctx, &[OutputKind::Mass])
+            .unwrap();
+        let mass: output::MassOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((500.0, Some("g")), (mass.value, mass.unit));
+        assert!(!result[0].latent);
+        let result = parser
+            .parse_with_kind_order("2 l", &ctx, &[OutputKind::Volume])
+            .unwrap();
+        assert!(result[0].latent);
+        let result = parser
+            .parse_with_kind_order("about 5 kg", &ctx, &[OutputKind::Mass])
+            .unwrap();
+        let mass: output::MassOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((5.0, Some("kg")), (mass.value, mass.unit));
+        assert_eq!(dimension::Precision::Approximate, mass.precision);
+        assert_eq!(Some(5000.0), mass.value_in("g"));
+        assert!(!result[0].latent);
+        let result = parser
+            .parse_with_kind_order("5 g", &ctx, &[OutputKind::Mass])
+            .unwrap();
+        assert!(result[0].latent);
+        let result = parser
+            .parse_with_kind_order("10 pounds", &ctx, &[OutputKind::Mass])
+            .unwrap();
+        assert!(result[0].latent);
+    }
+
+    #[test]
+    fn test_rate_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser
+            .parse_with_kind_order("60 miles per hour", &ctx, &[OutputKind::Rate])
+            .unwrap();
+        let rate: output::RateOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((60.0, Some("mi"), "hour"), (rate.value, rate.numerator_unit, rate.denominator_unit));
+        let result = parser
+            .parse_with_kind_order("5 minutes per kilometre", &ctx, &[OutputKind::Rate])
+            .unwrap();
+        let rate: output::RateOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((5.0, Some("minute"), "km"), (rate.value, rate.numerator_unit, rate.denominator_unit));
+        let result = parser
+            .parse_with_kind_order("about 10 km/h", &ctx, &[OutputKind::Rate])
+            .unwrap();
+        let rate: output::RateOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((10.0, Some("km"), "hour"), (rate.value, rate.numerator_unit, rate.denominator_unit));
+        assert_eq!(dimension::Precision::Approximate, rate.precision);
+    }
+
+    #[test]
+    fn test_roman_numerals_en() {
+        let ctx = ResolverContext::default();
+        let parser = train_parser_with_rules(
+            Lang::EN,
+            |b| rules_roman_numerals(Lang::EN, b),
+            examples_roman_numerals(Lang::EN),
+        ).unwrap();
+        let result = parser
+            .parse_with_kind_order("louis xiv", &ctx, &[OutputKind::Ordinal])
+            .unwrap();
+        let ordinal: output::OrdinalOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(14, ordinal.0);
+        assert!(!result[0].latent);
+        let result = parser
+            .parse_with_kind_order("super bowl lii", &ctx, &[OutputKind::Ordinal])
+            .unwrap();
+        let ordinal: output::OrdinalOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(52, ordinal.0);
+        assert!(!result[0].latent);
+        let result = parser
+            .parse_with_kind_order("i", &ctx, &[OutputKind::Number])
+            .unwrap();
+        assert!(result.iter().all(|m| m.latent));
+        // The numbers written with digits or words are left as they are
+        for (input, value) in &[("42", 42), ("3 apples", 3), ("chapter 3", 3), ("twenty-one", 21)] {
+            let result = parser
+                .parse_with_kind_order(input, &ctx, &[OutputKind::Number])
+                .unwrap();
+            let integer: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
+            assert_eq!(*value, integer.0);
+            assert!(!result[0].latent);
+        }
+    }
+
+    #[test]
+    fn test_age_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser
+            .parse_with_kind_order("my son is 7 years old", &ctx, &[OutputKind::Age, OutputKind::Duration])
+            .unwrap();
+        let age: output::AgeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((7.0, Grain::Year), (age.value, age.unit));
+    }
+
+    #[cfg(feature = "embed-fr")]
+    #[test]
+    fn test_age_fr() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::FR).unwrap();
+        let result = parser
+            .parse_with_kind_order("il est âgé de 35 ans", &ctx, &[OutputKind::Age])
+            .unwrap();
+        let age: output::AgeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((35.0, Grain::Year), (age.value, age.unit));
+        assert!(!result[0].latent);
+        let result = parser
+            .parse_with_kind_order("j'ai 3 ans de retard", &ctx, &[OutputKind::Age])
+            .unwrap();
+        assert!(result.iter().all(|m| m.latent));
+    }
+
+    #[test]
+    fn test_occurrences_en() {
+        let ctx = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            Grain::Second,
+        ));
+        let march = Interval::starting_at(
+            Moment(Tz::local().ymd(2013, 3, 1).and_hms(0, 0, 0)),
+            Grain::Month,
+        );
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser.occurrences("monday", &ctx, march, 10).unwrap();
+        let mondays = result[0].occurrences.iter().map(|i| i.start).collect::<Vec<_>>();
+        assert_eq!(
+            vec![4, 11, 18, 25]
+                .into_iter()
+                .map(|d| Moment(Tz::local().ymd(2013, 3, d).and_hms(0, 0, 0)))
+                .collect::<Vec<_>>(),
+            mondays
+        );
+        let result = parser.occurrences("monday", &ctx, march, 2).unwrap();
+        assert_eq!(2, result[0].occurrences.len());
+    }
+
+    #[test]
+    fn test_recurrence_en() {
+        let ctx = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            Grain::Second,
+        ));
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser
+            .parse_with_kind_order("every monday at 5pm", &ctx, &[OutputKind::Recurrence])
+            .unwrap();
+        let recurrence: output::RecurrenceOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((Grain::Week, 1), (recurrence.frequency, recurrence.interval));
+        assert_eq!(vec![Weekday::Mon], recurrence.by_day);
+        assert_eq!((Some(17), Some(0)), (recurrence.by_hour, recurrence.by_minute));
+        assert_eq!(Moment(Tz::local().ymd(2013, 2, 18).and_hms(17, 0, 0)), recurrence.start);
+        let result = parser
+            .parse_with_kind_order("every monday and wednesday at 5pm", &ctx, &[OutputKind::Recurrence])
+            .unwrap();
+        let recurrence: output::RecurrenceOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((Grain::Week, 1), (recurrence.frequency, recurrence.interval));
+        assert_eq!(vec![Weekday::Mon, Weekday::Wed], recurrence.by_day);
+        assert_eq!(Some(17), recurrence.by_hour);
+        assert_eq!(Moment(Tz::local().ymd(2013, 2, 13).and_hms(17, 0, 0)), recurrence.start);
+        let result = parser
+            .parse_with_kind_order("quarterly", &ctx, &[OutputKind::Recurrence])
+            .unwrap();
+        let recurrence: output::RecurrenceOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((Grain::Month, 3), (recurrence.frequency, recurrence.interval));
+    }
+
+    #[test]
+    fn test_contact_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser
+            .parse_with_kind_order("write to john.doe@gmail.com or call +33 6 12 34 56 78", &ctx, &[OutputKind::Email, OutputKind::PhoneNumber])
+            .unwrap();
+        let email: output::EmailOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!("john.doe@gmail.com", email.value);
+        let phone_number: output::PhoneNumberOutput = result[1].value.clone().attempt_into().unwrap();
+        assert_eq!("+33612345678", phone_number.value);
+        let result = parser
+            .parse_with_kind_order("call 4155550123", &ctx, &[OutputKind::PhoneNumber])
+            .unwrap();
+        assert!(result[0].latent);
+        let result = parser
+            .parse_with_kind_order("on 2013-05-06", &ctx, &[OutputKind::PhoneNumber])
+            .unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_fraction_en() {
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser
+            .parse_with_kind_order("two and three quarters", &ctx, &[OutputKind::Number])
+            .unwrap();
+        let fraction: output::FractionOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!((2.75, 11, 4), (fraction.value, fraction.numerator, fraction.denominator));
+        let ctx = ResolverContext::for_reference(Interval::starting_at(
+            Moment(Tz::local().ymd(2013, 2, 12).and_hms(4, 30, 0)),
+            Grain::Second,
+        ));
+        let result = parser.parse("5/6", &ctx).unwrap();
+        let datetime: output::DatetimeOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(Moment(Tz::local().ymd(2013, 5, 6).and_hms(0, 0, 0)), datetime.moment);
+        let result = parser
+            .parse_with_kind_order("1 1/2 hours", &ctx, &[OutputKind::Duration])
+            .unwrap();
+        assert_eq!(1, result.len());
+    }
+
+    #[test]
+    fn test_quantity_range_en() {
+        use rustling_ontology_values::output::QuantityIntervalKind;
+
+        let ctx = ResolverContext::default();
+        let parser = build_parser(Lang::EN).unwrap();
+        let result = parser
+            .parse_with_kind_order("between 20 and 30 euros", &ctx, &[OutputKind::AmountOfMoneyInterval])
+            .unwrap();
+        let interval: output::AmountOfMoneyIntervalOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(QuantityIntervalKind::Between { start: 20.0, end: 30.0 }, interval.interval_kind);
+        let result = parser
+            .parse_with_kind_order("under 50 dollars", &ctx, &[OutputKind::AmountOfMoneyInterval])
+            .unwrap();
+        let interval: output::AmountOfMoneyIntervalOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(
+            (QuantityIntervalKind::Below { end: 50.0, inclusive: false }, Some("$")),
+            (interval.interval_kind, interval.unit)
+        );
+        for &(sentence, inclusive) in &[("more than 5", false), ("at least 5", true), ("5 or more", true)] {
+            let result = parser
+                .parse_with_kind_order(sentence, &ctx, &[OutputKind::NumberInterval])
+                .unwrap();
+            let interval: output::NumberIntervalOutput = result[0].value.clone().attempt_into().unwrap();
+            assert_eq!(QuantityIntervalKind::Above { start: 5.0, inclusive }, interval.interval_kind);
+        }
+        let result = parser
+            .parse_with_kind_order("between 5 and 10", &ctx, &[OutputKind::NumberInterval])
+            .unwrap();
+        let interval: output::NumberIntervalOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(QuantityIntervalKind::Between { start: 5.0, end: 10.0 }, interval.interval_kind);
+        assert!(!result[0].latent);
+        let result = parser
+            .parse_with_kind_order("3-4 people", &ctx, &[OutputKind::NumberInterval])
+            .unwrap();
+        let interval: output::NumberIntervalOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(QuantityIntervalKind::Between { start: 3.0, end: 4.0 }, interval.interval_kind);
+        assert!(result[0].latent);
+        // Other bare numbers on both sides are left to the other dimensions, e.g. phone numbers
+        for sentence in &["5 to 10", "555-1234"] {
+            let result = parser
+                .parse_with_kind_order(sentence, &ctx, &[OutputKind::NumberInterval])
+                .unwrap();
+            assert!(result.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_train_parser_with_rules() {
+        use rustling_ontology_values::check::check_integer;
+        use rustling_ontology_values::dimension::IntegerValue;
+
+        let ctx = ResolverContext::default();
+        let parser = train_parser_with_rules(
+            Lang::EN,
+            |b| {
+                b.rule_1_terminal("a baker's dozen",
+                                  b.reg(r#"a baker'?s dozen"#)?,
+                                  |_| IntegerValue::new_with_grain(13, 1)
+                );
+                Ok(())
+            },
+            vec![Example::new("a baker's dozen", Box::new(check_integer(13)))],
+        ).unwrap();
+        let result = parser
+            .parse_with_kind_order("a baker's dozen", &ctx, &[OutputKind::Number])
+            .unwrap();
+        let int: output::IntegerOutput = result[0].value.clone().attempt_into().unwrap();
+        assert_eq!(13, int.0);
+    }
+
     #[test]
     #[ignore]
     fn time_resolve_complex_train_sentence() {
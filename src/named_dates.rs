@@ -0,0 +1,159 @@
+use crate::Lang;
+use rustling::RuleResult;
+use rustling_ontology_moment::{Grain, Weekday};
+use rustling_ontology_values::dimension::{DatetimeValue, Form};
+use rustling_ontology_values::helpers;
+
+/// Day of the year on which a named date falls.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum NamedDateRule {
+    /// Fixed day of a month, e.g. June 30.
+    MonthDay { month: u32, day: u32 },
+    /// n-th day of the week of a month, e.g. the 2nd friday of March. A negative `n` counts from
+    /// the end of the month, -1 being the last one.
+    NthDayOfWeek { n: i64, weekday: Weekday, month: u32 },
+    /// Number of days after Easter Sunday, negative for the days before.
+    Easter { offset: i64 },
+}
+
+impl NamedDateRule {
+    fn datetime_value(&self) -> RuleResult<DatetimeValue> {
+        let value = match *self {
+            NamedDateRule::MonthDay { month, day } => helpers::month_day(month, day)?,
+            NamedDateRule::NthDayOfWeek { n, weekday, month } => {
+                helpers::nth_day_of_week_of_month(n, weekday, month)?
+            }
+            NamedDateRule::Easter { offset: 0 } => helpers::easter()?,
+            NamedDateRule::Easter { offset } => {
+                helpers::cycle_nth_after(Grain::Day, offset, &helpers::easter()?)?
+            }
+        };
+        Ok(value.form(Form::Celebration))
+    }
+}
+
+/// A date declared at runtime, with the surface forms naming it in each language.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamedDate {
+    pub name: String,
+    pub rule: NamedDateRule,
+    pub surface_forms: Vec<(Lang, String)>,
+}
+
+impl NamedDate {
+    pub fn new<S: Into<String>>(name: S, rule: NamedDateRule) -> NamedDate {
+        NamedDate {
+            name: name.into(),
+            rule,
+            surface_forms: vec![],
+        }
+    }
+
+    /// Adds a phrase naming the date in the given language. It is matched literally, ignoring the
+    /// case.
+    pub fn surface_form<S: Into<String>>(mut self, lang: Lang, form: S) -> NamedDate {
+        self.surface_forms.push((lang, form.into()));
+        self
+    }
+
+    fn forms(&self, lang: Lang) -> impl Iterator<Item = &str> {
+        self.surface_forms
+            .iter()
+            .filter(move |&&(form_lang, _)| form_lang == lang)
+            .map(|&(_, ref form)| form.as_str())
+    }
+}
+
+/// Registry of named dates, e.g. company or regional holidays, which are parsed and resolved like
+/// the built-in celebrations. The dates are given to the resolver context so that the built-in
+/// parsers find them without retraining:
+///
+/// ```ignore
+/// let registry = NamedDateRegistry::new().add(
+///     NamedDate::new("founders day", NamedDateRule::NthDayOfWeek { n: 2, weekday: Weekday::Fri, month: 3 })
+///         .surface_form(Lang::EN, "founders day")
+///         .surface_form(Lang::FR, "jour des fondateurs"),
+/// );
+/// let context = ResolverContext::default().with_named_dates(registry.named_dates(Lang::EN)?);
+/// let result = build_parser(Lang::EN)?.parse("founders day", &context)?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NamedDateRegistry {
+    dates: Vec<NamedDate>,
+}
+
+impl NamedDateRegistry {
+    pub fn new() -> NamedDateRegistry {
+        NamedDateRegistry::default()
+    }
+
+    pub fn add(mut self, date: NamedDate) -> NamedDateRegistry {
+        self.dates.push(date);
+        self
+    }
+
+    pub fn dates(&self) -> &[NamedDate] {
+        &self.dates
+    }
+
+    /// Surface forms of the named dates in the given language with the datetime they name, to be
+    /// given to `ResolverContext::with_named_dates`.
+    pub fn named_dates(&self, lang: Lang) -> RuleResult<Vec<(String, DatetimeValue)>> {
+        let mut named_dates = vec![];
+        for date in &self.dates {
+            let value = date.rule.datetime_value()?;
+            for form in date.forms(lang) {
+                named_dates.push((form.to_string(), value.clone()));
+            }
+        }
+        Ok(named_dates)
+    }
+}
+
+#[cfg(all(test, feature = "embed-en"))]
+mod tests {
+    use super::*;
+    use crate::{build_parser, OutputKind};
+    use rustling_ontology_moment::{Interval, Moment, TimeZone, Tz};
+    use rustling_ontology_values::output::{DatetimeOutput, Output};
+    use rustling_ontology_values::ResolverContext;
+
+    #[test]
+    fn test_named_dates() {
+        let registry = NamedDateRegistry::new()
+            .add(
+                NamedDate::new(
+                    "founders day",
+                    NamedDateRule::NthDayOfWeek {
+                        n: 2,
+                        weekday: Weekday::Fri,
+                        month: 3,
+                    },
+                )
+                .surface_form(Lang::EN, "founders day")
+                .surface_form(Lang::EN, "founders' day"),
+            )
+            .add(
+                NamedDate::new("fiscal year end", NamedDateRule::MonthDay { month: 6, day: 30 })
+                    .surface_form(Lang::EN, "fiscal year end"),
+            );
+        let parser = build_parser(Lang::EN).unwrap();
+        let tz = Tz::local();
+        let ctx = ResolverContext::for_reference(Interval::starting_at(
+            Moment(tz.ymd(2017, 1, 10).and_hms(9, 0, 0)),
+            Grain::Second,
+        ))
+        .with_named_dates(registry.named_dates(Lang::EN).unwrap());
+        for &(sentence, month, day) in &[("founders' day", 3, 10), ("Fiscal Year End", 6, 30)] {
+            let result = parser
+                .parse_with_kind_order(sentence, &ctx, &[OutputKind::Datetime])
+                .unwrap();
+            match result[0].value {
+                Output::Datetime(DatetimeOutput { ref moment, .. }) => {
+                    assert_eq!(Moment(tz.ymd(2017, month, day).and_hms(0, 0, 0)), *moment)
+                }
+                ref output => panic!("Unexpected output {:?}", output),
+            }
+        }
+    }
+}
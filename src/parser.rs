@@ -56,7 +56,7 @@ pub fn extract_node_features(
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "embed-en"))]
 mod tests {
     use crate::{build_raw_parser, CandidateTagger, Lang, OutputKind};
     use rustling::ParserMatch;
@@ -58,6 +58,11 @@ impl<'a, C: ParsingContext<Dimension>> MaxElementTagger<Dimension> for Candidate
                 .cmp(&b.1.byte_range.len())
                 .then_with(|| a.1.byte_range.0.cmp(&b.1.byte_range.0))
                 .then_with(|| a.2.cmp(&b.2))
+                .then_with(|| {
+                    self.context
+                        .preference(&a.1.value)
+                        .cmp(&self.context.preference(&b.1.value))
+                })
                 .then_with(|| {
                     if a.1.value.kind() == b.1.value.kind() {
                         a.1.probalog
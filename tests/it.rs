@@ -1,3 +1,5 @@
+#![cfg(feature = "lang-it")]
+
 extern crate rustling_ontology;
 extern crate rustling_ontology_moment as moment;
 extern crate rustling_ontology_json_utils as json_utils;
@@ -1,7 +1,7 @@
 use crate::context::{ParsingContext, ResolverContext};
 use crate::dimension::*;
 use crate::output::*;
-use moment::{Grain, Interval, Local, Moment, Period};
+use moment::{Grain, Interval, Moment, Period, Tz, Weekday};
 use rustling::{AttemptFrom, Check, ParsedNode};
 
 #[derive(Debug)]
@@ -51,6 +51,28 @@ impl Check<Dimension> for CheckFloat {
     }
 }
 
+#[derive(Debug)]
+pub struct CheckFraction {
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
+impl Check<Dimension> for CheckFraction {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        FloatValue::attempt_from(pn.value.clone())
+            .and_then(|v| v.fraction)
+            .map(|f| f.numerator == self.numerator && f.denominator == self.denominator)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_fraction(numerator: i64, denominator: i64) -> CheckFraction {
+    CheckFraction {
+        numerator,
+        denominator,
+    }
+}
+
 pub fn check_float(v: f64) -> CheckFloat {
     CheckFloat { value: v }
 }
@@ -77,7 +99,7 @@ pub fn check_duration(period: Period, precision: Precision) -> CheckDuration {
 pub struct CheckMoment {
     pub direction: Option<Direction>,
     pub precision: Precision,
-    pub interval: Interval<Local>,
+    pub interval: Interval<Tz>,
     pub context: ResolverContext,
 }
 
@@ -131,7 +153,7 @@ impl Check<Dimension> for CheckMoment {
 
 pub fn check_moment(
     context: ResolverContext,
-    moment: Moment<Local>,
+    moment: Moment<Tz>,
     grain: Grain,
     precision: Precision,
     direction: Option<Direction>,
@@ -146,7 +168,7 @@ pub fn check_moment(
 
 #[derive(Debug)]
 pub struct CheckMomentSpan {
-    pub interval: Interval<Local>,
+    pub interval: Interval<Tz>,
     pub precision: Precision,
     pub context: ResolverContext,
 }
@@ -178,8 +200,8 @@ impl Check<Dimension> for CheckMomentSpan {
 pub fn check_moment_span(
     context: ResolverContext,
     precision: Precision,
-    start: Moment<Local>,
-    end: Moment<Local>,
+    start: Moment<Tz>,
+    end: Moment<Tz>,
     grain: Grain,
 ) -> CheckMomentSpan {
     CheckMomentSpan {
@@ -246,3 +268,239 @@ impl Check<Dimension> for CheckTemperature {
 pub fn check_temperature(value: f64, unit: Option<&'static str>) -> CheckTemperature {
     CheckTemperature { value, unit }
 }
+
+#[derive(Debug)]
+pub struct CheckDistance {
+    pub value: f64,
+    pub unit: Option<&'static str>,
+    pub precision: Precision,
+}
+
+impl Check<Dimension> for CheckDistance {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        DistanceValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value && v.precision == self.precision && v.unit == self.unit)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_distance(value: f64, unit: Option<&'static str>, precision: Precision) -> CheckDistance {
+    CheckDistance {
+        value,
+        precision,
+        unit,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckVolume {
+    pub value: f64,
+    pub unit: Option<&'static str>,
+    pub precision: Precision,
+}
+
+impl Check<Dimension> for CheckVolume {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        VolumeValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value && v.precision == self.precision && v.unit == self.unit)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_volume(value: f64, unit: Option<&'static str>, precision: Precision) -> CheckVolume {
+    CheckVolume {
+        value,
+        precision,
+        unit,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckMass {
+    pub value: f64,
+    pub unit: Option<&'static str>,
+    pub precision: Precision,
+}
+
+impl Check<Dimension> for CheckMass {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        MassValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value && v.precision == self.precision && v.unit == self.unit)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_mass(value: f64, unit: Option<&'static str>, precision: Precision) -> CheckMass {
+    CheckMass {
+        value,
+        precision,
+        unit,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckRate {
+    pub value: f64,
+    pub numerator_unit: Option<&'static str>,
+    pub denominator_unit: &'static str,
+    pub precision: Precision,
+}
+
+impl Check<Dimension> for CheckRate {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        RateValue::attempt_from(pn.value.clone())
+            .map(|v| {
+                v.value == self.value
+                    && v.precision == self.precision
+                    && v.numerator_unit == self.numerator_unit
+                    && v.denominator_unit == self.denominator_unit
+            })
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_rate(
+    value: f64,
+    numerator_unit: Option<&'static str>,
+    denominator_unit: &'static str,
+    precision: Precision,
+) -> CheckRate {
+    CheckRate {
+        value,
+        numerator_unit,
+        denominator_unit,
+        precision,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckQuantityRange {
+    pub quantity: QuantityKind,
+    pub start: Option<f64>,
+    pub end: Option<f64>,
+}
+
+impl Check<Dimension> for CheckQuantityRange {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        QuantityRangeValue::attempt_from(pn.value.clone())
+            .map(|v| v.quantity == self.quantity && v.start == self.start && v.end == self.end)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_quantity_range(
+    quantity: QuantityKind,
+    start: Option<f64>,
+    end: Option<f64>,
+) -> CheckQuantityRange {
+    CheckQuantityRange {
+        quantity,
+        start,
+        end,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckPhoneNumber {
+    pub value: &'static str,
+}
+
+impl Check<Dimension> for CheckPhoneNumber {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        PhoneNumberValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_phone_number(value: &'static str) -> CheckPhoneNumber {
+    CheckPhoneNumber { value }
+}
+
+#[derive(Debug)]
+pub struct CheckEmail {
+    pub value: &'static str,
+}
+
+impl Check<Dimension> for CheckEmail {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        EmailValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_email(value: &'static str) -> CheckEmail {
+    CheckEmail { value }
+}
+
+#[derive(Debug)]
+pub struct CheckUrl {
+    pub value: &'static str,
+}
+
+impl Check<Dimension> for CheckUrl {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        UrlValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_url(value: &'static str) -> CheckUrl {
+    CheckUrl { value }
+}
+
+#[derive(Debug)]
+pub struct CheckRecurrence {
+    pub frequency: Grain,
+    pub interval: i64,
+    pub by_day: Vec<Weekday>,
+    pub context: ResolverContext,
+}
+
+impl Check<Dimension> for CheckRecurrence {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        self.context
+            .resolve(&pn.value)
+            .and_then(|v| RecurrenceOutput::attempt_from(v))
+            .map(|v| {
+                v.frequency == self.frequency
+                    && v.interval == self.interval
+                    && v.by_day == self.by_day
+            })
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_recurrence(
+    context: ResolverContext,
+    frequency: Grain,
+    interval: i64,
+    by_day: Vec<Weekday>,
+) -> CheckRecurrence {
+    CheckRecurrence {
+        frequency,
+        interval,
+        by_day,
+        context,
+    }
+}
+
+#[derive(Debug)]
+pub struct CheckAge {
+    pub value: f64,
+    pub unit: Grain,
+}
+
+impl Check<Dimension> for CheckAge {
+    fn check(&self, pn: &ParsedNode<Dimension>) -> bool {
+        AgeValue::attempt_from(pn.value.clone())
+            .map(|v| v.value == self.value && v.unit == self.unit)
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_age(value: f64, unit: Grain) -> CheckAge {
+    CheckAge { value, unit }
+}
@@ -1,12 +1,22 @@
 use crate::dimension::*;
 use crate::output::*;
 use log::warn;
+use moment::walker::Walker;
 use moment::*;
-use rustling::Value;
+use regex::{Regex, RegexBuilder};
+use rustling::{Range, Value};
+use std::cmp::Ordering;
+use std::sync::Arc;
 
 pub trait ParsingContext<V: Value> {
     type O;
     fn resolve(&self, value: &V) -> Option<Self::O>;
+
+    /// Ranks a value against the competing readings of the same text: `Greater` when it follows
+    /// the conventions of the context, `Less` when it goes against them.
+    fn preference(&self, _value: &V) -> Ordering {
+        Ordering::Equal
+    }
 }
 
 pub struct IdentityContext<V: Value + Clone> {
@@ -28,125 +38,433 @@ impl<V: Value + Clone> ParsingContext<V> for IdentityContext<V> {
     }
 }
 
-#[derive(Default, Debug, Copy, Clone)]
+/// Clock on which a time of day is given when it is not explicitly set, e.g. "at 8".
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Clock {
+    /// "at 8" is the next 8:00 or 20:00, whichever comes first.
+    H12,
+    /// "at 8" is the next 8:00.
+    H24,
+}
+
+/// Regional conventions used to choose between the competing readings of a text.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Locale {
+    /// Order of the dates written with numbers only, the usual order of the language when unset.
+    pub date_order: Option<DateOrder>,
+    pub clock: Clock,
+    pub first_day_of_week: Weekday,
+}
+
+impl Locale {
+    pub fn new(date_order: DateOrder, clock: Clock, first_day_of_week: Weekday) -> Locale {
+        Locale {
+            date_order: Some(date_order),
+            clock,
+            first_day_of_week,
+        }
+    }
+}
+
+impl Default for Locale {
+    /// Dates in the usual order of the language, 12 hour clock and weeks starting on monday.
+    fn default() -> Locale {
+        Locale {
+            date_order: None,
+            clock: Clock::H12,
+            first_day_of_week: Weekday::Mon,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone)]
 pub struct ResolverContext {
-    ctx: Context<Local>,
+    ctx: Context<Tz>,
+    locale: Locale,
+    named_dates: Arc<Vec<(Regex, DatetimeValue)>>,
 }
 
 impl ResolverContext {
     pub fn from_secs(secs: i64) -> ResolverContext {
-        let anchor = Interval::starting_at(Moment(Local.timestamp(secs, 0)), Grain::Second);
+        ResolverContext::from_secs_in(secs, Tz::local())
+    }
+
+    /// Returns a ResolverContext for the given timestamp, moments are resolved in the given
+    /// timezone.
+    pub fn from_secs_in(secs: i64, tz: Tz) -> ResolverContext {
+        let anchor = Interval::starting_at(Moment(tz.timestamp(secs, 0)), Grain::Second);
         ResolverContext::for_reference(anchor)
     }
 
+    /// Returns a ResolverContext for the current moment in the given timezone.
+    pub fn now_in(tz: Tz) -> ResolverContext {
+        ResolverContext {
+            ctx: Context::now_in(tz),
+            locale: Locale::default(),
+            named_dates: Arc::default(),
+        }
+    }
+
     /// Returns a ResolverContext for the given interval. This API is working for 32bits and 64bits 
-    /// operating system by supporting dates only between 1970 and 2038
-    pub fn for_reference(now: Interval<Local>) -> ResolverContext {
+    /// operating system by supporting dates only between 1970 and 2038.
+    /// Moments are resolved in the timezone of the given interval.
+    pub fn for_reference(now: Interval<Tz>) -> ResolverContext {
         ResolverContext {
             ctx: Context::for_reference(now),
+            locale: Locale::default(),
+            named_dates: Arc::default(),
         }
     }
 
     /// Returns a ResolverContext with the given intervals. No restrictions is applied. 
-    pub fn new(now: Interval<Local>, min: Interval<Local>, max: Interval<Local>) -> ResolverContext {
+    pub fn new(now: Interval<Tz>, min: Interval<Tz>, max: Interval<Tz>) -> ResolverContext {
         ResolverContext {
-            ctx: Context::new(now, min, max)
+            ctx: Context::new(now, min, max),
+            locale: Locale::default(),
+            named_dates: Arc::default(),
         }
     }
-}
 
-impl ParsingContext<Dimension> for ResolverContext {
-    type O = Output;
+    /// Returns the same ResolverContext with local times skipped or repeated by a daylight saving
+    /// time transition resolved according to the given policy.
+    pub fn with_dst_policy(self, dst_policy: DstPolicy) -> ResolverContext {
+        ResolverContext {
+            ctx: self.ctx.with_dst_policy(dst_policy),
+            ..self
+        }
+    }
 
-    fn resolve(&self, dim: &Dimension) -> Option<Output> {
+    /// Returns the same ResolverContext with the competing readings of a text chosen according
+    /// to the conventions of the given locale, e.g. "05/06" is the 5th of june in a day first
+    /// locale.
+    pub fn with_locale(self, locale: Locale) -> ResolverContext {
+        ResolverContext {
+            ctx: self.ctx.with_week_start(locale.first_day_of_week),
+            locale,
+            ..self
+        }
+    }
+
+    /// Returns the same ResolverContext with weekends running from the first to the last given
+    /// day, e.g. for "next business day" where weekends are on friday and saturday.
+    pub fn with_weekend(self, first: Weekday, last: Weekday) -> ResolverContext {
+        ResolverContext {
+            ctx: self.ctx.with_weekend(first, last),
+            ..self
+        }
+    }
+
+    /// Returns the same ResolverContext with the given local dates off work on top of the
+    /// weekends, e.g. for "next business day".
+    pub fn with_holidays<I: IntoIterator<Item = NaiveDate>>(self, holidays: I) -> ResolverContext {
+        ResolverContext {
+            ctx: self.ctx.with_holidays(holidays),
+            ..self
+        }
+    }
+
+    /// Returns the same ResolverContext with working days opening and closing at the given
+    /// hours, e.g. for "end of business". Fails unless the opening is before the closing, which
+    /// is at 24 at the latest.
+    pub fn with_business_hours(self, open: u32, close: u32) -> MomentResult<ResolverContext> {
+        Ok(ResolverContext {
+            ctx: self.ctx.with_business_hours(open, close)?,
+            ..self
+        })
+    }
+
+    /// Returns the same ResolverContext with fiscal years starting on the first day of the given
+    /// month, e.g. for "Q3 FY24" or "next fiscal quarter". Fails unless the month is between 1
+    /// and 12.
+    pub fn with_fiscal_year_start(self, month: u32) -> MomentResult<ResolverContext> {
+        Ok(ResolverContext {
+            ctx: self.ctx.with_fiscal_year_start(month)?,
+            ..self
+        })
+    }
+
+    /// Returns the same ResolverContext with weeks starting on the given day, e.g. "next week"
+    /// starts on sunday in the US.
+    pub fn with_week_start(self, week_start: Weekday) -> ResolverContext {
+        let locale = Locale {
+            first_day_of_week: week_start,
+            ..self.locale
+        };
+        self.with_locale(locale)
+    }
+
+    /// Returns the same ResolverContext with dates named by the application, e.g. a company
+    /// holiday, each given with one of its surface forms. The forms are found in the input as
+    /// whole words regardless of the case, and resolve like the datetime they name.
+    pub fn with_named_dates<I, S>(self, named_dates: I) -> ResolverContext
+    where
+        I: IntoIterator<Item = (S, DatetimeValue)>,
+        S: AsRef<str>,
+    {
+        let named_dates = named_dates
+            .into_iter()
+            .filter_map(|(form, datetime)| {
+                RegexBuilder::new(&format!(r"\b{}\b", regex::escape(form.as_ref().trim())))
+                    .case_insensitive(true)
+                    .build()
+                    .ok()
+                    .map(|regex| (regex, datetime))
+            })
+            .collect();
+        ResolverContext {
+            named_dates: Arc::new(named_dates),
+            ..self
+        }
+    }
+
+    /// Spans of the input naming one of the dates of `with_named_dates`, with the datetime they
+    /// name. The longest form wins when several overlap.
+    pub fn named_dates(&self, input: &str) -> Vec<(Range, &DatetimeValue)> {
+        let mut found: Vec<(Range, &DatetimeValue)> = self
+            .named_dates
+            .iter()
+            .flat_map(|&(ref regex, ref datetime)| {
+                regex
+                    .find_iter(input)
+                    .map(move |m| (Range(m.start(), m.end()), datetime))
+            })
+            .collect();
+        found.sort_by(|a, b| (a.0).0.cmp(&(b.0).0).then((b.0).1.cmp(&(a.0).1)));
+        let mut selected: Vec<(Range, &DatetimeValue)> = vec![];
+        for (range, datetime) in found {
+            if selected.last().map_or(true, |last| (last.0).1 <= range.0) {
+                selected.push((range, datetime));
+            }
+        }
+        selected
+    }
+
+    /// Regional conventions of the context.
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// Timezone in which the moments are resolved.
+    pub fn timezone(&self) -> Tz {
+        self.ctx.reference.timezone()
+    }
+
+    /// Returns the possible resolutions of a dimension, the most likely first. A time of day
+    /// given on a 12 hour clock, e.g. "at 8", resolves to its next occurrence and to the one 12
+    /// hours later, the morning one first when the locale uses a 24 hour clock.
+    pub fn resolve_alternatives(&self, dim: &Dimension) -> Vec<Output> {
         match dim {
-            &Dimension::Datetime(ref datetime_value) => {
-                let mut walker = datetime_value
+            &Dimension::Datetime(ref datetime_value) if datetime_value.form.is_12_clock() => {
+                let mut intervals = self.walk_intervals(datetime_value, 2, None);
+                if let Some(hour) = self.clock_hour(datetime_value) {
+                    intervals.sort_by_key(|interval| interval.start.hour() != hour);
+                }
+                intervals
+                    .into_iter()
+                    .map(|interval| datetime_output(datetime_value, interval))
+                    .collect()
+            }
+            _ => self.resolve(dim).into_iter().collect(),
+        }
+    }
+
+    /// Returns the intervals matching the datetime value which overlap the window, in
+    /// chronological order, e.g. the mondays of march. The iteration stops at the end of the
+    /// window or after `max_count` intervals, whichever comes first.
+    pub fn occurrences(
+        &self,
+        datetime_value: &DatetimeValue,
+        window: Interval<Tz>,
+        max_count: usize,
+    ) -> Occurrences {
+        let origin = Interval::starting_at(window.start, Grain::Second);
+        Occurrences {
+            walker: datetime_value.constraint.to_walker(&origin, &self.ctx).forward,
+            start: window.start,
+            horizon: window.end_moment(),
+            remaining: max_count,
+        }
+    }
+
+    /// Returns up to `n` intervals matching the datetime value, starting from the reference.
+    fn datetime_intervals(&self, datetime_value: &DatetimeValue, n: usize) -> Vec<Interval<Tz>> {
+        self.walk_intervals(datetime_value, n, self.clock_hour(datetime_value))
+    }
+
+    /// Hour of the day a time given on a 12 hour clock is restricted to, when the locale uses a
+    /// 24 hour clock.
+    fn clock_hour(&self, datetime_value: &DatetimeValue) -> Option<u32> {
+        match datetime_value.form {
+            Form::TimeOfDay(ref form) if form.is_12_clock() && self.locale.clock == Clock::H24 => {
+                Some(form.full_hour())
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns up to `n` intervals matching the datetime value, starting from the reference,
+    /// keeping only the ones starting at the given hour if any.
+    fn walk_intervals(
+        &self,
+        datetime_value: &DatetimeValue,
+        n: usize,
+        hour: Option<u32>,
+    ) -> Vec<Interval<Tz>> {
+        let mut walker = datetime_value
+            .constraint
+            .to_walker(&self.ctx.reference, &self.ctx);
+        if let Some(hour) = hour {
+            walker.forward = walker.forward.filter(move |interval| interval.start.hour() == hour);
+            walker.backward = walker.backward.filter(move |interval| interval.start.hour() == hour);
+        }
+        let first = walker.forward.next().and_then(|h| {
+            if datetime_value.form.not_immediate().unwrap_or(false)
+                && h.intersect(self.ctx.reference).is_some()
+            {
+                walker.forward.next()
+            } else {
+                Some(h)
+            }
+        });
+        match first {
+            Some(first) => {
+                let mut intervals = vec![first];
+                while intervals.len() < n {
+                    match walker.forward.next() {
+                        Some(interval) => intervals.push(interval),
+                        None => break,
+                    }
+                }
+                intervals
+            }
+            None => walker.backward.next().into_iter().collect(),
+        }
+    }
+
+    /// Resolves a recurrence to its first occurrence, the frequency and the `by_*` fields being
+    /// the ones set by the rules. A bound that can't be resolved is left out.
+    fn recurrence_output(&self, recurrence: &RecurrenceValue) -> Option<Output> {
+        let start = recurrence
+            .datetimes
+            .iter()
+            .filter_map(|datetime| {
+                datetime
                     .constraint
-                    .to_walker(&self.ctx.reference, &self.ctx);
-                walker
+                    .to_walker(&self.ctx.reference, &self.ctx)
                     .forward
                     .next()
-                    .and_then(|h| {
-                        if datetime_value.form.not_immediate().unwrap_or(false)
-                            && h.intersect(self.ctx.reference).is_some()
-                        {
-                            walker.forward.next()
-                        } else {
-                            Some(h)
-                        }
-                    })
-                    .or_else(|| walker.backward.next())
-                    .map(|interval| {
-                        if let Some(bounded_direction) = datetime_value.direction {
-                            let anchor = match bounded_direction.bound {
-                                Bound::Start => interval.start,
-                                Bound::End { only_interval } if only_interval => {
-                                    interval.end.unwrap_or(interval.start)
-                                }
-                                Bound::End { .. } => interval.end_moment(),
-                            };
-                            let datetime_output_value = DatetimeOutput {
-                                moment: anchor,
-                                grain: interval.grain,
-                                precision: datetime_value.precision,
-                                latent: datetime_value.latent,
-                                datetime_kind: datetime_value.datetime_kind,
-                            };
-                            match bounded_direction.direction {
-                                Direction::After => {
-                                    let datetime_interval_output_value = DatetimeIntervalOutput {
-                                        interval_kind: DatetimeIntervalKind::After(
-                                            datetime_output_value,
-                                        ),
-                                        datetime_kind: datetime_output_value.datetime_kind,
-                                    };
-                                    Output::DatetimeInterval(datetime_interval_output_value)
-                                }
-                                Direction::Before => {
-                                    let datetime_interval_output_value = DatetimeIntervalOutput {
-                                        interval_kind: DatetimeIntervalKind::Before(
-                                            datetime_output_value,
-                                        ),
-                                        datetime_kind: datetime_output_value.datetime_kind,
-                                    };
-                                    Output::DatetimeInterval(datetime_interval_output_value)
-                                }
-                            }
-                        } else if let Some(end) = interval.end {
-                            if datetime_value.datetime_kind == DatetimeKind::Date
-                                || datetime_value.datetime_kind == DatetimeKind::Time
-                            {
-                                warn!(
-                                    "{:?} kind with an interval - {:?}",
-                                    datetime_value.datetime_kind, interval
-                                );
-                            }
-                            let datetime_interval_output_value = DatetimeIntervalOutput {
-                                interval_kind: DatetimeIntervalKind::Between {
-                                    start: interval.start,
-                                    end: end,
-                                    precision: datetime_value.precision,
-                                    latent: datetime_value.latent,
-                                },
-                                datetime_kind: datetime_value.datetime_kind,
-                            };
-                            Output::DatetimeInterval(datetime_interval_output_value)
-                        } else {
-                            let datetime_output_value = DatetimeOutput {
-                                moment: interval.start,
-                                grain: interval.grain,
-                                precision: datetime_value.precision,
-                                latent: datetime_value.latent,
-                                datetime_kind: datetime_value.datetime_kind,
-                            };
-                            Output::Datetime(datetime_output_value)
-                        }
-                    })
+            })
+            .map(|interval| interval.start)
+            .min()?;
+        // The weekday of a day counted from the start of the week of the locale, and the day of
+        // "every 15th", are only known once resolved
+        let is_nth_day_of_week = |datetime: &DatetimeValue| match datetime.form {
+            Form::DayOfWeek { weekday: None, .. } => true,
+            _ => false,
+        };
+        let by_day = if recurrence.by_day.is_empty() && recurrence.datetimes.iter().any(is_nth_day_of_week) {
+            vec![start.weekday()]
+        } else {
+            recurrence.by_day.clone()
+        };
+        let by_month_day = recurrence.by_month_day.or_else(|| {
+            if recurrence.datetimes.iter().any(|datetime| datetime.form == Form::DayOfMonth) {
+                Some(start.day())
+            } else {
+                None
             }
+        });
+        // A time of day on the 12 hour clock, e.g. "at 5", is in the half of the day of the first
+        // occurrence
+        let by_hour = recurrence.by_hour.map(|hour| {
+            if hour % 12 == start.hour() % 12 {
+                start.hour()
+            } else {
+                hour
+            }
+        });
+        let until = recurrence
+            .until
+            .as_ref()
+            .and_then(|until| self.datetime_intervals(until, 1).into_iter().next())
+            .map(|interval| interval.end_moment());
+        Some(Output::Recurrence(RecurrenceOutput {
+            frequency: recurrence.frequency,
+            interval: recurrence.interval,
+            by_day,
+            by_month_day,
+            by_month: recurrence.by_month,
+            by_hour,
+            by_minute: recurrence.by_minute,
+            by_second: recurrence.by_second,
+            start,
+            until,
+            count: recurrence.count,
+        }))
+    }
+}
+
+/// Iterator over the intervals of a datetime value within a window, built by
+/// `ResolverContext::occurrences`.
+pub struct Occurrences {
+    walker: Walker<Interval<Tz>>,
+    start: Moment<Tz>,
+    horizon: Moment<Tz>,
+    remaining: usize,
+}
+
+impl Iterator for Occurrences {
+    type Item = Interval<Tz>;
+
+    fn next(&mut self) -> Option<Interval<Tz>> {
+        while self.remaining > 0 {
+            let interval = self.walker.next()?;
+            if interval.start >= self.horizon {
+                self.remaining = 0;
+            } else if interval.end_moment() > self.start {
+                self.remaining -= 1;
+                return Some(interval);
+            }
+        }
+        None
+    }
+}
+
+impl ParsingContext<Dimension> for ResolverContext {
+    type O = Output;
+
+    fn preference(&self, dim: &Dimension) -> Ordering {
+        match dim {
+            &Dimension::Datetime(DatetimeValue {
+                date_order: Some(date_order),
+                ..
+            }) => match self.locale.date_order {
+                // Without a configured order, the model alone chooses between the readings.
+                None => Ordering::Equal,
+                Some(locale_order) if locale_order == date_order => Ordering::Greater,
+                Some(_) => Ordering::Less,
+            },
+            _ => Ordering::Equal,
+        }
+    }
+
+    fn resolve(&self, dim: &Dimension) -> Option<Output> {
+        match dim {
+            &Dimension::Datetime(ref datetime_value) => self
+                .datetime_intervals(datetime_value, 1)
+                .into_iter()
+                .next()
+                .map(|interval| datetime_output(datetime_value, interval)),
             &Dimension::Number(ref number) => match number {
                 &NumberValue::Integer(ref v) => Some(Output::Integer(IntegerOutput(v.value))),
-                &NumberValue::Float(ref v) => Some(Output::Float(FloatOutput(v.value))),
+                &NumberValue::Float(ref v) => match v.fraction {
+                    Some(fraction) => Some(Output::Fraction(FractionOutput {
+                        value: v.value,
+                        numerator: fraction.numerator,
+                        denominator: fraction.denominator,
+                    })),
+                    None => Some(Output::Float(FloatOutput(v.value))),
+                },
             },
             &Dimension::Ordinal(ref ordinal) => Some(Output::Ordinal(OrdinalOutput(ordinal.value))),
             &Dimension::AmountOfMoney(ref aom) => {
@@ -168,7 +486,157 @@ impl ParsingContext<Dimension> for ResolverContext {
             &Dimension::Percentage(ref percentage) => {
                 Some(Output::Percentage(PercentageOutput(percentage.0)))
             }
+            &Dimension::Distance(ref distance) => Some(Output::Distance(DistanceOutput {
+                value: distance.value,
+                precision: distance.precision,
+                unit: distance.unit,
+                latent: distance.latent,
+            })),
+            &Dimension::Volume(ref volume) => Some(Output::Volume(VolumeOutput {
+                value: volume.value,
+                precision: volume.precision,
+                unit: volume.unit,
+                latent: volume.latent,
+            })),
+            &Dimension::Mass(ref mass) => Some(Output::Mass(MassOutput {
+                value: mass.value,
+                precision: mass.precision,
+                unit: mass.unit,
+                latent: mass.latent,
+            })),
+            &Dimension::Rate(ref rate) => Some(Output::Rate(RateOutput {
+                value: rate.value,
+                precision: rate.precision,
+                numerator_unit: rate.numerator_unit,
+                denominator_unit: rate.denominator_unit,
+            })),
+            &Dimension::QuantityRange(ref range) => quantity_range_output(range),
+            &Dimension::PhoneNumber(ref phone_number) => Some(Output::PhoneNumber(PhoneNumberOutput {
+                value: phone_number.value.clone(),
+            })),
+            &Dimension::Email(ref email) => Some(Output::Email(EmailOutput {
+                value: email.value.clone(),
+            })),
+            &Dimension::Url(ref url) => Some(Output::Url(UrlOutput {
+                value: url.value.clone(),
+            })),
+            &Dimension::Recurrence(ref recurrence) => self.recurrence_output(recurrence),
+            &Dimension::Age(ref age) => Some(Output::Age(AgeOutput {
+                value: age.value,
+                unit: age.unit,
+            })),
             _ => None,
         }
     }
 }
+
+/// Maps a range of quantities to the interval output of its kind of quantity.
+fn quantity_range_output(range: &QuantityRangeValue) -> Option<Output> {
+    let interval_kind = match (range.start, range.end) {
+        (Some(start), Some(end)) => QuantityIntervalKind::Between { start, end },
+        (Some(start), None) => QuantityIntervalKind::Above {
+            start,
+            inclusive: range.inclusive,
+        },
+        (None, Some(end)) => QuantityIntervalKind::Below {
+            end,
+            inclusive: range.inclusive,
+        },
+        (None, None) => return None,
+    };
+    let output = match range.quantity {
+        QuantityKind::Number => Output::NumberInterval(NumberIntervalOutput {
+            interval_kind,
+            precision: range.precision,
+        }),
+        QuantityKind::Ordinal => Output::OrdinalInterval(OrdinalIntervalOutput {
+            interval_kind: match interval_kind {
+                QuantityIntervalKind::Above { start, inclusive } => QuantityIntervalKind::Above {
+                    start: start as i64,
+                    inclusive,
+                },
+                QuantityIntervalKind::Below { end, inclusive } => QuantityIntervalKind::Below {
+                    end: end as i64,
+                    inclusive,
+                },
+                QuantityIntervalKind::Between { start, end } => QuantityIntervalKind::Between {
+                    start: start as i64,
+                    end: end as i64,
+                },
+            },
+        }),
+        QuantityKind::AmountOfMoney { unit } => {
+            Output::AmountOfMoneyInterval(AmountOfMoneyIntervalOutput {
+                interval_kind,
+                precision: range.precision,
+                unit,
+            })
+        }
+        QuantityKind::Temperature { unit } => {
+            Output::TemperatureInterval(TemperatureIntervalOutput { interval_kind, unit })
+        }
+    };
+    Some(output)
+}
+
+/// Maps a resolved interval of a datetime value to its output.
+fn datetime_output(datetime_value: &DatetimeValue, interval: Interval<Tz>) -> Output {
+    if let Some(bounded_direction) = datetime_value.direction {
+        let anchor = match bounded_direction.bound {
+            Bound::Start => interval.start,
+            Bound::End { only_interval } if only_interval => interval.end.unwrap_or(interval.start),
+            Bound::End { .. } => interval.end_moment(),
+        };
+        let datetime_output_value = DatetimeOutput {
+            moment: anchor,
+            grain: interval.grain,
+            precision: datetime_value.precision,
+            latent: datetime_value.latent,
+            datetime_kind: datetime_value.datetime_kind,
+        };
+        match bounded_direction.direction {
+            Direction::After => {
+                let datetime_interval_output_value = DatetimeIntervalOutput {
+                    interval_kind: DatetimeIntervalKind::After(datetime_output_value),
+                    datetime_kind: datetime_output_value.datetime_kind,
+                };
+                Output::DatetimeInterval(datetime_interval_output_value)
+            }
+            Direction::Before => {
+                let datetime_interval_output_value = DatetimeIntervalOutput {
+                    interval_kind: DatetimeIntervalKind::Before(datetime_output_value),
+                    datetime_kind: datetime_output_value.datetime_kind,
+                };
+                Output::DatetimeInterval(datetime_interval_output_value)
+            }
+        }
+    } else if let Some(end) = interval.end {
+        if datetime_value.datetime_kind == DatetimeKind::Date
+            || datetime_value.datetime_kind == DatetimeKind::Time
+        {
+            warn!(
+                "{:?} kind with an interval - {:?}",
+                datetime_value.datetime_kind, interval
+            );
+        }
+        let datetime_interval_output_value = DatetimeIntervalOutput {
+            interval_kind: DatetimeIntervalKind::Between {
+                start: interval.start,
+                end: end,
+                precision: datetime_value.precision,
+                latent: datetime_value.latent,
+            },
+            datetime_kind: datetime_value.datetime_kind,
+        };
+        Output::DatetimeInterval(datetime_interval_output_value)
+    } else {
+        let datetime_output_value = DatetimeOutput {
+            moment: interval.start,
+            grain: interval.grain,
+            precision: datetime_value.precision,
+            latent: datetime_value.latent,
+            datetime_kind: datetime_value.datetime_kind,
+        };
+        Output::Datetime(datetime_output_value)
+    }
+}
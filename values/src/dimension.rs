@@ -1,4 +1,4 @@
-use moment::{Grain, Local, Period, RcConstraint};
+use moment::{Grain, Period, RcConstraint, Tz, Weekday};
 use rustling::*;
 use std::{fmt, result};
 
@@ -16,6 +16,19 @@ rustling_value! {
         Datetime(DatetimeValue),
         Duration(DurationValue),
         Percentage(PercentageValue),
+        Distance(DistanceValue),
+        DistanceUnit(DistanceUnitValue),
+        Volume(VolumeValue),
+        VolumeUnit(VolumeUnitValue),
+        Mass(MassValue),
+        MassUnit(MassUnitValue),
+        Rate(RateValue),
+        QuantityRange(QuantityRangeValue),
+        PhoneNumber(PhoneNumberValue),
+        Email(EmailValue),
+        Url(UrlValue),
+        Recurrence(RecurrenceValue),
+        Age(AgeValue),
         Cycle(CycleValue),
         UnitOfDuration(UnitOfDurationValue),
         RelativeMinute(RelativeMinuteValue),
@@ -23,14 +36,27 @@ rustling_value! {
 
     fn latent(v: &Dimension) -> bool {
         match v {
-            &Dimension::Number(_) => false,
+            &Dimension::Number(ref number) => number.latent(),
             &Dimension::Percentage(_) => false,
             &Dimension::AmountOfMoney(_) => false,
-            &Dimension::Ordinal(_) => false,
+            &Dimension::Ordinal(ref ordinal) => ordinal.latent,
             &Dimension::Temperature(ref temp) => temp.latent,
             &Dimension::MoneyUnit(_) => true,
             &Dimension::Datetime(ref dtv) => dtv.latent,
             &Dimension::Duration(_) => false,
+            &Dimension::Distance(ref distance) => distance.latent,
+            &Dimension::DistanceUnit(_) => true,
+            &Dimension::Volume(ref volume) => volume.latent,
+            &Dimension::VolumeUnit(_) => true,
+            &Dimension::Mass(ref mass) => mass.latent,
+            &Dimension::MassUnit(_) => true,
+            &Dimension::Rate(_) => false,
+            &Dimension::QuantityRange(ref range) => range.latent,
+            &Dimension::PhoneNumber(ref phone_number) => phone_number.latent,
+            &Dimension::Email(_) => false,
+            &Dimension::Url(_) => false,
+            &Dimension::Recurrence(_) => false,
+            &Dimension::Age(ref age) => age.latent,
             &Dimension::Cycle(_) => true,
             &Dimension::UnitOfDuration(_) => true,
             &Dimension::RelativeMinute(_) => true,
@@ -47,6 +73,19 @@ rustling_value! {
             &Dimension::MoneyUnit(_) => None,
             &Dimension::Datetime(ref dtv) => Some(Payload(dtv.constraint.grain())),
             &Dimension::Duration(_) => None,
+            &Dimension::Distance(_) => None,
+            &Dimension::DistanceUnit(_) => None,
+            &Dimension::Volume(_) => None,
+            &Dimension::VolumeUnit(_) => None,
+            &Dimension::Mass(_) => None,
+            &Dimension::MassUnit(_) => None,
+            &Dimension::Rate(_) => None,
+            &Dimension::QuantityRange(_) => None,
+            &Dimension::PhoneNumber(_) => None,
+            &Dimension::Email(_) => None,
+            &Dimension::Url(_) => None,
+            &Dimension::Recurrence(ref recurrence) => Some(Payload(recurrence.datetimes[0].constraint.grain())),
+            &Dimension::Age(_) => None,
             &Dimension::Cycle(_) => None,
             &Dimension::UnitOfDuration(_) => None,
             &Dimension::RelativeMinute(_) => None,
@@ -65,6 +104,19 @@ impl Dimension {
             &Dimension::MoneyUnit(_) => false,
             &Dimension::Datetime(ref dtv) => dtv.is_too_ambiguous(),
             &Dimension::Duration(_) => false,
+            &Dimension::Distance(_) => false,
+            &Dimension::DistanceUnit(_) => false,
+            &Dimension::Volume(_) => false,
+            &Dimension::VolumeUnit(_) => false,
+            &Dimension::Mass(_) => false,
+            &Dimension::MassUnit(_) => false,
+            &Dimension::Rate(_) => false,
+            &Dimension::QuantityRange(_) => false,
+            &Dimension::PhoneNumber(_) => false,
+            &Dimension::Email(_) => false,
+            &Dimension::Url(_) => false,
+            &Dimension::Recurrence(_) => false,
+            &Dimension::Age(_) => false,
             &Dimension::Cycle(_) => true,
             &Dimension::UnitOfDuration(_) => true,
             &Dimension::RelativeMinute(_) => true,
@@ -89,6 +141,19 @@ impl fmt::Display for Dimension {
             &Dimension::MoneyUnit(_) => write!(fmt, "MoneyUnit"),
             &Dimension::Datetime(_) => write!(fmt, "Datetime"),
             &Dimension::Duration(_) => write!(fmt, "Duration"),
+            &Dimension::Distance(_) => write!(fmt, "Distance"),
+            &Dimension::DistanceUnit(_) => write!(fmt, "DistanceUnit"),
+            &Dimension::Volume(_) => write!(fmt, "Volume"),
+            &Dimension::VolumeUnit(_) => write!(fmt, "VolumeUnit"),
+            &Dimension::Mass(_) => write!(fmt, "Mass"),
+            &Dimension::MassUnit(_) => write!(fmt, "MassUnit"),
+            &Dimension::Rate(_) => write!(fmt, "Rate"),
+            &Dimension::QuantityRange(_) => write!(fmt, "QuantityRange"),
+            &Dimension::PhoneNumber(_) => write!(fmt, "PhoneNumber"),
+            &Dimension::Email(_) => write!(fmt, "Email"),
+            &Dimension::Url(_) => write!(fmt, "Url"),
+            &Dimension::Recurrence(_) => write!(fmt, "Recurrence"),
+            &Dimension::Age(_) => write!(fmt, "Age"),
             &Dimension::Cycle(_) => write!(fmt, "Cycle"),
             &Dimension::UnitOfDuration(_) => write!(fmt, "UnitOfDuration"),
             &Dimension::RelativeMinute(_) => write!(fmt, "RelativeMinute"),
@@ -102,6 +167,9 @@ pub struct OrdinalValue {
     pub value: i64,
     pub prefixed: bool,
     pub grain: Option<u8>,
+    /// true if it can not be confirmed that the value is actually an ordinal, e.g. a bare Roman
+    /// numeral
+    pub latent: bool,
 }
 
 impl OrdinalValue {
@@ -110,6 +178,7 @@ impl OrdinalValue {
             value,
             prefixed: false,
             grain: None,
+            latent: false,
         }
     }
 
@@ -118,6 +187,7 @@ impl OrdinalValue {
             value,
             prefixed: false,
             grain: Some(grain),
+            latent: false,
         }
     }
 
@@ -126,11 +196,18 @@ impl OrdinalValue {
             value: self.value,
             prefixed: true,
             grain: None,
+            latent: self.latent,
         }
     }
+
+    pub fn latent(self, latent: bool) -> OrdinalValue {
+        OrdinalValue { latent, ..self }
+    }
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Precision {
     Approximate,
     Exact,
@@ -156,6 +233,125 @@ pub struct MoneyUnitValue {
     pub unit: Option<&'static str>,
 }
 
+/// Payload for the distance value of Dimension
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct DistanceValue {
+    pub value: f64,
+    pub precision: Precision,
+    /// Canonical unit: km, m, cm, mm, mi, yd, ft or in
+    pub unit: Option<&'static str>,
+    /// true if the unit is an ambiguous abbreviation, e.g. "m"
+    pub latent: bool,
+}
+
+/// Payload for the unit of distance value of Dimension
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct DistanceUnitValue {
+    pub unit: Option<&'static str>,
+    pub latent: bool,
+}
+
+/// Payload for the volume value of Dimension
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct VolumeValue {
+    pub value: f64,
+    pub precision: Precision,
+    /// Canonical unit: l, dl, cl, ml, gal, pt, cup, fl oz, tbsp or tsp
+    pub unit: Option<&'static str>,
+    /// true if the unit is an ambiguous abbreviation, e.g. "l"
+    pub latent: bool,
+}
+
+/// Payload for the unit of volume value of Dimension
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct VolumeUnitValue {
+    pub unit: Option<&'static str>,
+    pub latent: bool,
+}
+
+/// Payload for the mass value of Dimension
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct MassValue {
+    pub value: f64,
+    pub precision: Precision,
+    /// Canonical unit: t, kg, g, mg, lb or oz
+    pub unit: Option<&'static str>,
+    /// true if the unit is an ambiguous abbreviation, e.g. "g"
+    pub latent: bool,
+}
+
+/// Payload for the unit of mass value of Dimension
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct MassUnitValue {
+    pub unit: Option<&'static str>,
+    pub latent: bool,
+}
+
+/// Payload for the rate value of Dimension, a quantity per unit such as a speed
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct RateValue {
+    pub value: f64,
+    pub precision: Precision,
+    /// Unit of the quantity, e.g. km or minute, None for a bare number
+    pub numerator_unit: Option<&'static str>,
+    /// Unit the quantity is counted per, e.g. hour or km
+    pub denominator_unit: &'static str,
+}
+
+/// Kind of the quantities bounding a range of quantities
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum QuantityKind {
+    Number,
+    Ordinal,
+    AmountOfMoney { unit: Option<&'static str> },
+    Temperature { unit: Option<&'static str> },
+}
+
+/// Payload for the range of quantities value of Dimension, e.g. "between 5 and 10 euros" or
+/// "under $50". A missing bound leaves the range open on its side.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct QuantityRangeValue {
+    pub quantity: QuantityKind,
+    pub start: Option<f64>,
+    pub end: Option<f64>,
+    /// Whether the bounds belong to the range, e.g. "at least 5" but not "more than 5"
+    pub inclusive: bool,
+    pub precision: Precision,
+    pub latent: bool,
+}
+
+impl QuantityRangeValue {
+    pub fn latent(self, latent: bool) -> QuantityRangeValue {
+        QuantityRangeValue { latent, ..self }
+    }
+}
+
+/// Payload for the phone number value of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct PhoneNumberValue {
+    /// Digits of the number, prefixed with + when an international prefix was given
+    pub value: String,
+    pub latent: bool,
+}
+
+impl PhoneNumberValue {
+    pub fn latent(self, latent: bool) -> PhoneNumberValue {
+        PhoneNumberValue { latent, ..self }
+    }
+}
+
+/// Payload for the email address value of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct EmailValue {
+    pub value: String,
+}
+
+/// Payload for the url value of Dimension
+#[derive(Debug, PartialEq, Clone)]
+pub struct UrlValue {
+    pub value: String,
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum CombinationDirection {
     Left,
@@ -178,6 +374,9 @@ pub struct IntegerValue {
     pub combine_from: Option<CombinationDirection>,
     #[doc(hidden)]
     pub precision: Precision,
+    /// true if it can not be confirmed that the value is actually a number, e.g. a bare Roman
+    /// numeral
+    pub latent: bool,
 }
 
 impl IntegerValue {
@@ -322,6 +521,18 @@ pub struct FloatValue {
     pub combine_from: Option<CombinationDirection>,
     #[doc(hidden)]
     pub precision: Precision,
+    /// Exact rational value of a number expressed as a fraction, e.g. 3/4 for "three quarters"
+    pub fraction: Option<Fraction>,
+    /// true if it can not be confirmed that the value is actually a number, e.g. "5/6" may be a
+    /// date
+    pub latent: bool,
+}
+
+/// Rational value of a fractional number, reduced to its lowest terms
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Fraction {
+    pub numerator: i64,
+    pub denominator: i64,
 }
 
 impl FloatValue {
@@ -332,6 +543,10 @@ impl FloatValue {
         })
     }
 
+    pub fn latent(self, latent: bool) -> FloatValue {
+        FloatValue { latent, ..self }
+    }
+
     #[doc(hidden)]
     pub fn combine_from(self, direction: CombinationDirection) -> RuleResult<FloatValue> {
         Ok(FloatValue {
@@ -376,6 +591,13 @@ pub enum NumberValue {
 }
 
 impl NumberValue {
+    pub fn precision(&self) -> Precision {
+        match self {
+            &NumberValue::Float(ref v) => v.precision,
+            &NumberValue::Integer(ref v) => v.precision,
+        }
+    }
+
     #[doc(hidden)]
     pub fn prefixed(&self) -> bool {
         match self {
@@ -435,6 +657,20 @@ impl NumberValue {
         }
     }
 
+    pub fn latent(&self) -> bool {
+        match self {
+            &NumberValue::Float(ref v) => v.latent,
+            &NumberValue::Integer(ref v) => v.latent,
+        }
+    }
+
+    pub fn fraction(&self) -> Option<Fraction> {
+        match self {
+            &NumberValue::Float(ref v) => v.fraction,
+            &NumberValue::Integer(_) => None,
+        }
+    }
+
     #[doc(hidden)]
     pub fn grain(&self) -> Option<u8> {
         match self {
@@ -454,6 +690,75 @@ pub struct TemperatureValue {
     pub latent: bool,
 }
 
+/// Payload for the age value of Dimension, e.g. "7 years old" or "a 18-month-old".
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct AgeValue {
+    pub value: f64,
+    pub unit: Grain,
+    /// true if it can not be confirmed that the value is actually an age, e.g. "j'ai 3 ans"
+    pub latent: bool,
+}
+
+impl AgeValue {
+    pub fn latent(self, latent: bool) -> AgeValue {
+        AgeValue { latent, ..self }
+    }
+}
+
+/// Payload for the recurrence value of Dimension, e.g. "every monday at 5pm". The frequency and
+/// the `by_*` fields are set by the rules, the datetimes, e.g. "monday" and "wednesday" in "every
+/// monday and wednesday", only give the first occurrence.
+#[derive(Debug, PartialEq, Clone)]
+pub struct RecurrenceValue {
+    pub datetimes: Vec<DatetimeValue>,
+    pub frequency: Grain,
+    pub interval: i64,
+    pub by_day: Vec<Weekday>,
+    pub by_month_day: Option<u32>,
+    pub by_month: Option<u32>,
+    pub by_hour: Option<u32>,
+    pub by_minute: Option<u32>,
+    pub by_second: Option<u32>,
+    pub count: Option<i64>,
+    pub until: Option<DatetimeValue>,
+}
+
+impl RecurrenceValue {
+    pub fn new(datetime: DatetimeValue, frequency: Grain) -> RecurrenceValue {
+        RecurrenceValue {
+            datetimes: vec![datetime],
+            frequency,
+            interval: 1,
+            by_day: vec![],
+            by_month_day: None,
+            by_month: None,
+            by_hour: None,
+            by_minute: None,
+            by_second: None,
+            count: None,
+            until: None,
+        }
+    }
+
+    pub fn interval(self, interval: i64) -> RecurrenceValue {
+        RecurrenceValue { interval, ..self }
+    }
+
+    pub fn count(self, count: i64) -> RecurrenceValue {
+        RecurrenceValue {
+            count: Some(count),
+            ..self
+        }
+    }
+
+    pub fn until(self, until: DatetimeValue) -> RecurrenceValue {
+        RecurrenceValue {
+            until: Some(until),
+            ..self
+        }
+    }
+}
+
 /// Payload for the cycle value of Dimension
 #[derive(Debug, PartialEq, Clone)]
 pub struct CycleValue {
@@ -507,16 +812,28 @@ pub enum DatetimeKind {
     Empty,
 }
 
+/// Order of the day, the month and the year in a date written with numbers only, e.g.
+/// "05/06/2013".
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Copy)]
+pub enum DateOrder {
+    DayMonthYear,
+    MonthDayYear,
+    YearMonthDay,
+}
+
 /// Payload for the datetime value of Dimension
 #[derive(Clone)]
 pub struct DatetimeValue {
-    pub constraint: RcConstraint<Local>,
+    pub constraint: RcConstraint<Tz>,
     pub form: Form,
     pub direction: Option<BoundedDirection>,
     pub precision: Precision,
     pub latent: bool,
     pub ambiguity: Ambiguity,
     pub datetime_kind: DatetimeKind,
+    /// Order assumed to read a date written with numbers only, set on the readings which depart
+    /// from the usual order of the language when other orders would give a valid date too.
+    pub date_order: Option<DateOrder>,
 }
 
 // We need partial eq to make Dimension partial eq happy, but this is only
@@ -613,7 +930,7 @@ pub enum Form {
     MonthDay(Option<MonthDayForm>),
     YearMonthDay(Option<YearMonthDayForm>),
     TimeOfDay(TimeOfDayForm),
-    DayOfWeek { not_immediate: bool },
+    DayOfWeek { not_immediate: bool, weekday: Option<Weekday> },
     PartOfDay(PartOfDayForm),
     PartOfWeek,
     PartOfMonth,
@@ -635,7 +952,7 @@ impl Form {
             &Form::MonthDay(_) => None,
             &Form::YearMonthDay(_) => None,
             &Form::TimeOfDay(_) => None,
-            &Form::DayOfWeek { not_immediate } => Some(not_immediate),
+            &Form::DayOfWeek { not_immediate, .. } => Some(not_immediate),
             &Form::Empty => None,
             &Form::PartOfDay { .. } => None,
             &Form::Meal => None,
@@ -672,6 +989,24 @@ impl Form {
             _ => false
         }
     }
+
+    /// Whether the form repeats on the calendar, e.g. a day of week or a time of day, as opposed
+    /// to a single date.
+    pub fn is_recurrent(&self) -> bool {
+        match self {
+            &Form::Month(_) => true,
+            &Form::DayOfMonth => true,
+            &Form::MonthDay(_) => true,
+            &Form::TimeOfDay(_) => true,
+            &Form::DayOfWeek { .. } => true,
+            &Form::PartOfDay(_) => true,
+            &Form::PartOfWeek => true,
+            &Form::Season => true,
+            &Form::Meal => true,
+            &Form::Celebration => true,
+            _ => false,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
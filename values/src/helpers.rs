@@ -99,6 +99,515 @@ pub fn compose_money_number(
     })
 }
 
+pub fn compose_distance(a: &NumberValue, b: &DistanceUnitValue) -> RuleResult<DistanceValue> {
+    Ok(DistanceValue {
+        value: a.value(),
+        precision: a.precision(),
+        unit: b.unit,
+        latent: b.latent,
+    })
+}
+
+pub fn compose_volume(a: &NumberValue, b: &VolumeUnitValue) -> RuleResult<VolumeValue> {
+    Ok(VolumeValue {
+        value: a.value(),
+        precision: a.precision(),
+        unit: b.unit,
+        latent: b.latent,
+    })
+}
+
+pub fn compose_mass(a: &NumberValue, b: &MassUnitValue) -> RuleResult<MassValue> {
+    Ok(MassValue {
+        value: a.value(),
+        precision: a.precision(),
+        unit: b.unit,
+        latent: b.latent,
+    })
+}
+
+/// Age of a number of years, months, weeks or days, e.g. "7 years old".
+pub fn age(number: &NumberValue, unit: Grain) -> RuleResult<AgeValue> {
+    let max = match unit {
+        Grain::Year => 150.0,
+        Grain::Month => 150.0 * 12.0,
+        Grain::Week => 150.0 * 52.0,
+        Grain::Day => 150.0 * 365.0,
+        _ => return Err(RuleError::Invalid.into()),
+    };
+    if number.value() < 0.0 || number.value() > max {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(AgeValue {
+        value: number.value(),
+        unit,
+        latent: false,
+    })
+}
+
+/// Recurrence of the occurrences of a datetime, one every `interval` of them, e.g. "every other
+/// monday". The frequency and the `by_*` fields follow from the form of the datetime.
+pub fn recurrence(datetime: &DatetimeValue, interval: i64) -> RuleResult<RecurrenceValue> {
+    if interval <= 0 {
+        return Err(RuleError::Invalid.into());
+    }
+    let frequency = match datetime.form {
+        Form::Cycle(grain) => grain,
+        Form::TimeOfDay(_) | Form::PartOfDay(_) | Form::Meal => Grain::Day,
+        Form::DayOfWeek { .. } | Form::PartOfWeek => Grain::Week,
+        Form::DayOfMonth => Grain::Month,
+        Form::Month(_) | Form::MonthDay(_) | Form::Season | Form::Celebration => Grain::Year,
+        _ => return Err(RuleError::Invalid.into()),
+    };
+    Ok(RecurrenceValue::new(datetime.clone(), frequency)
+        .interval(interval)
+        .pin(&datetime.form))
+}
+
+/// Recurrence of a cycle, e.g. "daily" or "every 2 weeks". Quarters recur every 3 months.
+pub fn recurrence_cycle(grain: Grain, interval: i64) -> RuleResult<RecurrenceValue> {
+    let recurrence = recurrence(&cycle(grain)?, interval)?;
+    match grain {
+        Grain::Quarter => Ok(RecurrenceValue {
+            frequency: Grain::Month,
+            interval: 3 * interval,
+            ..recurrence
+        }),
+        _ => Ok(recurrence),
+    }
+}
+
+impl RecurrenceValue {
+    /// Restricts the occurrences to the given datetime, e.g. "every day" "at 8am". The datetimes
+    /// keep their form so that the resolution knows what they stand for.
+    pub fn intersect(&self, other: &DatetimeValue) -> RuleResult<RecurrenceValue> {
+        let datetimes = self
+            .datetimes
+            .iter()
+            .map(|datetime| Ok(datetime.intersect(other)?.form(datetime.form.clone())))
+            .collect::<RuleResult<Vec<_>>>()?;
+        Ok(RecurrenceValue {
+            datetimes,
+            ..self.clone()
+        }
+        .pin(&other.form))
+    }
+
+    /// Adds the occurrences of another day of the week, e.g. "every monday" "and wednesday".
+    pub fn and(&self, other: &DatetimeValue) -> RuleResult<RecurrenceValue> {
+        match other.form {
+            Form::DayOfWeek {
+                weekday: Some(weekday),
+                ..
+            } if !self.by_day.is_empty() && !self.by_day.contains(&weekday) => {
+                let mut recurrence = self.clone();
+                recurrence.datetimes.push(other.clone());
+                recurrence.by_day.push(weekday);
+                Ok(recurrence)
+            }
+            _ => Err(RuleError::Invalid.into()),
+        }
+    }
+
+    /// Sets the `by_*` fields pinned by the form of a datetime, e.g. the day of "monday" or the
+    /// time of "at 5pm".
+    fn pin(self, form: &Form) -> RecurrenceValue {
+        match form {
+            &Form::DayOfWeek {
+                weekday: Some(weekday),
+                ..
+            } => RecurrenceValue {
+                by_day: vec![weekday],
+                ..self
+            },
+            &Form::TimeOfDay(ref time_of_day) => {
+                let (minute, second) = match time_of_day {
+                    &TimeOfDayForm::Hour { .. } => (0, None),
+                    &TimeOfDayForm::HourMinute { minute, .. } => (minute, None),
+                    &TimeOfDayForm::HourMinuteSecond { minute, second, .. } => (minute, Some(second)),
+                };
+                RecurrenceValue {
+                    by_hour: Some(time_of_day.get_hour()),
+                    by_minute: Some(minute),
+                    by_second: second,
+                    ..self
+                }
+            }
+            &Form::Month(month) => RecurrenceValue {
+                by_month: Some(month),
+                ..self
+            },
+            &Form::MonthDay(Some(month_day)) => RecurrenceValue {
+                by_month: Some(month_day.month),
+                by_month_day: Some(month_day.day_of_month),
+                ..self
+            },
+            _ => self,
+        }
+    }
+}
+
+/// Phone number made of its digits only, the international prefix "00" being normalized to "+".
+pub fn phone_number(text: &str) -> RuleResult<PhoneNumberValue> {
+    let text = text.trim();
+    let (prefix, number) = if text.starts_with('+') {
+        ("+", &text[1..])
+    } else if text.starts_with("00") {
+        ("+", &text[2..])
+    } else {
+        ("", text)
+    };
+    let digits = number.chars().filter(|c| c.is_digit(10)).collect::<String>();
+    if digits.len() < 7 || digits.len() > 15 {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(PhoneNumberValue {
+        value: format!("{}{}", prefix, digits),
+        latent: false,
+    })
+}
+
+/// Email address built from its local part and its domain, e.g. "john.doe" and "gmail.com".
+pub fn email(local: &str, domain: &str) -> RuleResult<EmailValue> {
+    let local = local.trim().to_lowercase();
+    let domain = domain.trim().trim_end_matches('.').to_lowercase();
+    let valid_domain = domain.contains('.')
+        && domain.split('.').all(|label| !label.is_empty() && !label.starts_with('-') && !label.ends_with('-'));
+    if local.is_empty() || local.starts_with('.') || local.ends_with('.') || !valid_domain {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(EmailValue { value: format!("{}@{}", local, domain) })
+}
+
+/// Email address spelled out, e.g. "john dot doe" at "gmail dot com", given the words standing
+/// for symbols in the language. Words made of several tokens must come before their prefixes.
+pub fn spoken_email(local: &str, domain: &str, symbols: &[(&str, &str)]) -> RuleResult<EmailValue> {
+    let spell = |text: &str| {
+        let mut text = format!(" {} ", text);
+        for &(word, symbol) in symbols {
+            text = text.replace(&format!(" {} ", word), symbol);
+        }
+        text.split_whitespace().collect::<String>()
+    };
+    email(&spell(local), &spell(domain))
+}
+
+/// Url as written in the sentence, without the trailing punctuation of the sentence.
+pub fn url(text: &str) -> RuleResult<UrlValue> {
+    let value = text.trim().trim_end_matches(|c| ".,;:!?)".contains(c));
+    if value.is_empty() {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(UrlValue { value: value.to_string() })
+}
+
+/// Value of a Roman numeral written in its canonical form, e.g. "xiv" but not "xiiii", from 1 to
+/// 3999.
+pub fn roman_numeral(text: &str) -> RuleResult<i64> {
+    let digit = |c: char| match c {
+        'i' => Some(1),
+        'v' => Some(5),
+        'x' => Some(10),
+        'l' => Some(50),
+        'c' => Some(100),
+        'd' => Some(500),
+        'm' => Some(1000),
+        _ => None,
+    };
+    let digits = text
+        .to_lowercase()
+        .chars()
+        .map(digit)
+        .collect::<Option<Vec<i64>>>()
+        .ok_or(RuleError::Invalid)?;
+    let value = digits.iter().enumerate().fold(0, |value, (i, &d)| {
+        match digits.get(i + 1) {
+            Some(&next) if next > d => value - d,
+            _ => value + d,
+        }
+    });
+    // Only the canonical writing of the value is accepted
+    if value <= 0 || value >= 4000 || to_roman_numeral(value) != text.to_lowercase() {
+        return Err(RuleError::Invalid.into());
+    }
+    Ok(value)
+}
+
+fn to_roman_numeral(mut value: i64) -> String {
+    let symbols = [
+        (1000, "m"), (900, "cm"), (500, "d"), (400, "cd"),
+        (100, "c"), (90, "xc"), (50, "l"), (40, "xl"),
+        (10, "x"), (9, "ix"), (5, "v"), (4, "iv"), (1, "i"),
+    ];
+    let mut numeral = String::new();
+    for &(symbol_value, symbol) in symbols.iter() {
+        while value >= symbol_value {
+            numeral.push_str(symbol);
+            value -= symbol_value;
+        }
+    }
+    numeral
+}
+
+/// Number expressed as the fraction numerator/denominator, e.g. "3/4" or "two thirds".
+pub fn fraction(numerator: i64, denominator: i64) -> RuleResult<FloatValue> {
+    if numerator < 0 || denominator <= 0 {
+        return Err(RuleError::Invalid.into());
+    }
+    let gcd = gcd(numerator, denominator);
+    Ok(FloatValue {
+        value: numerator as f64 / denominator as f64,
+        fraction: Some(Fraction {
+            numerator: numerator / gcd,
+            denominator: denominator / gcd,
+        }),
+        ..FloatValue::default()
+    })
+}
+
+/// Mixed number made of an integer and a proper fraction, e.g. "2 1/2" or "one and a half".
+pub fn mixed_number(integer: &IntegerValue, fraction: &NumberValue) -> RuleResult<FloatValue> {
+    let fraction = fraction.fraction().ok_or(RuleError::Invalid)?;
+    if integer.value < 0 || fraction.numerator >= fraction.denominator {
+        return Err(RuleError::Invalid.into());
+    }
+    self::fraction(
+        integer.value * fraction.denominator + fraction.numerator,
+        fraction.denominator,
+    )
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Duration of a fractional number of units of duration, e.g. "one and a half hours". The
+/// fractional part is carried to the finer grains, a month counting 30 days.
+pub fn fractional_duration(
+    number: &NumberValue,
+    uod: &UnitOfDurationValue,
+) -> RuleResult<DurationValue> {
+    let mut value = number.value();
+    if value <= 0.0 {
+        return Err(RuleError::Invalid.into());
+    }
+    let mut grain = uod.grain;
+    let mut period = Period::default();
+    loop {
+        // Absorb the rounding errors of the float conversions, e.g. 0.1 * 60
+        let whole = (value + 1e-6).floor();
+        if whole >= 1.0 {
+            period = period + PeriodComp::new(grain, whole as i64);
+        }
+        let rest = value - whole;
+        if rest < 1e-6 {
+            break;
+        }
+        let (finer_grain, factor) = match grain {
+            Grain::Year => (Grain::Month, 12.0),
+            Grain::Quarter => (Grain::Month, 3.0),
+            Grain::Month => (Grain::Day, 30.0),
+            Grain::Week => (Grain::Day, 7.0),
+            Grain::Day => (Grain::Hour, 24.0),
+            Grain::Hour => (Grain::Minute, 60.0),
+            Grain::Minute => (Grain::Second, 60.0),
+            Grain::Second => break,
+        };
+        value = rest * factor;
+        grain = finer_grain;
+    }
+    Ok(DurationValue::new(period))
+}
+
+/// A quantity which can bound a range of quantities.
+pub trait RangeBound {
+    fn range_quantity(&self) -> QuantityKind;
+    fn range_value(&self) -> f64;
+    fn range_precision(&self) -> Precision {
+        Precision::Exact
+    }
+}
+
+impl RangeBound for NumberValue {
+    fn range_quantity(&self) -> QuantityKind {
+        QuantityKind::Number
+    }
+    fn range_value(&self) -> f64 {
+        self.value()
+    }
+}
+
+impl RangeBound for OrdinalValue {
+    fn range_quantity(&self) -> QuantityKind {
+        QuantityKind::Ordinal
+    }
+    fn range_value(&self) -> f64 {
+        self.value as f64
+    }
+}
+
+impl RangeBound for AmountOfMoneyValue {
+    fn range_quantity(&self) -> QuantityKind {
+        QuantityKind::AmountOfMoney { unit: self.unit }
+    }
+    fn range_value(&self) -> f64 {
+        self.value
+    }
+    fn range_precision(&self) -> Precision {
+        self.precision
+    }
+}
+
+impl RangeBound for TemperatureValue {
+    fn range_quantity(&self) -> QuantityKind {
+        QuantityKind::Temperature { unit: self.unit }
+    }
+    fn range_value(&self) -> f64 {
+        self.value
+    }
+}
+
+/// Range between two quantities, bounds included, e.g. "between 20 and 30 euros". A bare number
+/// takes the kind of the other bound, two bare numbers give a range of numbers, and the start must
+/// be lower than the end.
+pub fn quantity_between<A: RangeBound, B: RangeBound>(
+    start: &A,
+    end: &B,
+) -> RuleResult<QuantityRangeValue> {
+    let quantity = match (start.range_quantity(), end.range_quantity()) {
+        (QuantityKind::Number, QuantityKind::Number) => QuantityKind::Number,
+        (QuantityKind::Number, QuantityKind::Ordinal)
+        | (QuantityKind::Ordinal, QuantityKind::Number) => return Err(RuleError::Invalid.into()),
+        (QuantityKind::Number, quantity) | (quantity, QuantityKind::Number) => quantity,
+        (QuantityKind::Ordinal, QuantityKind::Ordinal) => QuantityKind::Ordinal,
+        (QuantityKind::AmountOfMoney { unit: a }, QuantityKind::AmountOfMoney { unit: b }) => {
+            QuantityKind::AmountOfMoney {
+                unit: merge_range_units(a, b)?,
+            }
+        }
+        (QuantityKind::Temperature { unit: a }, QuantityKind::Temperature { unit: b }) => {
+            QuantityKind::Temperature {
+                unit: merge_range_units(a, b)?,
+            }
+        }
+        _ => return Err(RuleError::Invalid.into()),
+    };
+    if start.range_value() >= end.range_value() {
+        return Err(RuleError::Invalid.into());
+    }
+    let precision = match (start.range_precision(), end.range_precision()) {
+        (Precision::Exact, Precision::Exact) => Precision::Exact,
+        _ => Precision::Approximate,
+    };
+    Ok(QuantityRangeValue {
+        quantity,
+        start: Some(start.range_value()),
+        end: Some(end.range_value()),
+        inclusive: true,
+        precision,
+        latent: false,
+    })
+}
+
+/// Range open above a quantity, e.g. "more than 5".
+pub fn quantity_above<A: RangeBound>(start: &A) -> RuleResult<QuantityRangeValue> {
+    Ok(QuantityRangeValue {
+        quantity: start.range_quantity(),
+        start: Some(start.range_value()),
+        end: None,
+        inclusive: false,
+        precision: start.range_precision(),
+        latent: false,
+    })
+}
+
+/// Range open above a quantity, including it, e.g. "at least 5" or "5 or more".
+pub fn quantity_at_least<A: RangeBound>(start: &A) -> RuleResult<QuantityRangeValue> {
+    Ok(QuantityRangeValue {
+        inclusive: true,
+        ..quantity_above(start)?
+    })
+}
+
+/// Range open below a quantity, e.g. "under $50".
+pub fn quantity_below<A: RangeBound>(end: &A) -> RuleResult<QuantityRangeValue> {
+    Ok(QuantityRangeValue {
+        quantity: end.range_quantity(),
+        start: None,
+        end: Some(end.range_value()),
+        inclusive: false,
+        precision: end.range_precision(),
+        latent: false,
+    })
+}
+
+/// Range open below a quantity, including it, e.g. "up to $50" or "$50 or less".
+pub fn quantity_at_most<A: RangeBound>(end: &A) -> RuleResult<QuantityRangeValue> {
+    Ok(QuantityRangeValue {
+        inclusive: true,
+        ..quantity_below(end)?
+    })
+}
+
+fn merge_range_units(
+    a: Option<&'static str>,
+    b: Option<&'static str>,
+) -> RuleResult<Option<&'static str>> {
+    match (a, b) {
+        (Some(a), Some(b)) if a != b => Err(RuleError::Invalid.into()),
+        _ => Ok(a.or(b)),
+    }
+}
+
+/// Name of the unit of a grain, used as the unit of a rate.
+pub fn grain_unit(grain: Grain) -> &'static str {
+    match grain {
+        Grain::Year => "year",
+        Grain::Quarter => "quarter",
+        Grain::Month => "month",
+        Grain::Week => "week",
+        Grain::Day => "day",
+        Grain::Hour => "hour",
+        Grain::Minute => "minute",
+        Grain::Second => "second",
+    }
+}
+
+/// Rate of a quantity, e.g. 60 miles, per a unit of duration.
+pub fn rate_per_duration(
+    value: f64,
+    numerator_unit: Option<&'static str>,
+    precision: Precision,
+    per: &UnitOfDurationValue,
+) -> RuleResult<RateValue> {
+    Ok(RateValue {
+        value,
+        precision,
+        numerator_unit,
+        denominator_unit: grain_unit(per.grain),
+    })
+}
+
+/// Rate of a duration per a unit of distance, e.g. 5 minutes per km. The duration is expressed
+/// in its finest grain, "5 minutes 30 seconds" becomes 330 seconds.
+pub fn duration_per_distance_unit(
+    duration: &DurationValue,
+    per: &DistanceUnitValue,
+) -> RuleResult<RateValue> {
+    let grain = duration.period.finer_grain().ok_or(RuleError::Invalid)?;
+    let denominator_unit = per.unit.ok_or(RuleError::Invalid)?;
+    Ok(RateValue {
+        value: duration.period.coarse_num_secs() as f64 / grain.coarse_num_secs() as f64,
+        precision: duration.precision,
+        numerator_unit: Some(grain_unit(grain)),
+        denominator_unit,
+    })
+}
+
 impl Form {
     fn time_of_day_hour(full_hour: u32, is_12_clock: bool) -> Form {
         Form::TimeOfDay(TimeOfDayForm::hour(full_hour, is_12_clock))
@@ -180,7 +689,7 @@ fn from_addition_resolution(lhs: Option<FromAddition>, rhs: Option<FromAddition>
     }
 }
 impl DatetimeValue {
-    pub fn constraint(constraint: RcConstraint<Local>) -> DatetimeValue {
+    pub fn constraint(constraint: RcConstraint<Tz>) -> DatetimeValue {
         DatetimeValue {
             constraint,
             form: Form::Empty,
@@ -191,6 +700,7 @@ impl DatetimeValue {
             // Could be filled through rules too, not only in tagger
             // (but could be overridden before the end of parsing)
             datetime_kind: DatetimeKind::Empty,
+            date_order: None,
         }
     }
 
@@ -296,6 +806,10 @@ impl DatetimeValue {
         }
     }
 
+    pub fn date_order(self, date_order: Option<DateOrder>) -> DatetimeValue {
+        DatetimeValue { date_order, ..self }
+    }
+
     pub fn precision(self, precision: Precision) -> DatetimeValue {
         DatetimeValue { precision, ..self }
     }
@@ -304,6 +818,7 @@ impl DatetimeValue {
         Ok(
             DatetimeValue::constraint(self.constraint.intersect(&other.constraint))
                 .direction(self.direction.or(other.direction))
+                .date_order(self.date_order.or(other.date_order))
                 .precision(precision_resolution(self.precision, other.precision)),
         )
     }
@@ -488,6 +1003,7 @@ pub fn day_of_week(weekday: Weekday) -> RuleResult<DatetimeValue> {
     Ok(
         DatetimeValue::constraint(DayOfWeek::new(weekday)).form(Form::DayOfWeek {
             not_immediate: true,
+            weekday: Some(weekday),
         }),
     )
 }
@@ -629,18 +1145,83 @@ pub fn cycle_n_not_immediate(grain: Grain, n: i64) -> RuleResult<DatetimeValue>
     Ok(DatetimeValue::constraint(Cycle::rc(grain).take_not_immediate(n)).form(Form::Cycle(grain)))
 }
 
+/// N-th day of the week, 0 being the first day of the week of the resolver context, counted from
+/// its last day when negative.
+pub fn nth_day_of_week(n: i64) -> RuleResult<DatetimeValue> {
+    Ok(
+        DatetimeValue::constraint(NthDayOfWeek::new(n)).form(Form::DayOfWeek {
+            not_immediate: true,
+            weekday: None,
+        }),
+    )
+}
+
+/// ISO 8601 week of the year, e.g. "week 42".
+pub fn week_of_year(w: u32) -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue::constraint(WeekOfYear::new(w).invalid_if_err()?))
+}
+
+/// N-th working day after today, 0 being the next one, e.g. "in 3 business days" is the 2nd one.
+/// The previous working days are counted backward from -1.
+pub fn business_day_nth(n: i64) -> RuleResult<DatetimeValue> {
+    let constraint = if n >= 0 {
+        BusinessDay::rc().take_the_nth_not_immediate(n)
+    } else {
+        BusinessDay::rc().take_the_nth(n)
+    };
+    Ok(DatetimeValue::constraint(constraint).datetime_kind(DatetimeKind::Date))
+}
+
+/// Working hours of the business days, e.g. "during business hours".
+pub fn business_hours() -> RuleResult<DatetimeValue> {
+    Ok(DatetimeValue::constraint(BusinessHours::rc()).datetime_kind(DatetimeKind::TimePeriod))
+}
+
+/// Closing time of the business days, e.g. "end of business".
+pub fn end_of_business() -> RuleResult<DatetimeValue> {
+    fn closing(i: &Interval<Tz>, _: &Context<Tz>) -> Option<Interval<Tz>> {
+        Some(Interval::starting_at(i.end_moment(), Grain::Minute))
+    }
+    Ok(
+        DatetimeValue::constraint(BusinessHours::rc().translate_with(closing))
+            .datetime_kind(DatetimeKind::Time),
+    )
+}
+
+/// N-th quarter or year of the fiscal calendar from the current one, e.g. "next fiscal quarter".
+pub fn fiscal_cycle_nth(grain: Grain, n: i64) -> RuleResult<DatetimeValue> {
+    Ok(
+        DatetimeValue::constraint(FiscalCycle::rc(grain).take_the_nth(n))
+            .datetime_kind(DatetimeKind::DatePeriod),
+    )
+}
+
+/// N-th quarter of the fiscal years, from 1 to 4.
+pub fn fiscal_quarter(q: u32) -> RuleResult<DatetimeValue> {
+    Ok(
+        DatetimeValue::constraint(FiscalQuarter::new(q).invalid_if_err()?)
+            .datetime_kind(DatetimeKind::DatePeriod),
+    )
+}
+
+/// Fiscal year ending in the given year, e.g. "FY24".
+pub fn fiscal_year(y: i32) -> RuleResult<DatetimeValue> {
+    let y = normalize_year(y)?;
+    Ok(DatetimeValue::constraint(FiscalYear::new(y)).datetime_kind(DatetimeKind::DatePeriod))
+}
+
+/// Weekends of the context, from 18:00 on the eve of their first day, e.g. "this weekend".
 pub fn weekend() -> RuleResult<DatetimeValue> {
-    let friday = day_of_week(Weekday::Fri)?.intersect(&hour(18, false)?)?;
-    let monday = day_of_week(Weekday::Mon)?.intersect(&hour(0, false)?)?;
-    Ok(friday
-        .span_to(&monday, false)?
-        .datetime_kind(DatetimeKind::DatePeriod))
+    Ok(DatetimeValue::constraint(Weekend::rc()).datetime_kind(DatetimeKind::DatePeriod))
 }
 
 pub fn easter() -> RuleResult<DatetimeValue> {
-    fn offset(i: &Interval<Local>, _: &Context<Local>) -> Option<Interval<Local>> {
+    fn offset(i: &Interval<Tz>, _: &Context<Tz>) -> Option<Interval<Tz>> {
         let (year, month, day) = computer_easter(i.start.year());
-        Some(Interval::ymd(year, month, day))
+        Some(Interval::starting_at(
+            Moment(i.timezone().ymd(year, month, day).and_hms(0, 0, 0)),
+            Grain::Day,
+        ))
     }
     Ok(
         DatetimeValue::constraint(Month::new(3).invalid_if_err()?.translate_with(offset))
@@ -667,6 +1248,62 @@ pub fn computer_easter(year: i32) -> (i32, u32, u32) {
     (year, month, day)
 }
 
+/// Day of a month of a non-gregorian calendar, e.g. the 1st day of the 1st month of the chinese
+/// calendar for the Chinese New Year.
+pub fn calendar_date(calendar: Calendar, month: u32, day: u32) -> RuleResult<DatetimeValue> {
+    Ok(
+        DatetimeValue::constraint(CalendarDate::new(calendar, month, day).invalid_if_err()?)
+            .datetime_kind(DatetimeKind::Date),
+    )
+}
+
+/// Year of a japanese era, e.g. 2013 for 平成25年.
+pub fn japanese_era_year(era: JapaneseEra, y: i32) -> RuleResult<DatetimeValue> {
+    match era.to_gregorian_year(y) {
+        Some(y) => year(y),
+        None => Err(RuleError::Invalid.into()),
+    }
+}
+
+/// The n-th given day of the week of a month, e.g. the 2nd friday of march. A negative n counts
+/// from the end of the month, -1 being the last one.
+pub fn nth_day_of_week_of_month(n: i64, weekday: Weekday, m: u32) -> RuleResult<DatetimeValue> {
+    if n == 0 || n.abs() > 5 {
+        return Err(RuleError::Invalid.into());
+    }
+    let offset = move |i: &Interval<Tz>, _: &Context<Tz>| -> Option<Interval<Tz>> {
+        let year = i.start.year();
+        let day = computer_nth_day_of_week(year, m, n, weekday)?;
+        Some(Interval::starting_at(
+            Moment(i.timezone().ymd(year, m, day).and_hms(0, 0, 0)),
+            Grain::Day,
+        ))
+    };
+    Ok(
+        DatetimeValue::constraint(Month::new(m).invalid_if_err()?.translate_with(offset))
+            .datetime_kind(DatetimeKind::Date),
+    )
+}
+
+/// Day of the month of the n-th given day of the week, if the month has one.
+pub fn computer_nth_day_of_week(year: i32, month: u32, n: i64, weekday: Weekday) -> Option<u32> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1)?.weekday();
+    let first_occurrence =
+        1 + (weekday.num_days_from_monday() + 7 - first.num_days_from_monday()) % 7;
+    let num_days = last_day_in_month(year, month, FixedOffset::east(0));
+    let day = if n > 0 {
+        first_occurrence as i64 + 7 * (n - 1)
+    } else {
+        let num_occurrences = (num_days - first_occurrence) as i64 / 7 + 1;
+        first_occurrence as i64 + 7 * (num_occurrences + n)
+    };
+    if day >= 1 && day <= num_days as i64 {
+        Some(day as u32)
+    } else {
+        None
+    }
+}
+
 impl CycleValue {
     pub fn last_of(&self, base: &DatetimeValue) -> RuleResult<DatetimeValue> {
         cycle(self.grain)?.last_of(base)
@@ -822,10 +1459,36 @@ mod tests {
         assert_eq!(93, decimal_hour_in_minute("1", "55").unwrap());
     }
 
+    #[test]
+    fn test_roman_numeral() {
+        assert_eq!(14, roman_numeral("xiv").unwrap());
+        assert_eq!(1999, roman_numeral("mcmxcix").unwrap());
+        assert!(roman_numeral("iiii").is_err());
+        assert!(roman_numeral("vx").is_err());
+    }
+
+    #[test]
+    fn test_phone_number() {
+        assert_eq!("+33612345678", phone_number("+33 6 12 34 56 78").unwrap().value);
+        assert_eq!("+33612345678", phone_number("0033 6 12 34 56 78").unwrap().value);
+        assert_eq!("4155550123", phone_number("(415) 555-0123").unwrap().value);
+        assert!(phone_number("12 34").is_err());
+    }
+
     #[test]
     fn test_computer_easter() {
         assert_eq!((2017, 4, 16), computer_easter(2017));
         assert_eq!((2018, 4, 1), computer_easter(2018));
         assert_eq!((2019, 4, 21), computer_easter(2019));
     }
+
+    #[test]
+    fn test_computer_nth_day_of_week() {
+        assert_eq!(Some(10), computer_nth_day_of_week(2017, 3, 2, Weekday::Fri));
+        assert_eq!(Some(23), computer_nth_day_of_week(2017, 11, 4, Weekday::Thu));
+        assert_eq!(Some(29), computer_nth_day_of_week(2017, 5, -1, Weekday::Mon));
+        assert_eq!(Some(24), computer_nth_day_of_week(2016, 2, -1, Weekday::Wed));
+        assert_eq!(Some(1), computer_nth_day_of_week(2017, 1, 1, Weekday::Sun));
+        assert_eq!(None, computer_nth_day_of_week(2017, 2, 5, Weekday::Mon));
+    }
 }
@@ -16,9 +16,10 @@ pub mod macros_training;
 pub mod macros_rules;
 pub mod output;
 pub mod context;
+pub mod rules;
 
 pub use dimension::Dimension;
 pub use dimension::DimensionKind;
 pub use output::Output;
 pub use output::OutputKind;
-pub use context::{ResolverContext, ParsingContext, IdentityContext};
+pub use context::{ResolverContext, ParsingContext, IdentityContext, Occurrences, Locale, Clock};
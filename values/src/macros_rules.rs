@@ -61,6 +61,55 @@ macro_rules! money_unit {
     () => ( ::rustling::core::AnyNodePattern::<MoneyUnitValue>::new() );
 }
 
+#[macro_export]
+macro_rules! distance_check {
+    () => ( ::rustling::core::AnyNodePattern::<DistanceValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<DistanceValue>::filter(vec![b!($predicate)]) );
+}
+
+#[macro_export]
+macro_rules! distance_unit {
+    () => ( ::rustling::core::AnyNodePattern::<DistanceUnitValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<DistanceUnitValue>::filter(vec![b!($predicate)]) );
+}
+
+#[macro_export]
+macro_rules! volume_check {
+    () => ( ::rustling::core::AnyNodePattern::<VolumeValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<VolumeValue>::filter(vec![b!($predicate)]) );
+}
+
+#[macro_export]
+macro_rules! volume_unit {
+    () => ( ::rustling::core::AnyNodePattern::<VolumeUnitValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<VolumeUnitValue>::filter(vec![b!($predicate)]) );
+}
+
+#[macro_export]
+macro_rules! mass_check {
+    () => ( ::rustling::core::AnyNodePattern::<MassValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<MassValue>::filter(vec![b!($predicate)]) );
+}
+
+#[macro_export]
+macro_rules! mass_unit {
+    () => ( ::rustling::core::AnyNodePattern::<MassUnitValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<MassUnitValue>::filter(vec![b!($predicate)]) );
+}
+
+#[macro_export]
+macro_rules! rate_check {
+    () => ( ::rustling::core::AnyNodePattern::<RateValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<RateValue>::filter(vec![b!($predicate)]) );
+}
+
+
+#[macro_export]
+macro_rules! quantity_range_check {
+    () => ( ::rustling::core::AnyNodePattern::<QuantityRangeValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<QuantityRangeValue>::filter(vec![b!($predicate)]) );
+}
+
 
 #[macro_export]
 macro_rules! cycle_check {
@@ -69,6 +118,13 @@ macro_rules! cycle_check {
 }
 
 
+#[macro_export]
+macro_rules! recurrence_check {
+    () => ( ::rustling::core::AnyNodePattern::<RecurrenceValue>::new() );
+    ($predicate:expr) => ( ::rustling::core::FilterNodePattern::<RecurrenceValue>::filter(vec![b!($predicate)]) );
+}
+
+
 #[macro_export]
 macro_rules! unit_of_duration_check {
     () => ( ::rustling::core::AnyNodePattern::<UnitOfDurationValue>::new() );
@@ -20,42 +20,42 @@ macro_rules! check_duration {
 
 #[macro_export]
 macro_rules! check_moment {
-    ($context:expr, [$($item:expr),*]) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, None) );
-    ($context:expr, [$($item:expr),*], $grain:expr) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), $grain, Precision::Exact, None) );
+    ($context:expr, [$($item:expr),*]) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, None) );
+    ($context:expr, [$($item:expr),*], $grain:expr) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), $grain, Precision::Exact, None) );
 }
 
 #[macro_export]
 macro_rules! check_moment_with_precision {
-    ($context:expr, [$($item:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), grain!($( $item ),*), $precision, None) );
+    ($context:expr, [$($item:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), grain!($( $item ),*), $precision, None) );
 }
 
 #[macro_export]
 macro_rules! check_moment_with_direction {
-    ($context:expr, [$($item:expr),*], $direction:expr) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, Some($direction)) );
-    ($context:expr, [$($item:expr),*], $direction:expr, $grain:expr) => ( ::rustling_ontology_values::check::check_moment($context, moment!($( $item ),*), $grain, Precision::Exact, Some($direction)) );
+    ($context:expr, [$($item:expr),*], $direction:expr) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), grain!($( $item ),*), Precision::Exact, Some($direction)) );
+    ($context:expr, [$($item:expr),*], $direction:expr, $grain:expr) => ( ::rustling_ontology_values::check::check_moment($context.clone(), moment!($( $item ),*), $grain, Precision::Exact, Some($direction)) );
 
 }
 
 #[macro_export]
 macro_rules! check_moment_span {
-    ($context:expr, [$($item1:expr),*], [$($item2:expr),*]) => ( ::rustling_ontology_values::check::check_moment_span($context, Precision::Exact, moment!($( $item1 ),*), moment!($( $item2 ),*), grain!($( $item1 ),*)) );
-    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $grain:expr) => ( ::rustling_ontology_values::check::check_moment_span($context, Precision::Exact, moment!($( $item1 ),*), moment!($( $item2 ),*), $grain) );
+    ($context:expr, [$($item1:expr),*], [$($item2:expr),*]) => ( ::rustling_ontology_values::check::check_moment_span($context.clone(), Precision::Exact, moment!($( $item1 ),*), moment!($( $item2 ),*), grain!($( $item1 ),*)) );
+    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $grain:expr) => ( ::rustling_ontology_values::check::check_moment_span($context.clone(), Precision::Exact, moment!($( $item1 ),*), moment!($( $item2 ),*), $grain) );
 }
 
 #[macro_export]
 macro_rules! check_moment_span_with_precision {
-    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_moment_span($context, $precision, moment!($( $item1 ),*), moment!($( $item2 ),*), grain!($( $item1 ),*)) );
-    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $precision:expr, $grain:expr) => ( ::rustling_ontology_values::check::check_moment_span($context, $precision, moment!($( $item1 ),*), moment!($( $item2 ),*), $grain) );
+    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $precision:expr) => ( ::rustling_ontology_values::check::check_moment_span($context.clone(), $precision, moment!($( $item1 ),*), moment!($( $item2 ),*), grain!($( $item1 ),*)) );
+    ($context:expr, [$($item1:expr),*], [$($item2:expr),*], $precision:expr, $grain:expr) => ( ::rustling_ontology_values::check::check_moment_span($context.clone(), $precision, moment!($( $item1 ),*), moment!($( $item2 ),*), $grain) );
 }
 
 #[macro_export]
 macro_rules! moment {
-    ($y:expr) => ( Moment(Local.ymd($y, 1, 1).and_hms(0, 0, 0)));
-    ($y:expr, $m:expr) => ( Moment(Local.ymd($y, $m, 1).and_hms(0, 0, 0)) );
-    ($y:expr, $m:expr, $d:expr) => ( Moment(Local.ymd($y, $m, $d).and_hms(0, 0, 0)) );
-    ($y:expr, $m:expr, $d:expr, $h:expr) => ( Moment(Local.ymd($y, $m, $d).and_hms($h, 0, 0)) );
-    ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr) => ( Moment(Local.ymd($y, $m, $d).and_hms($h, $min, 0)) );
-    ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr, $sec:expr) => ( Moment(Local.ymd($y, $m, $d).and_hms($h, $min, $sec)) );
+    ($y:expr) => ( Moment(Tz::local().ymd($y, 1, 1).and_hms(0, 0, 0)));
+    ($y:expr, $m:expr) => ( Moment(Tz::local().ymd($y, $m, 1).and_hms(0, 0, 0)) );
+    ($y:expr, $m:expr, $d:expr) => ( Moment(Tz::local().ymd($y, $m, $d).and_hms(0, 0, 0)) );
+    ($y:expr, $m:expr, $d:expr, $h:expr) => ( Moment(Tz::local().ymd($y, $m, $d).and_hms($h, 0, 0)) );
+    ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr) => ( Moment(Tz::local().ymd($y, $m, $d).and_hms($h, $min, 0)) );
+    ($y:expr, $m:expr, $d:expr, $h:expr, $min:expr, $sec:expr) => ( Moment(Tz::local().ymd($y, $m, $d).and_hms($h, $min, $sec)) );
 }
 
 #[macro_export]
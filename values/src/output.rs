@@ -1,11 +1,14 @@
 use crate::dimension::*;
 use moment::*;
 use rustling::Value;
+#[cfg(feature = "serde")]
+use serde::Serialize;
 
 #[derive(Clone, PartialEq, Debug)]
 pub enum Output {
     Integer(IntegerOutput),
     Float(FloatOutput),
+    Fraction(FractionOutput),
     Percentage(PercentageOutput),
     Ordinal(OrdinalOutput),
     Datetime(DatetimeOutput),
@@ -13,6 +16,19 @@ pub enum Output {
     AmountOfMoney(AmountOfMoneyOutput),
     Temperature(TemperatureOutput),
     Duration(DurationOutput),
+    Distance(DistanceOutput),
+    Volume(VolumeOutput),
+    Mass(MassOutput),
+    Rate(RateOutput),
+    NumberInterval(NumberIntervalOutput),
+    OrdinalInterval(OrdinalIntervalOutput),
+    AmountOfMoneyInterval(AmountOfMoneyIntervalOutput),
+    TemperatureInterval(TemperatureIntervalOutput),
+    PhoneNumber(PhoneNumberOutput),
+    Email(EmailOutput),
+    Url(UrlOutput),
+    Recurrence(RecurrenceOutput),
+    Age(AgeOutput),
 }
 
 impl Output {
@@ -20,6 +36,7 @@ impl Output {
         match self {
             &Output::Integer(_) => OutputKind::Number,
             &Output::Float(_) => OutputKind::Number,
+            &Output::Fraction(_) => OutputKind::Number,
             &Output::Ordinal(_) => OutputKind::Ordinal,
             Output::Datetime(datetime_output_value) => {
                 match datetime_output_value.datetime_kind {
@@ -45,6 +62,19 @@ impl Output {
             &Output::Temperature(_) => OutputKind::Temperature,
             &Output::Duration(_) => OutputKind::Duration,
             &Output::Percentage(_) => OutputKind::Percentage,
+            &Output::Distance(_) => OutputKind::Distance,
+            &Output::Volume(_) => OutputKind::Volume,
+            &Output::Mass(_) => OutputKind::Mass,
+            &Output::Rate(_) => OutputKind::Rate,
+            &Output::NumberInterval(_) => OutputKind::NumberInterval,
+            &Output::OrdinalInterval(_) => OutputKind::OrdinalInterval,
+            &Output::AmountOfMoneyInterval(_) => OutputKind::AmountOfMoneyInterval,
+            &Output::TemperatureInterval(_) => OutputKind::TemperatureInterval,
+            &Output::PhoneNumber(_) => OutputKind::PhoneNumber,
+            &Output::Email(_) => OutputKind::Email,
+            &Output::Url(_) => OutputKind::Url,
+            &Output::Recurrence(_) => OutputKind::Recurrence,
+            &Output::Age(_) => OutputKind::Age,
         }
     }
 }
@@ -62,7 +92,20 @@ enum_kind!(
         Duration,
         AmountOfMoney,
         Temperature,
-        Percentage
+        Percentage,
+        Distance,
+        Volume,
+        Mass,
+        Rate,
+        NumberInterval,
+        OrdinalInterval,
+        AmountOfMoneyInterval,
+        TemperatureInterval,
+        PhoneNumber,
+        Email,
+        Url,
+        Recurrence,
+        Age
     ]
 );
 
@@ -80,6 +123,19 @@ impl OutputKind {
             &OutputKind::Temperature => DimensionKind::Temperature,
             &OutputKind::Duration => DimensionKind::Duration,
             &OutputKind::Percentage => DimensionKind::Percentage,
+            &OutputKind::Distance => DimensionKind::Distance,
+            &OutputKind::Volume => DimensionKind::Volume,
+            &OutputKind::Mass => DimensionKind::Mass,
+            &OutputKind::Rate => DimensionKind::Rate,
+            &OutputKind::NumberInterval => DimensionKind::QuantityRange,
+            &OutputKind::OrdinalInterval => DimensionKind::QuantityRange,
+            &OutputKind::AmountOfMoneyInterval => DimensionKind::QuantityRange,
+            &OutputKind::TemperatureInterval => DimensionKind::QuantityRange,
+            &OutputKind::PhoneNumber => DimensionKind::PhoneNumber,
+            &OutputKind::Email => DimensionKind::Email,
+            &OutputKind::Url => DimensionKind::Url,
+            &OutputKind::Recurrence => DimensionKind::Recurrence,
+            &OutputKind::Age => DimensionKind::Age,
         }
     }
 
@@ -102,29 +158,56 @@ impl OutputKind {
                     _ => false,
                 }
             }
+            Dimension::QuantityRange(range_value) => {
+                match range_value.quantity {
+                    QuantityKind::Number => OutputKind::NumberInterval == *self,
+                    QuantityKind::Ordinal => OutputKind::OrdinalInterval == *self,
+                    QuantityKind::AmountOfMoney { .. } => {
+                        OutputKind::AmountOfMoneyInterval == *self
+                    }
+                    QuantityKind::Temperature { .. } => OutputKind::TemperatureInterval == *self,
+                }
+            }
             _ => self.to_dim() == dimension_value.kind(),
         }
     }
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct IntegerOutput(pub i64);
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct FloatOutput(pub f64);
 
+/// Number expressed as a fraction, with its exact rational value alongside the float
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct FractionOutput {
+    pub value: f64,
+    pub numerator: i64,
+    pub denominator: i64,
+}
+
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct PercentageOutput(pub f64);
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct OrdinalOutput(pub i64);
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct DatetimeOutput {
-    pub moment: Moment<Local>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialization::moment"))]
+    pub moment: Moment<Tz>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialization::grain"))]
     pub grain: Grain,
     pub precision: Precision,
     pub latent: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub datetime_kind: DatetimeKind,
 }
 
@@ -138,24 +221,31 @@ impl DatetimeOutput {
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct DatetimeIntervalOutput {
     pub interval_kind: DatetimeIntervalKind,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub datetime_kind: DatetimeKind,
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 pub enum DatetimeIntervalKind {
     After(DatetimeOutput),
     Before(DatetimeOutput),
     Between {
-        start: Moment<Local>,
-        end: Moment<Local>,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "serialization::moment"))]
+        start: Moment<Tz>,
+        #[cfg_attr(feature = "serde", serde(serialize_with = "serialization::moment"))]
+        end: Moment<Tz>,
         precision: Precision,
         latent: bool,
     },
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct AmountOfMoneyOutput {
     pub value: f64,
     pub precision: Precision,
@@ -163,20 +253,213 @@ pub struct AmountOfMoneyOutput {
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct TemperatureOutput {
     pub value: f64,
     pub unit: Option<&'static str>,
     pub latent: bool,
 }
 
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct DistanceOutput {
+    pub value: f64,
+    pub precision: Precision,
+    pub unit: Option<&'static str>,
+    pub latent: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct VolumeOutput {
+    pub value: f64,
+    pub precision: Precision,
+    pub unit: Option<&'static str>,
+    pub latent: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct MassOutput {
+    pub value: f64,
+    pub precision: Precision,
+    pub unit: Option<&'static str>,
+    pub latent: bool,
+}
+
+impl DistanceOutput {
+    /// Value converted to another canonical unit of distance, e.g. 5 km is 3.1 "mi".
+    pub fn value_in(&self, unit: &str) -> Option<f64> {
+        Some(self.value * metres_per_unit(self.unit?)? / metres_per_unit(unit)?)
+    }
+}
+
+impl VolumeOutput {
+    /// Value converted to another canonical unit of volume, e.g. 2 cups are 0.47 "l".
+    pub fn value_in(&self, unit: &str) -> Option<f64> {
+        Some(self.value * litres_per_unit(self.unit?)? / litres_per_unit(unit)?)
+    }
+}
+
+impl MassOutput {
+    /// Value converted to another canonical unit of mass, e.g. 2 lb are 0.91 "kg".
+    pub fn value_in(&self, unit: &str) -> Option<f64> {
+        Some(self.value * grams_per_unit(self.unit?)? / grams_per_unit(unit)?)
+    }
+}
+
+fn metres_per_unit(unit: &str) -> Option<f64> {
+    match unit {
+        "km" => Some(1000.0),
+        "m" => Some(1.0),
+        "cm" => Some(0.01),
+        "mm" => Some(0.001),
+        "mi" => Some(1609.344),
+        "yd" => Some(0.9144),
+        "ft" => Some(0.3048),
+        "in" => Some(0.0254),
+        _ => None,
+    }
+}
+
+fn litres_per_unit(unit: &str) -> Option<f64> {
+    match unit {
+        "l" => Some(1.0),
+        "dl" => Some(0.1),
+        "cl" => Some(0.01),
+        "ml" => Some(0.001),
+        // US customary measures
+        "gal" => Some(3.785_411_784),
+        "pt" => Some(0.473_176_473),
+        "cup" => Some(0.236_588_236_5),
+        "fl oz" => Some(0.029_573_529_562_5),
+        "tbsp" => Some(0.014_786_764_781_25),
+        "tsp" => Some(0.004_928_921_593_75),
+        _ => None,
+    }
+}
+
+fn grams_per_unit(unit: &str) -> Option<f64> {
+    match unit {
+        "t" => Some(1_000_000.0),
+        "kg" => Some(1000.0),
+        "g" => Some(1.0),
+        "mg" => Some(0.001),
+        "lb" => Some(453.592_37),
+        "oz" => Some(28.349_523_125),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct RateOutput {
+    pub value: f64,
+    pub precision: Precision,
+    pub numerator_unit: Option<&'static str>,
+    pub denominator_unit: &'static str,
+}
+
+/// Bounds of an interval of quantities, open on one side for `Above` and `Below`, which include
+/// their bound for "at least 5" or "up to 5" but not for "more than 5" or "under 5".
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", content = "value", rename_all = "snake_case"))]
+pub enum QuantityIntervalKind<T> {
+    Above { start: T, inclusive: bool },
+    Below { end: T, inclusive: bool },
+    /// Both bounds included
+    Between { start: T, end: T },
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct NumberIntervalOutput {
+    pub interval_kind: QuantityIntervalKind<f64>,
+    pub precision: Precision,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct OrdinalIntervalOutput {
+    pub interval_kind: QuantityIntervalKind<i64>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct AmountOfMoneyIntervalOutput {
+    pub interval_kind: QuantityIntervalKind<f64>,
+    pub precision: Precision,
+    pub unit: Option<&'static str>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct TemperatureIntervalOutput {
+    pub interval_kind: QuantityIntervalKind<f64>,
+    pub unit: Option<&'static str>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct PhoneNumberOutput {
+    pub value: String,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct EmailOutput {
+    pub value: String,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct UrlOutput {
+    pub value: String,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct AgeOutput {
+    pub value: f64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialization::grain"))]
+    pub unit: Grain,
+}
+
+/// Recurring datetime in the spirit of an RFC 5545 recurrence rule, e.g. "every monday at 5pm"
+/// is weekly on mondays at 17:00. The `by_*` fields pin the occurrences within the frequency,
+/// `start` is the first occurrence from the reference.
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct RecurrenceOutput {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialization::grain"))]
+    pub frequency: Grain,
+    pub interval: i64,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialization::weekdays"))]
+    pub by_day: Vec<Weekday>,
+    pub by_month_day: Option<u32>,
+    pub by_month: Option<u32>,
+    pub by_hour: Option<u32>,
+    pub by_minute: Option<u32>,
+    pub by_second: Option<u32>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialization::moment"))]
+    pub start: Moment<Tz>,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialization::optional_moment"))]
+    pub until: Option<Moment<Tz>>,
+    pub count: Option<i64>,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct DurationOutput {
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialization::period"))]
     pub period: Period,
     pub precision: Precision,
 }
 
 variant_converters!(Output, Integer, IntegerOutput);
 variant_converters!(Output, Float, FloatOutput);
+variant_converters!(Output, Fraction, FractionOutput);
 variant_converters!(Output, Percentage, PercentageOutput);
 variant_converters!(Output, Ordinal, OrdinalOutput);
 variant_converters!(Output, Datetime, DatetimeOutput);
@@ -184,3 +467,135 @@ variant_converters!(Output, DatetimeInterval, DatetimeIntervalOutput);
 variant_converters!(Output, AmountOfMoney, AmountOfMoneyOutput);
 variant_converters!(Output, Temperature, TemperatureOutput);
 variant_converters!(Output, Duration, DurationOutput);
+variant_converters!(Output, Distance, DistanceOutput);
+variant_converters!(Output, Volume, VolumeOutput);
+variant_converters!(Output, Mass, MassOutput);
+variant_converters!(Output, Rate, RateOutput);
+variant_converters!(Output, NumberInterval, NumberIntervalOutput);
+variant_converters!(Output, OrdinalInterval, OrdinalIntervalOutput);
+variant_converters!(Output, AmountOfMoneyInterval, AmountOfMoneyIntervalOutput);
+variant_converters!(Output, TemperatureInterval, TemperatureIntervalOutput);
+variant_converters!(Output, PhoneNumber, PhoneNumberOutput);
+variant_converters!(Output, Email, EmailOutput);
+variant_converters!(Output, Url, UrlOutput);
+variant_converters!(Output, Recurrence, RecurrenceOutput);
+variant_converters!(Output, Age, AgeOutput);
+
+/// Serde representation of the outputs, enabled by the `serde` feature.
+///
+/// An `Output` is serialized as a `version`, its `kind` and its `value`:
+///
+/// ```json
+/// {
+///   "version": 1,
+///   "kind": "Date",
+///   "value": {
+///     "moment": "2013-02-13T00:00:00+01:00",
+///     "grain": "day",
+///     "precision": "exact",
+///     "latent": false
+///   }
+/// }
+/// ```
+///
+/// Moments are ISO-8601 datetimes with their offset, grains are lower case and durations are
+/// maps from grains to quantities. The version is bumped on every breaking change of this
+/// representation.
+#[cfg(feature = "serde")]
+pub mod serialization {
+    use super::*;
+    use serde::ser::{SerializeMap, SerializeStruct, Serializer};
+
+    /// Version of the serialized representation of `Output`.
+    pub const VERSION: u32 = 1;
+
+    impl Serialize for Output {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("Output", 3)?;
+            state.serialize_field("version", &VERSION)?;
+            state.serialize_field("kind", &format!("{:?}", self.kind()))?;
+            match self {
+                Output::Integer(v) => state.serialize_field("value", v)?,
+                Output::Float(v) => state.serialize_field("value", v)?,
+                Output::Fraction(v) => state.serialize_field("value", v)?,
+                Output::Percentage(v) => state.serialize_field("value", v)?,
+                Output::Ordinal(v) => state.serialize_field("value", v)?,
+                Output::Datetime(v) => state.serialize_field("value", v)?,
+                Output::DatetimeInterval(v) => state.serialize_field("value", v)?,
+                Output::AmountOfMoney(v) => state.serialize_field("value", v)?,
+                Output::Temperature(v) => state.serialize_field("value", v)?,
+                Output::Duration(v) => state.serialize_field("value", v)?,
+                Output::Distance(v) => state.serialize_field("value", v)?,
+                Output::Volume(v) => state.serialize_field("value", v)?,
+                Output::Mass(v) => state.serialize_field("value", v)?,
+                Output::Rate(v) => state.serialize_field("value", v)?,
+                Output::NumberInterval(v) => state.serialize_field("value", v)?,
+                Output::OrdinalInterval(v) => state.serialize_field("value", v)?,
+                Output::AmountOfMoneyInterval(v) => state.serialize_field("value", v)?,
+                Output::TemperatureInterval(v) => state.serialize_field("value", v)?,
+                Output::PhoneNumber(v) => state.serialize_field("value", v)?,
+                Output::Email(v) => state.serialize_field("value", v)?,
+                Output::Url(v) => state.serialize_field("value", v)?,
+                Output::Recurrence(v) => state.serialize_field("value", v)?,
+                Output::Age(v) => state.serialize_field("value", v)?,
+            }
+            state.end()
+        }
+    }
+
+    fn grain_name(grain: Grain) -> String {
+        format!("{:?}", grain).to_lowercase()
+    }
+
+    pub(crate) fn moment<S: Serializer>(
+        moment: &Moment<Tz>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&moment.0.to_rfc3339())
+    }
+
+    pub(crate) fn optional_moment<S: Serializer>(
+        moment: &Option<Moment<Tz>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match moment {
+            Some(moment) => serializer.serialize_some(&moment.0.to_rfc3339()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Weekdays are written as the two letter codes of RFC 5545, e.g. "MO".
+    pub(crate) fn weekdays<S: Serializer>(
+        weekdays: &[Weekday],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(weekdays.iter().map(|weekday| format!("{:?}", weekday)[..2].to_uppercase()))
+    }
+
+    pub(crate) fn grain<S: Serializer>(grain: &Grain, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&grain_name(*grain))
+    }
+
+    pub(crate) fn period<S: Serializer>(
+        period: &Period,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let grains = [
+            Grain::Year,
+            Grain::Quarter,
+            Grain::Month,
+            Grain::Week,
+            Grain::Day,
+            Grain::Hour,
+            Grain::Minute,
+            Grain::Second,
+        ];
+        let mut map = serializer.serialize_map(Some(period.0.len()))?;
+        for grain in grains.iter() {
+            if let Some(quantity) = period.0.get(*grain as usize) {
+                map.serialize_entry(&grain_name(*grain), quantity)?;
+            }
+        }
+        map.end()
+    }
+}
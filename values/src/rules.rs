@@ -0,0 +1,386 @@
+//! Rules shared by the grammars, for the entities written the same way in every language or
+//! built with a few words of the language. Each grammar adds the rules specific to its language.
+
+use crate::dimension::*;
+use crate::helpers;
+use moment::Grain;
+use rustling::*;
+
+/// Email addresses, urls and phone numbers as written, e.g. "john.doe@gmail.com",
+/// "www.snips.ai" or "+33 6 12 34 56 78". A bare run of digits is only a latent phone number.
+pub fn rules_contact(b: &mut RuleSetBuilder<Dimension>) -> RustlingResult<()> {
+    b.rule_1_terminal("email address",
+                      b.reg(r#"([a-z0-9][a-z0-9._%+\-]*)@([a-z0-9][a-z0-9\-]*(?:\.[a-z0-9\-]+)*\.[a-z]{2,})"#)?,
+                      |text_match| helpers::email(text_match.group(1), text_match.group(2))
+    );
+    b.rule_1_terminal("url",
+                      b.reg(r#"(?:(?:https?|ftp)://|www\.)[a-z0-9\-]+(?:\.[a-z0-9\-]+)+(?::\d+)?(?:/[^\s]*)?"#)?,
+                      |text_match| helpers::url(text_match.group(0))
+    );
+    b.rule_1_terminal("url without scheme",
+                      b.reg(r#"[a-z0-9\-]+(?:\.[a-z0-9\-]+)*\.(?:com|org|net|edu|gov|io|co|eu|fr|de|es|it|pt|uk|jp|kr|cn)(?:/[^\s]*)?"#)?,
+                      |text_match| helpers::url(text_match.group(0))
+    );
+    // International prefix, area code in parentheses, or digits grouped the way phone numbers
+    // are, which dates and ranges of numbers aren't
+    b.rule_1_terminal("phone number",
+                      b.reg(r#"(?:\+|00)\d{1,3}[ .\-]?(?:\(\d{1,4}\)[ .\-]?)?\d{1,4}(?:[ .\-]?\d{2,4}){1,5}|\(\d{1,4}\)[ .\-]?\d{2,4}(?:[ .\-]?\d{2,4}){1,4}|\d{2,4}(?:[ .\-]\d{2,4}){3,5}|\d{2,4}[ .\-]\d{3,4}[ .\-]\d{3,4}|\d{2,5}[ \-]\d{5,8}|\d{3}\-\d{4}"#)?,
+                      |text_match| helpers::phone_number(text_match.group(0))
+    );
+    b.rule_1_terminal("phone number (latent)",
+                      b.reg(r#"\d{7,15}"#)?,
+                      |text_match| Ok(helpers::phone_number(text_match.group(0))?.latent(true))
+    );
+    Ok(())
+}
+
+/// Words of a language used by the shared rules of quantities, as regular expressions.
+#[derive(Debug, Copy, Clone)]
+pub struct QuantityWords {
+    /// Article standing for a single unit, e.g. "a" in "a mile"
+    pub one: &'static str,
+    /// Approximation of a quantity, e.g. "about"
+    pub approximately: &'static str,
+    /// Insistence on the exact quantity, e.g. "exactly"
+    pub exactly: &'static str,
+    /// Word between a quantity and the unit it is counted per, e.g. "per"
+    pub per: &'static str,
+}
+
+/// Distances, volumes, masses and rates made of a number and a unit, e.g. "5 km", "about 2 l" or
+/// "10 km/h". The units written as symbols are shared, the single letter ones being latent, each
+/// grammar adds the units spelled out in its language.
+pub fn rules_quantities(b: &mut RuleSetBuilder<Dimension>, words: &QuantityWords) -> RustlingResult<()> {
+    b.rule_1_terminal("km (symbol)",
+                      b.reg(r#"kms?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("km"), latent: false })
+    );
+    b.rule_1_terminal("m (symbol)",
+                      b.reg(r#"m"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("m"), latent: true })
+    );
+    b.rule_1_terminal("cm (symbol)",
+                      b.reg(r#"cms?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("cm"), latent: false })
+    );
+    b.rule_1_terminal("mm (symbol)",
+                      b.reg(r#"mms?"#)?,
+                      |_| Ok(DistanceUnitValue { unit: Some("mm"), latent: false })
+    );
+    b.rule_1_terminal("l (symbol)",
+                      b.reg(r#"l"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("l"), latent: true })
+    );
+    b.rule_1_terminal("dl (symbol)",
+                      b.reg(r#"dl"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("dl"), latent: false })
+    );
+    b.rule_1_terminal("cl (symbol)",
+                      b.reg(r#"cl"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("cl"), latent: false })
+    );
+    b.rule_1_terminal("ml (symbol)",
+                      b.reg(r#"ml"#)?,
+                      |_| Ok(VolumeUnitValue { unit: Some("ml"), latent: false })
+    );
+    b.rule_1_terminal("kg (symbol)",
+                      b.reg(r#"kgs?"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("kg"), latent: false })
+    );
+    b.rule_1_terminal("g (symbol)",
+                      b.reg(r#"g"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("g"), latent: true })
+    );
+    b.rule_1_terminal("g (abbreviation)",
+                      b.reg(r#"gr"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("g"), latent: false })
+    );
+    b.rule_1_terminal("mg (symbol)",
+                      b.reg(r#"mg"#)?,
+                      |_| Ok(MassUnitValue { unit: Some("mg"), latent: false })
+    );
+    b.rule_2("<number> <distance-unit>",
+             number_check!(),
+             distance_unit!(),
+             |a, b| helpers::compose_distance(a.value(), b.value()));
+    b.rule_2("a <distance-unit>",
+             b.reg(words.one)?,
+             distance_unit!(|unit: &DistanceUnitValue| !unit.latent),
+             |_, a| helpers::compose_distance(&NumberValue::from(IntegerValue::new(1)?), a.value()));
+    b.rule_2("about <distance>",
+             b.reg(words.approximately)?,
+             distance_check!(),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Precision::Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <distance>",
+             b.reg(words.exactly)?,
+             distance_check!(),
+             |_, a| {
+                 Ok(DistanceValue {
+                     precision: Precision::Exact,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("<number> <volume-unit>",
+             number_check!(),
+             volume_unit!(),
+             |a, b| helpers::compose_volume(a.value(), b.value()));
+    b.rule_2("a <volume-unit>",
+             b.reg(words.one)?,
+             volume_unit!(|unit: &VolumeUnitValue| !unit.latent),
+             |_, a| helpers::compose_volume(&NumberValue::from(IntegerValue::new(1)?), a.value()));
+    b.rule_2("about <volume>",
+             b.reg(words.approximately)?,
+             volume_check!(),
+             |_, a| {
+                 Ok(VolumeValue {
+                     precision: Precision::Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <volume>",
+             b.reg(words.exactly)?,
+             volume_check!(),
+             |_, a| {
+                 Ok(VolumeValue {
+                     precision: Precision::Exact,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("<number> <mass-unit>",
+             number_check!(),
+             mass_unit!(),
+             |a, b| helpers::compose_mass(a.value(), b.value()));
+    b.rule_2("a <mass-unit>",
+             b.reg(words.one)?,
+             mass_unit!(|unit: &MassUnitValue| !unit.latent),
+             |_, a| helpers::compose_mass(&NumberValue::from(IntegerValue::new(1)?), a.value()));
+    b.rule_2("about <mass>",
+             b.reg(words.approximately)?,
+             mass_check!(),
+             |_, a| {
+                 Ok(MassValue {
+                     precision: Precision::Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <mass>",
+             b.reg(words.exactly)?,
+             mass_check!(),
+             |_, a| {
+                 Ok(MassValue {
+                     precision: Precision::Exact,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_3("<number> per <unit-of-duration>",
+             number_check!(),
+             b.reg(words.per)?,
+             unit_of_duration_check!(),
+             |a, _, uod| helpers::rate_per_duration(a.value().value(), None, a.value().precision(), uod.value()));
+    b.rule_3("<distance> per <unit-of-duration>",
+             distance_check!(),
+             b.reg(words.per)?,
+             unit_of_duration_check!(),
+             |a, _, uod| helpers::rate_per_duration(a.value().value, a.value().unit, a.value().precision, uod.value()));
+    b.rule_2("<number> km/h",
+             number_check!(),
+             b.reg(r#"km/h|kmh|kph"#)?,
+             |a, _| helpers::rate_per_duration(a.value().value(), Some("km"), a.value().precision(), &UnitOfDurationValue::new(Grain::Hour)));
+    b.rule_3("<duration> per <distance-unit>",
+             duration_check!(),
+             b.reg(words.per)?,
+             distance_unit!(|unit: &DistanceUnitValue| !unit.latent),
+             |a, _, unit| helpers::duration_per_distance_unit(a.value(), unit.value()));
+    b.rule_2("about <rate>",
+             b.reg(words.approximately)?,
+             rate_check!(),
+             |_, a| {
+                 Ok(RateValue {
+                     precision: Precision::Approximate,
+                     ..a.value().clone()
+                 })
+             });
+    b.rule_2("exactly <rate>",
+             b.reg(words.exactly)?,
+             rate_check!(),
+             |_, a| {
+                 Ok(RateValue {
+                     precision: Precision::Exact,
+                     ..a.value().clone()
+                 })
+             });
+    Ok(())
+}
+
+/// Words of a language used by the shared rules of recurrences, as regular expressions. The
+/// optional ones are left out by the languages which don't need them.
+#[derive(Debug, Copy, Clone)]
+pub struct RecurrenceWords {
+    /// Recurrence of each occurrence, e.g. "every" in "every monday"
+    pub every: &'static str,
+    /// Recurrence of every second occurrence, e.g. "every other"
+    pub every_other: Option<&'static str>,
+    /// Word before a number of cycles, e.g. "every" in "every 3 weeks"
+    pub every_integer: Option<&'static str>,
+    pub hourly: &'static str,
+    pub daily: &'static str,
+    pub weekly: &'static str,
+    pub monthly: &'static str,
+    pub quarterly: Option<&'static str>,
+    pub yearly: &'static str,
+    /// Word joining the days of the week, e.g. "and" in "every monday and wednesday"
+    pub and: Option<&'static str>,
+    /// Word before the end of the recurrence, e.g. "until"
+    pub until: Option<&'static str>,
+    /// Word after the number of occurrences, e.g. "times"
+    pub times: Option<&'static str>,
+}
+
+/// Recurrences of datetimes and cycles, e.g. "every monday at 5pm", "daily" or "every 2 weeks
+/// until june". Each grammar adds the days of the week in the plural, e.g. "mondays".
+pub fn rules_recurrence(b: &mut RuleSetBuilder<Dimension>, words: &RecurrenceWords) -> RustlingResult<()> {
+    b.rule_2("every <datetime>",
+             b.reg(words.every)?,
+             datetime_check!(|datetime: &DatetimeValue| datetime.form.is_recurrent()),
+             |_, datetime| helpers::recurrence(datetime.value(), 1)
+    );
+    b.rule_2("every <cycle>",
+             b.reg(words.every)?,
+             cycle_check!(),
+             |_, cycle| helpers::recurrence_cycle(cycle.value().grain, 1)
+    );
+    if let Some(every_other) = words.every_other {
+        b.rule_2("every other <datetime>",
+                 b.reg(every_other)?,
+                 datetime_check!(|datetime: &DatetimeValue| datetime.form.is_recurrent()),
+                 |_, datetime| helpers::recurrence(datetime.value(), 2)
+        );
+        b.rule_2("every other <cycle>",
+                 b.reg(every_other)?,
+                 cycle_check!(),
+                 |_, cycle| helpers::recurrence_cycle(cycle.value().grain, 2)
+        );
+    }
+    if let Some(every_integer) = words.every_integer {
+        b.rule_3("every <integer> <cycle>",
+                 b.reg(every_integer)?,
+                 integer_check_by_range!(1),
+                 cycle_check!(),
+                 |_, integer, cycle| helpers::recurrence_cycle(cycle.value().grain, integer.value().value)
+        );
+    }
+    b.rule_1_terminal("hourly",
+                      b.reg(words.hourly)?,
+                      |_| helpers::recurrence_cycle(Grain::Hour, 1)
+    );
+    b.rule_1_terminal("daily",
+                      b.reg(words.daily)?,
+                      |_| helpers::recurrence_cycle(Grain::Day, 1)
+    );
+    b.rule_1_terminal("weekly",
+                      b.reg(words.weekly)?,
+                      |_| helpers::recurrence_cycle(Grain::Week, 1)
+    );
+    b.rule_1_terminal("monthly",
+                      b.reg(words.monthly)?,
+                      |_| helpers::recurrence_cycle(Grain::Month, 1)
+    );
+    if let Some(quarterly) = words.quarterly {
+        b.rule_1_terminal("quarterly",
+                          b.reg(quarterly)?,
+                          |_| helpers::recurrence_cycle(Grain::Quarter, 1)
+        );
+    }
+    b.rule_1_terminal("yearly",
+                      b.reg(words.yearly)?,
+                      |_| helpers::recurrence_cycle(Grain::Year, 1)
+    );
+    b.rule_2("<recurrence> <datetime>",
+             recurrence_check!(),
+             datetime_check!(|datetime: &DatetimeValue| match datetime.form {
+                 Form::TimeOfDay(_) | Form::PartOfDay(_) | Form::DayOfWeek { .. } => true,
+                 _ => false,
+             }),
+             |recurrence, datetime| recurrence.value().intersect(datetime.value())
+    );
+    if let Some(and) = words.and {
+        // The time of day comes after the last day, e.g. "every monday and wednesday at 5pm"
+        b.rule_3("<recurrence> and <day-of-week>",
+                 recurrence_check!(|recurrence: &RecurrenceValue| !recurrence.by_day.is_empty() && recurrence.by_hour.is_none()),
+                 b.reg(and)?,
+                 datetime_check!(form!(Form::DayOfWeek { .. })),
+                 |recurrence, _, datetime| recurrence.value().and(datetime.value())
+        );
+    }
+    if let Some(until) = words.until {
+        b.rule_3("<recurrence> until <datetime>",
+                 recurrence_check!(|recurrence: &RecurrenceValue| recurrence.until.is_none()),
+                 b.reg(until)?,
+                 datetime_check!(),
+                 |recurrence, _, datetime| Ok(recurrence.value().clone().until(datetime.value().clone()))
+        );
+    }
+    if let Some(times) = words.times {
+        b.rule_3("<recurrence> <integer> times",
+                 recurrence_check!(|recurrence: &RecurrenceValue| recurrence.count.is_none()),
+                 integer_check_by_range!(1),
+                 b.reg(times)?,
+                 |recurrence, integer, _| Ok(recurrence.value().clone().count(integer.value().value))
+        );
+    }
+    Ok(())
+}
+
+/// Names followed by a Roman ordinal in every language, e.g. "Louis XIV" or "Super Bowl LII".
+pub const ROMAN_NUMERAL_NAMES: &str = r#"louis|henri|charles|ludwig|napol[ée]on|super bowl"#;
+
+/// Words of a language used by the shared rules of Roman numerals, as regular expressions.
+#[derive(Debug, Copy, Clone)]
+pub struct RomanNumeralWords {
+    /// Nouns numbered with a Roman numeral, e.g. "chapter"
+    pub nouns: &'static str,
+    /// Names followed by a Roman ordinal in the language, on top of `ROMAN_NUMERAL_NAMES`, e.g.
+    /// "pope john paul"
+    pub names: &'static str,
+}
+
+/// Opt-in rules for Roman numerals, e.g. "chapter iv" or "louis xiv". A bare Roman numeral is a
+/// latent number and ordinal.
+pub fn rules_roman_numerals(b: &mut RuleSetBuilder<Dimension>, words: &RomanNumeralWords) -> RustlingResult<()> {
+    b.rule_1_terminal("roman numeral",
+                      b.reg(r#"[mdclxvi]+"#)?,
+                      |text_match| Ok(IntegerValue {
+                          value: helpers::roman_numeral(text_match.group(0))?,
+                          latent: true,
+                          ..IntegerValue::default()
+                      })
+    );
+    b.rule_1_terminal("roman ordinal",
+                      b.reg(r#"[mdclxvi]+"#)?,
+                      |text_match| Ok(OrdinalValue::new(helpers::roman_numeral(text_match.group(0))?).latent(true))
+    );
+    b.rule_2("<noun> <roman numeral>",
+             b.reg(words.nouns)?,
+             integer_check!(|integer: &IntegerValue| integer.latent),
+             |_, integer| Ok(IntegerValue {
+                 latent: false,
+                 ..integer.value().clone()
+             })
+    );
+    b.rule_2("<name> <roman ordinal>",
+             b.reg(ROMAN_NUMERAL_NAMES)?,
+             ordinal_check!(|ordinal: &OrdinalValue| ordinal.latent),
+             |_, ordinal| Ok(ordinal.value().latent(false))
+    );
+    b.rule_2("<name> <roman ordinal> (language)",
+             b.reg(words.names)?,
+             ordinal_check!(|ordinal: &OrdinalValue| ordinal.latent),
+             |_, ordinal| Ok(ordinal.value().latent(false))
+    );
+    Ok(())
+}